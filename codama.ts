@@ -1,5 +1,5 @@
 import { renderJavaScriptUmiVisitor, renderJavaScriptVisitor, renderRustVisitor } from '@codama/renderers';
-import { accountNode, arrayTypeNode, arrayValueNode, booleanTypeNode, bytesTypeNode, constantDiscriminatorNode, constantValueNode, createFromRoot, definedTypeLinkNode, definedTypeNode, instructionAccountNode, instructionArgumentNode, instructionNode, numberTypeNode, numberValueNode, optionTypeNode, prefixedCountNode, programNode, publicKeyTypeNode, publicKeyValueNode, REGISTERED_COUNT_NODE_KINDS, rootNode, sizeDiscriminatorNode, sizePrefixTypeNode, stringTypeNode, stringValueNode, structFieldTypeNode, structTypeNode, tupleTypeNode, tupleValueNode } from "codama"
+import { accountNode, arrayTypeNode, arrayValueNode, booleanTypeNode, bytesTypeNode, constantDiscriminatorNode, constantValueNode, createFromRoot, definedTypeLinkNode, definedTypeNode, errorNode, fixedCountNode, fixedSizeTypeNode, instructionAccountNode, instructionArgumentNode, instructionNode, noneValueNode, numberTypeNode, numberValueNode, optionTypeNode, prefixedCountNode, programNode, publicKeyTypeNode, publicKeyValueNode, REGISTERED_COUNT_NODE_KINDS, remainderOptionTypeNode, rootNode, sizeDiscriminatorNode, sizePrefixTypeNode, stringTypeNode, stringValueNode, structFieldTypeNode, structTypeNode, tupleTypeNode, tupleValueNode } from "codama"
 import path from "path";
 import fs from "fs";
 
@@ -27,7 +27,13 @@ const root = rootNode(
                     structFieldTypeNode({ name: 'authority', type: publicKeyTypeNode() }),
                     structFieldTypeNode({ name: 'isPermissioned', type: booleanTypeNode() }),
                     structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'transferFeeBps', type: numberTypeNode("u16") }),
+                    structFieldTypeNode({ name: 'feeDestination', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'maxRecordSize', type: numberTypeNode("u32") }),
+                    structFieldTypeNode({ name: 'maxRecords', type: numberTypeNode("u32") }),
+                    structFieldTypeNode({ name: 'bump', type: numberTypeNode("u8") }),
                     structFieldTypeNode({ name: 'name', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
+                    structFieldTypeNode({ name: 'metadataSchemaUri', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
                     structFieldTypeNode({ name: 'metadata', type: stringTypeNode("utf8") }),
                 ])
             }),
@@ -42,11 +48,104 @@ const root = rootNode(
                     structFieldTypeNode({ name: 'ownerType', type: numberTypeNode('u8'), defaultValue: numberValueNode(0), defaultValueStrategy: 'omitted' }),
                     structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() }),
                     structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'nonTransferable', type: booleanTypeNode() }),
                     structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'createdAt', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'updatedAt', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'revision', type: numberTypeNode("u32") }),
+                    structFieldTypeNode({ name: 'mutationCursor', type: numberTypeNode("u8") }),
+                    structFieldTypeNode({ name: 'recentMutations', type: arrayTypeNode(definedTypeLinkNode('recordMutation'), fixedCountNode(5)) }),
+                    structFieldTypeNode({ name: 'bump', type: numberTypeNode("u8") }),
+                    structFieldTypeNode({ name: 'mintBump', type: numberTypeNode("u8") }),
                     structFieldTypeNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
                     structFieldTypeNode({ name: 'data', type: bytesTypeNode() }),
                 ])
             }),
+            accountNode({
+                name: "recordLease",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(3)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(3), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'lessee', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'expiresAt', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'permissions', type: numberTypeNode("u8") }),
+                    structFieldTypeNode({ name: 'recordOwner', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'recordCreatedAt', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'recordClass', type: publicKeyTypeNode() }),
+                ])
+            }),
+            accountNode({
+                name: "classStats",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(4)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(4), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'recordCount', type: numberTypeNode("u64") }),
+                    structFieldTypeNode({ name: 'totalBytes', type: numberTypeNode("u64") }),
+                ])
+            }),
+            accountNode({
+                name: "recordSnapshot",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(5)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(5), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'revision', type: numberTypeNode("u32") }),
+                    structFieldTypeNode({ name: 'slot', type: numberTypeNode("u64") }),
+                    structFieldTypeNode({ name: 'unixTimestamp', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'dataHash', type: fixedSizeTypeNode(bytesTypeNode(), 32) }),
+                ])
+            }),
+            accountNode({
+                name: "royaltyConfig",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(6)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(6), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'basisPoints', type: numberTypeNode("u16") }),
+                    structFieldTypeNode({ name: 'destination', type: publicKeyTypeNode() }),
+                ])
+            }),
+            accountNode({
+                name: "recordAccessList",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(7)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(7), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'entries', type: arrayTypeNode(publicKeyTypeNode(), prefixedCountNode(numberTypeNode("u8"))) }),
+                ])
+            }),
+            accountNode({
+                name: "classRegistry",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(8)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(8), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'classCount', type: numberTypeNode("u32") }),
+                ])
+            }),
+            accountNode({
+                name: "classRegistryPage",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(9)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(9), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'classes', type: arrayTypeNode(publicKeyTypeNode(), prefixedCountNode(numberTypeNode("u32"))) }),
+                ])
+            }),
        ],
         instructions: [
             instructionNode({
@@ -63,7 +162,10 @@ const root = rootNode(
                     }),
                     instructionArgumentNode({ name: 'isPermissioned', type: booleanTypeNode() }),
                     instructionArgumentNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                    instructionArgumentNode({ name: 'maxRecordSize', type: numberTypeNode("u32") }),
+                    instructionArgumentNode({ name: 'maxRecords', type: numberTypeNode("u32") }),
                     instructionArgumentNode({ name: 'name', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
+                    instructionArgumentNode({ name: 'metadataSchemaUri', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
                     instructionArgumentNode({ name: 'metadata', type: stringTypeNode("utf8") }),
                 ],
                 accounts: [
@@ -85,6 +187,18 @@ const root = rootNode(
                         isWritable: true,
                         docs: ["New class account to be initialized"]
                     }),
+                    instructionAccountNode({
+                        name: "classRegistry",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class registry of the authority, created on the first class"]
+                    }),
+                    instructionAccountNode({
+                        name: "classRegistryPage",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class registry page the new class is appended to"]
+                    }),
                     instructionAccountNode({
                         name: "systemProgram",
                         defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
@@ -92,6 +206,12 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to open our new class account"]
                     }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the class"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -106,6 +226,11 @@ const root = rootNode(
                         defaultValue: numberValueNode(1),
                         defaultValueStrategy: 'omitted',
                     }),
+                    instructionArgumentNode({ name: 'maxRecordSize', type: numberTypeNode("u32") }),
+                    instructionArgumentNode({ name: 'maxRecords', type: numberTypeNode("u32") }),
+                    instructionArgumentNode({ name: 'transferFeeBps', type: numberTypeNode("u16") }),
+                    instructionArgumentNode({ name: 'feeDestination', type: publicKeyTypeNode() }),
+                    instructionArgumentNode({ name: 'metadataSchemaUri', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
                     instructionArgumentNode({ name: 'metadata', type: stringTypeNode("utf8") }),
                 ],
                 accounts: [
@@ -190,20 +315,19 @@ const root = rootNode(
                         defaultValue: numberValueNode(3),
                         defaultValueStrategy: 'omitted',
                     }),
-                    instructionArgumentNode({ name: 'isFrozen', type: booleanTypeNode() }),
                 ],
                 accounts: [
                     instructionAccountNode({
                         name: "authority",
                         isSigner: true,
                         isWritable: true,
-                        docs: ["Authority used to freeze/thaw a class"]
+                        docs: ["Authority used to freeze a class"]
                     }),
                     instructionAccountNode({
                         name: "class",
                         isSigner: false,
                         isWritable: true,
-                        docs: ["Class account to be frozen/thawed"]
+                        docs: ["Class account to be frozen"]
                     })
                 ]
             }),
@@ -222,6 +346,7 @@ const root = rootNode(
                     instructionArgumentNode({ 
                         name: 'expiration', type: numberTypeNode("i64") 
                     }),
+                    instructionArgumentNode({ name: 'nonTransferable', type: booleanTypeNode() }),
                     instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8"))}),
                     instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
                 ],
@@ -257,6 +382,12 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to create our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the class, created on the first record"]
+                    }),
                     instructionAccountNode({
                         name: "authority",
                         isOptional: true,
@@ -264,6 +395,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Optional authority for permissioned classes"]
                     }),
+                    instructionAccountNode({
+                        name: "accessList",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record access list for permissioned classes"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -281,6 +419,7 @@ const root = rootNode(
                     instructionArgumentNode({ 
                         name: 'expiration', type: numberTypeNode("i64") 
                     }),
+                    instructionArgumentNode({ name: 'nonTransferable', type: booleanTypeNode() }),
                     instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
                     instructionArgumentNode({ name: 'metadata', type: definedTypeLinkNode('metadata')})
                 ],
@@ -316,6 +455,12 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to create our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the class, created on the first record"]
+                    }),
                     instructionAccountNode({
                         name: "authority",
                         isOptional: true,
@@ -323,6 +468,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Optional authority for permissioned classes"]
                     }),
+                    instructionAccountNode({
+                        name: "accessList",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record access list for permissioned classes"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -371,6 +523,81 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to extend our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Active record lease held by the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "updateRecordWithRevision",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(40)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(40),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expectedRevision', type: numberTypeNode("u32") }),
+                    instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to extend our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Active record lease held by the authority"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -419,6 +646,19 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to extend our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Active record lease held by the authority"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -467,6 +707,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to extend our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Active record lease held by the authority"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -501,7 +748,28 @@ const root = rootNode(
                         isOptional: true,
                         isSigner: false,
                         isWritable: false,
-                        docs: ["Class account of the record"]
+                        docs: ["Class account of the record, required to pay the transfer fee"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isOptional: true,
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account paying the transfer fee, required when the class charges a transfer fee"]
+                    }),
+                    instructionAccountNode({
+                        name: "feeDestination",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Fee destination of the class, required when the class charges a transfer fee"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to pay the transfer fee"]
                     }),
                 ],
             }),
@@ -537,6 +805,12 @@ const root = rootNode(
                         isWritable: true,
                         docs: ["Record account to be updated"]
                     }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
                     instructionAccountNode({
                         name: "class",
                         isOptional: true,
@@ -593,6 +867,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Class account of the record"]
                     }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Active record lease held by the authority"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -678,6 +959,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to create our token"]
                     }),
+                    instructionAccountNode({
+                        name: "recipient",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Wallet receiving the record token, defaults to the record owner"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -746,6 +1034,7 @@ const root = rootNode(
                         defaultValue: numberValueNode(12),
                         defaultValueStrategy: 'omitted',
                     }),
+                    instructionArgumentNode({ name: 'tokenValueLamports', type: remainderOptionTypeNode(numberTypeNode("u64")), defaultValue: noneValueNode() }),
                 ],
                 accounts: [
                     instructionAccountNode({
@@ -790,8 +1079,43 @@ const root = rootNode(
                         isOptional: true,
                         isSigner: false,
                         isWritable: false,
-                        docs: ["Class account of the record"]
-                    }),    
+                        docs: ["Class account of the record, required with the royalty config and to pay the transfer fee"]
+                    }),
+                    instructionAccountNode({
+                        name: "royaltyConfig",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Royalty config account of the record class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isOptional: true,
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account paying the royalty and the transfer fee, required when the class charges either"]
+                    }),
+                    instructionAccountNode({
+                        name: "destination",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Royalty destination of the royalty config, required when the class charges a royalty"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to pay the royalty and the transfer fee"]
+                    }),
+                    instructionAccountNode({
+                        name: "feeDestination",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Fee destination of the class, required when the class charges a transfer fee"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -853,28 +1177,1304 @@ const root = rootNode(
                         docs: ["Class account of the record"]
                     }),    
                 ],
-            })
-        ],
-        definedTypes: [
-            definedTypeNode({
-                name: "metadata",
-                docs: "Token22 Metadata Extension compatible Metadata format",
-                type: structTypeNode([
-                    structFieldTypeNode({
-                        name: 'name',
-                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
-                    }),
-                    structFieldTypeNode({
-                        name: 'symbol', 
-                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")),
-                        defaultValue: stringValueNode("SRS"),
-                        defaultValueStrategy: 'optional'
+            }),
+            instructionNode({
+                name: "grantRecordLease",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(14)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(14),
+                        defaultValueStrategy: 'omitted',
                     }),
-                    structFieldTypeNode({
-                        name: 'uri', 
-                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
+                    instructionArgumentNode({ name: 'lessee', type: publicKeyTypeNode() }),
+                    instructionArgumentNode({ name: 'expiresAt', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'permissions', type: numberTypeNode("u8") }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority granting the lease"]
                     }),
-                    structFieldTypeNode({
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the lease account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the lease grants access to"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record lease account of the lessee to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our lease account"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "revokeRecordLease",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(15)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(15),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority revoking the lease"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the lease account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the lease grants access to"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record lease account to be closed"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "expireRecordLease",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(16)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(16),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class authority that gets refunded for the lease account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the lease grants access to"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Expired record lease account to be closed"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "deleteClass",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(17)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(17),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority used to delete a class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the class account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be deleted"]
+                    }),
+                    instructionAccountNode({
+                        name: "classRegistryPage",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class registry page the class is removed from"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class stats account of the class, must not count any record"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "reclaimExpiredRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(18)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(18),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["The account that will get refunded for the record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["The expired record account to be reclaimed"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["The class stats account of the record class"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "cloneRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(19)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(19),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the source record or class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the destination record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "sourceRecord",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account to be cloned"]
+                    }),
+                    instructionAccountNode({
+                        name: "destinationRecord",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the source record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the destination record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the class, created on the first record"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "createRecordSnapshot",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(20)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(20),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the snapshot account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account to take a snapshot of"]
+                    }),
+                    instructionAccountNode({
+                        name: "snapshot",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record snapshot account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our snapshot account"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "batchCreateRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(21)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(21),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'count', type: numberTypeNode("u8") }),
+                    instructionArgumentNode({ name: 'records', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the new records and class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account for the records to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our record accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the class, created on the first record"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "batchDeleteRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(22)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(22),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the records or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the record accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the records"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the records class"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "extendRecordExpiry",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(23)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(23),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'newExpiry', type: numberTypeNode("i64") }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be extended"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "renameRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(24)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(24),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the record or class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the new record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "oldRecord",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be renamed"]
+                    }),
+                    instructionAccountNode({
+                        name: "newRecord",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created under the new seed"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the new record account"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "updateClassPermission",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(25)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(25),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'isPermissioned', type: booleanTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Authority used to update the class permission"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be updated"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "updateTokenizedRecordMetadata",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(26)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(26),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expectedRevision', type: numberTypeNode("u32") }),
+                    instructionArgumentNode({ name: 'metadata', type: definedTypeLinkNode('metadata') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record and mint resizing"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Tokenized record account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "mint",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Mint account of the record token"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "token2022",
+                        defaultValue: publicKeyValueNode('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb', 'token2022'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Token2022 program used to update the mint metadata"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to extend our record and mint accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "addAdditionalMetadata",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(27)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(27),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'label', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")) }),
+                    instructionArgumentNode({ name: 'value', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record and mint resizing"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Tokenized record account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "mint",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Mint account of the record token"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "token2022",
+                        defaultValue: publicKeyValueNode('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb', 'token2022'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Token2022 program used to update the mint metadata"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record and mint accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "removeAdditionalMetadata",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(28)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(28),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'label', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the record resizing"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Tokenized record account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "mint",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Mint account of the record token"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "token2022",
+                        defaultValue: publicKeyValueNode('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb', 'token2022'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Token2022 program used to update the mint metadata"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record and mint accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "classStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the record class"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "createRoyaltyConfig",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(29)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(29),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'basisPoints', type: numberTypeNode("u16") }),
+                    instructionArgumentNode({ name: 'destination', type: publicKeyTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the royalty config account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account the royalties apply to"]
+                    }),
+                    instructionAccountNode({
+                        name: "royaltyConfig",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Royalty config account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our royalty config account"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "updateRoyaltyConfig",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(30)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(30),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'basisPoints', type: numberTypeNode("u16") }),
+                    instructionArgumentNode({ name: 'destination', type: publicKeyTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account the royalties apply to"]
+                    }),
+                    instructionAccountNode({
+                        name: "royaltyConfig",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Royalty config account to be updated"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "resizeRecordDataOnly",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(31)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(31),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'byteOffset', type: numberTypeNode("u32") }),
+                    instructionArgumentNode({ name: 'patch', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be patched"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to extend our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Active record lease held by the authority"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "renewRecordLease",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(32)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(32),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expiresAt', type: numberTypeNode("i64") }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority renewing the lease"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record the lease grants access to"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record lease account to be renewed"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "createRecordAccessList",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(33)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(33),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    instructionArgumentNode({ name: 'entries', type: arrayTypeNode(publicKeyTypeNode(), prefixedCountNode(numberTypeNode("u8"))) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the access list account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the access list applies to"]
+                    }),
+                    instructionAccountNode({
+                        name: "accessList",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record access list account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our access list account"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "updateRecordAccessList",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(34)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(34),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'entries', type: arrayTypeNode(publicKeyTypeNode(), prefixedCountNode(numberTypeNode("u8"))) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay or get refunded for the access list resizing"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "accessList",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record access list account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our access list account"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "deleteRecordAccessList",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(35)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(35),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the access list account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "accessList",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record access list account to be deleted"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "unfreezeClass",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(36)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(36),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Authority used to unfreeze a class"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be unfrozen"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "updateGroupMetadata",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(37)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(37),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the group resizing"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the group"]
+                    }),
+                    instructionAccountNode({
+                        name: "group",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Group mint account of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "token2022",
+                        defaultValue: publicKeyValueNode('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb', 'token2022'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Token2022 program"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System program"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "transferRecordToClass",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(38)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(38),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of both the old and the new class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the new record account and get refunded for the old one"]
+                    }),
+                    instructionAccountNode({
+                        name: "oldClass",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "newClass",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account the record is transferred to"]
+                    }),
+                    instructionAccountNode({
+                        name: "oldRecord",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be transferred"]
+                    }),
+                    instructionAccountNode({
+                        name: "newRecord",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created under the new class"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the new record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "oldClassStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the old class"]
+                    }),
+                    instructionAccountNode({
+                        name: "newClassStats",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class stats account of the new class, created on its first record"]
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: "createRecordLease",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(39)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(39),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'lessee', type: publicKeyTypeNode() }),
+                    instructionArgumentNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'canUpdate', type: booleanTypeNode() }),
+                    instructionArgumentNode({ name: 'canFreeze', type: booleanTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority granting the lease"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the lease account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the lease grants access to"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "lease",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record lease account of the lessee to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our lease account"]
+                    }),
+                ],
+            })
+        ],
+        definedTypes: [
+            definedTypeNode({
+                name: "metadata",
+                docs: "Token22 Metadata Extension compatible Metadata format",
+                type: structTypeNode([
+                    structFieldTypeNode({
+                        name: 'name',
+                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
+                    }),
+                    structFieldTypeNode({
+                        name: 'symbol', 
+                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")),
+                        defaultValue: stringValueNode("SRS"),
+                        defaultValueStrategy: 'optional'
+                    }),
+                    structFieldTypeNode({
+                        name: 'uri', 
+                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
+                    }),
+                    structFieldTypeNode({
                         name: 'additionalMetadata',
                         type: arrayTypeNode(
                             definedTypeLinkNode('additionalMetadata'),
@@ -883,6 +2483,24 @@ const root = rootNode(
                     })
                 ])  
             }),
+            definedTypeNode({
+                name: "batchRecord",
+                docs: "Entry of a batch of records to be created",
+                type: structTypeNode([
+                    structFieldTypeNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    structFieldTypeNode({ name: 'data', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")) }),
+                    structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                ])
+            }),
+            definedTypeNode({
+                name: "recordMutation",
+                docs: "Entry of the record audit log",
+                type: structTypeNode([
+                    structFieldTypeNode({ name: 'instructionType', type: numberTypeNode("u8") }),
+                    structFieldTypeNode({ name: 'timestamp', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'actor', type: publicKeyTypeNode() }),
+                ])
+            }),
             definedTypeNode({
                 name: "additionalMetadata",
                 docs: "Additional metadata for Token22 Metadata Extension compatible Metadata format",
@@ -897,6 +2515,73 @@ const root = rootNode(
                     })
                 ])
             })
+        ],
+        errors: [
+            errorNode({
+                code: 1,
+                name: "classCapacityExceeded",
+                message: "The class group has reached its maximum size",
+            }),
+            errorNode({
+                code: 2,
+                name: "staleRevision",
+                message: "The record revision does not match the expected revision",
+            }),
+            errorNode({
+                code: 3,
+                name: "batchTooLarge",
+                message: "The batch holds more records than fit in a single transaction",
+            }),
+            errorNode({
+                code: 4,
+                name: "classFrozen",
+                message: "The class is frozen",
+            }),
+            errorNode({
+                code: 5,
+                name: "recordFrozen",
+                message: "The record is frozen",
+            }),
+            errorNode({
+                code: 6,
+                name: "recordExpired",
+                message: "The record has expired",
+            }),
+            errorNode({
+                code: 7,
+                name: "recordTooLarge",
+                message: "The record data exceeds the class maximum record size",
+            }),
+            errorNode({
+                code: 8,
+                name: "notRecordOwner",
+                message: "The authority is not the owner of the record",
+            }),
+            errorNode({
+                code: 9,
+                name: "notClassAuthority",
+                message: "The authority is not the authority of the class",
+            }),
+            errorNode({
+                code: 10,
+                name: "invalidDelegate",
+                message: "The class does not allow its authority to act as a record delegate",
+            }),
+            errorNode({
+                code: 11,
+                name: "alreadyTokenized",
+                message: "The record is tokenized",
+            }),
+            errorNode({
+                code: 12,
+                name: "notTokenized",
+                message: "The record is not tokenized by the given mint",
+            }),
+            errorNode({
+                code: 13,
+                name: "classNotFrozen",
+                message: "The class is not frozen",
+            })
         ]
     })
 )
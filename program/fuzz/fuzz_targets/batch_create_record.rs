@@ -0,0 +1,8 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use srs::instructions::BatchCreateRecord;
+use srs_fuzz::FuzzInput;
+
+fuzz_target!(|input: FuzzInput| {
+    input.parse(None, |ctx| BatchCreateRecord::try_from(ctx).map(|_| ()));
+});
@@ -0,0 +1,8 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use srs::instructions::CreateClass;
+use srs_fuzz::FuzzInput;
+
+fuzz_target!(|input: FuzzInput| {
+    input.parse(None, |ctx| CreateClass::try_from(ctx).map(|_| ()));
+});
@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use srs::instructions::{create_record::CREATE_RECORD_FIXED_ACCOUNTS, CreateRecord};
+use srs_fuzz::FuzzInput;
+
+fuzz_target!(|input: FuzzInput| {
+    input.parse(Some(CREATE_RECORD_FIXED_ACCOUNTS), |ctx| {
+        CreateRecord::try_from(ctx).map(|_| ())
+    });
+});
@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use srs::instructions::{update_record::UPDATE_RECORD_FIXED_ACCOUNTS, UpdateRecordData};
+use srs_fuzz::FuzzInput;
+
+fuzz_target!(|input: FuzzInput| {
+    input.parse(Some(UPDATE_RECORD_FIXED_ACCOUNTS), |ctx| {
+        UpdateRecordData::try_from(ctx).map(|_| ())
+    });
+});
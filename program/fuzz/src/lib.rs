@@ -0,0 +1,240 @@
+//! Shared harness of the instruction parser fuzz targets.
+//!
+//! Each target feeds an arbitrary [`FuzzInput`] to one instruction parser.
+//! The input is serialized in the loader input format and deserialized by
+//! the pinocchio entrypoint, so the parser sees the same `AccountInfo`s it
+//! would on chain, duplicate accounts included.
+//!
+//! The parser must either succeed or fail with one of the expected
+//! [`ProgramError`]s, any other error fails the target like a panic does.
+//!
+//! PDA derivation and sysvars are not available off chain, so parsers
+//! checking a PDA or reading the clock always fail at that point.
+//!
+//! Run a target from `program` with `cargo +nightly fuzz run <target>`, the
+//! targets are `create_class`, `create_record`, `update_record` and
+//! `batch_create_record`. Pass `--no-default-features` to fuzz the parsers
+//! without the `perf` feature.
+use arbitrary::Arbitrary;
+use core::mem::{size_of, MaybeUninit};
+use pinocchio::{
+    account_info::AccountInfo, entrypoint::deserialize, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use srs::{
+    state::{Class, Record},
+    token2022::constants::TOKEN_2022_PROGRAM_ID,
+    utils::Context,
+};
+
+/// Maximum number of accounts of an input
+const MAX_ACCOUNTS: usize = 16;
+
+/// Number of distinct keys accounts are picked from, small enough for
+/// accounts to regularly alias each other
+const KEY_POOL_LEN: u8 = 8;
+
+/// Loader input format constants
+const NON_DUP_MARKER: u8 = u8::MAX;
+const MAX_PERMITTED_DATA_INCREASE: usize = 1024 * 10;
+const BPF_ALIGN_OF_U128: usize = 8;
+
+#[derive(Arbitrary, Debug)]
+pub struct FuzzInput {
+    accounts: Vec<FuzzAccount>,
+    data: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+pub struct FuzzAccount {
+    /// Index in the key pool, accounts sharing a key are duplicates
+    key: u8,
+    owner: FuzzOwner,
+    is_signer: bool,
+    is_writable: bool,
+    lamports: u64,
+    data: FuzzData,
+}
+
+#[derive(Arbitrary, Debug)]
+pub enum FuzzOwner {
+    Program,
+    Token2022,
+    System,
+}
+
+/// Account data, either raw bytes or a valid-looking header of the program
+/// accounts followed by raw bytes
+#[derive(Arbitrary, Debug)]
+pub enum FuzzData {
+    Raw(Vec<u8>),
+    Class {
+        authority: u8,
+        is_permissioned: bool,
+        is_frozen: bool,
+        max_record_size: u32,
+        max_records: u32,
+        bump: u8,
+        tail: Vec<u8>,
+    },
+    Record {
+        class: u8,
+        owner_type: u8,
+        owner: u8,
+        tail: Vec<u8>,
+    },
+}
+
+impl FuzzInput {
+    /// Runs `parse` on the input, with the first `fixed_count` accounts as
+    /// required accounts if set
+    pub fn parse<F>(&self, fixed_count: Option<usize>, parse: F)
+    where
+        F: for<'info> FnOnce(Context<'info>) -> Result<(), ProgramError>,
+    {
+        let mut input = self.serialize();
+
+        let mut accounts = [const { MaybeUninit::<AccountInfo>::uninit() }; MAX_ACCOUNTS];
+        let (_, count, data) =
+            unsafe { deserialize::<MAX_ACCOUNTS>(input.as_mut_ptr() as *mut u8, &mut accounts) };
+        let accounts =
+            unsafe { core::slice::from_raw_parts(accounts.as_ptr() as *const AccountInfo, count) };
+
+        let ctx = match fixed_count {
+            Some(fixed_count) => Context::with_remaining_accounts(accounts, data, fixed_count),
+            None => Context::new(accounts, data),
+        };
+
+        if let Err(error) = parse(ctx) {
+            assert!(is_expected(&error), "unexpected error: {error:?}");
+        }
+    }
+
+    /// Serializes the input in the loader input format, in an 8 bytes
+    /// aligned buffer
+    fn serialize(&self) -> Vec<u64> {
+        let accounts = &self.accounts[..self.accounts.len().min(MAX_ACCOUNTS)];
+
+        let mut input = Vec::new();
+        input.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+
+        let mut seen = [None; KEY_POOL_LEN as usize];
+        for (index, account) in accounts.iter().enumerate() {
+            let key = account.key % KEY_POOL_LEN;
+
+            if let Some(original) = seen[key as usize] {
+                input.push(original);
+                input.extend_from_slice(&[0u8; 7]);
+                continue;
+            }
+            seen[key as usize] = Some(index as u8);
+
+            let data = account.data.to_bytes();
+
+            input.push(NON_DUP_MARKER);
+            input.push(account.is_signer as u8);
+            input.push(account.is_writable as u8);
+            input.push(0); // executable
+            input.extend_from_slice(&[0u8; 4]); // original data length
+            input.extend_from_slice(&pool_key(key));
+            input.extend_from_slice(&account.owner.key());
+            input.extend_from_slice(&account.lamports.to_le_bytes());
+            input.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            input.extend_from_slice(&data);
+            input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            input.resize(input.len().next_multiple_of(BPF_ALIGN_OF_U128), 0);
+            input.extend_from_slice(&u64::MAX.to_le_bytes()); // rent epoch
+        }
+
+        input.extend_from_slice(&(self.data.len() as u64).to_le_bytes());
+        input.extend_from_slice(&self.data);
+        input.extend_from_slice(&srs::ID);
+
+        let mut aligned = vec![0u64; input.len().div_ceil(size_of::<u64>())];
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                input.as_ptr(),
+                aligned.as_mut_ptr() as *mut u8,
+                input.len(),
+            );
+        }
+        aligned
+    }
+}
+
+impl FuzzOwner {
+    fn key(&self) -> Pubkey {
+        match self {
+            Self::Program => srs::ID,
+            Self::Token2022 => TOKEN_2022_PROGRAM_ID,
+            Self::System => [0; 32],
+        }
+    }
+}
+
+impl FuzzData {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        match self {
+            Self::Raw(bytes) => data.extend_from_slice(bytes),
+            Self::Class {
+                authority,
+                is_permissioned,
+                is_frozen,
+                max_record_size,
+                max_records,
+                bump,
+                tail,
+            } => {
+                data.push(Class::DISCRIMINATOR);
+                data.extend_from_slice(&pool_key(authority % KEY_POOL_LEN));
+                data.push(*is_permissioned as u8);
+                data.push(*is_frozen as u8);
+                data.extend_from_slice(&max_record_size.to_le_bytes());
+                data.extend_from_slice(&max_records.to_le_bytes());
+                data.push(*bump);
+                data.extend_from_slice(tail);
+            }
+            Self::Record {
+                class,
+                owner_type,
+                owner,
+                tail,
+            } => {
+                data.push(Record::DISCRIMINATOR);
+                data.extend_from_slice(&pool_key(class % KEY_POOL_LEN));
+                data.push(*owner_type);
+                data.extend_from_slice(&pool_key(owner % KEY_POOL_LEN));
+                data.extend_from_slice(tail);
+            }
+        }
+        data
+    }
+}
+
+/// Key of the pool at `index`, the last one is the system program
+fn pool_key(index: u8) -> Pubkey {
+    if index == KEY_POOL_LEN - 1 {
+        return [0; 32];
+    }
+    [index + 1; 32]
+}
+
+/// Errors an instruction parser may fail with on malformed input
+fn is_expected(error: &ProgramError) -> bool {
+    matches!(
+        error,
+        ProgramError::Custom(_)
+            | ProgramError::NotEnoughAccountKeys
+            | ProgramError::InvalidInstructionData
+            | ProgramError::InvalidAccountData
+            | ProgramError::InvalidArgument
+            | ProgramError::MissingRequiredSignature
+            | ProgramError::IncorrectProgramId
+            | ProgramError::AccountAlreadyInitialized
+            | ProgramError::ArithmeticOverflow
+            | ProgramError::AccountBorrowFailed
+            // Sysvars are not available off chain
+            | ProgramError::UnsupportedSysvar
+    )
+}
@@ -1,3 +1,26 @@
 /// Variable data length constraints
 pub const MAX_SEED_LEN: usize = 0x20;
-pub const MAX_METADATA_LEN: usize = 0xff;
\ No newline at end of file
+pub const MAX_METADATA_LEN: usize = 0xff;
+/// Maximum number of records handled by a single batch instruction
+pub const MAX_BATCH_SIZE: usize = 8;
+/// Amount the class transfer fee is charged on, in lamports
+///
+/// Record transfers carry no price the program can verify: `TransferRecord` takes none and the
+/// value of a tokenized record transfer is declared by the caller. The `transfer_fee_bps` of a
+/// class are therefore charged on a fixed 1 SOL, so that each basis point is 100_000 lamports
+/// and the maximum of 10_000 bps is 1 SOL.
+pub const TRANSFER_FEE_BASE_LAMPORTS: u64 = 1_000_000_000;
+/// Discriminators of the instructions recorded in the record audit log
+pub const UPDATE_RECORD_DATA_IX: u8 = 5;
+pub const UPDATE_RECORD_EXPIRY_IX: u8 = 6;
+pub const TRANSFER_RECORD_IX: u8 = 7;
+pub const FREEZE_RECORD_IX: u8 = 9;
+pub const MINT_TOKENIZED_RECORD_IX: u8 = 10;
+pub const BURN_TOKENIZED_RECORD_IX: u8 = 13;
+pub const EXTEND_RECORD_EXPIRY_IX: u8 = 23;
+pub const RENAME_RECORD_IX: u8 = 24;
+pub const UPDATE_TOKENIZED_RECORD_METADATA_IX: u8 = 26;
+pub const ADD_ADDITIONAL_METADATA_IX: u8 = 27;
+pub const REMOVE_ADDITIONAL_METADATA_IX: u8 = 28;
+pub const RESIZE_RECORD_DATA_ONLY_IX: u8 = 31;
+pub const TRANSFER_RECORD_TO_CLASS_IX: u8 = 38;
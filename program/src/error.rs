@@ -0,0 +1,39 @@
+use pinocchio::program_error::ProgramError;
+
+/// Custom program errors
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SrsError {
+    /// The class has reached its maximum number of records or tokenized records
+    ClassCapacityExceeded = 1,
+    /// The record revision does not match the expected revision of the update
+    StaleRevision = 2,
+    /// The batch holds more records than fit in a single transaction
+    BatchTooLarge = 3,
+    /// The class is frozen
+    ClassFrozen = 4,
+    /// The record is frozen
+    RecordFrozen = 5,
+    /// The record has expired
+    RecordExpired = 6,
+    /// The record data exceeds the class `max_record_size`
+    RecordTooLarge = 7,
+    /// The authority is not the owner of the record
+    NotRecordOwner = 8,
+    /// The authority is not the authority of the class
+    NotClassAuthority = 9,
+    /// The class does not allow its authority to act as a record delegate
+    InvalidDelegate = 10,
+    /// The record is tokenized
+    AlreadyTokenized = 11,
+    /// The record is not tokenized by the given mint
+    NotTokenized = 12,
+    /// The class is not frozen
+    ClassNotFrozen = 13,
+}
+
+impl From<SrsError> for ProgramError {
+    fn from(e: SrsError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
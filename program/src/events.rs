@@ -0,0 +1,109 @@
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+
+/// Maximum number of pubkeys carried by a single event
+const MAX_EVENT_KEYS: usize = 4;
+
+/// Events emitted with `sol_log_data` once a mutating instruction succeeds,
+/// so that indexers can follow state changes from transaction logs alone.
+///
+/// An event is logged as `[event_type, key_0, .., key_n]`, where the event
+/// type is the discriminator of the emitting instruction and the keys are
+/// the affected accounts, in the order documented on each variant. Batch
+/// instructions emit the `CreateRecord` or `DeleteRecord` event of every
+/// record in the batch, and `CreateRecordLease` emits the `GrantRecordLease`
+/// event.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum EventType {
+    /// `[class, authority]`
+    CreateClass = 0,
+    /// `[class]`
+    UpdateClassMetadata = 1,
+    /// `[class, new_authority]`
+    UpdateClassAuthority = 2,
+    /// `[class]`
+    FreezeClass = 3,
+    /// `[class, record, owner]`
+    CreateRecord = 4,
+    /// `[record]`
+    UpdateRecordData = 5,
+    /// `[record]`
+    UpdateRecordExpiry = 6,
+    /// `[record, new_owner]`
+    TransferRecord = 7,
+    /// `[record]`
+    DeleteRecord = 8,
+    /// `[record]`
+    FreezeRecord = 9,
+    /// `[record, mint, recipient]`
+    MintTokenizedRecord = 10,
+    /// `[record, token_account]`
+    FreezeTokenizedRecord = 11,
+    /// `[record, token_account, new_token_account]`
+    TransferTokenizedRecord = 12,
+    /// `[record, mint]`
+    BurnTokenizedRecord = 13,
+    /// `[record, lease, lessee]`
+    GrantRecordLease = 14,
+    /// `[lease]`
+    RevokeRecordLease = 15,
+    /// `[lease]`
+    ExpireRecordLease = 16,
+    /// `[class]`
+    DeleteClass = 17,
+    /// `[record]`
+    ReclaimExpiredRecord = 18,
+    /// `[class, source_record, destination_record]`
+    CloneRecord = 19,
+    /// `[record, snapshot]`
+    CreateRecordSnapshot = 20,
+    /// `[record]`
+    ExtendRecordExpiry = 23,
+    /// `[old_record, new_record]`
+    RenameRecord = 24,
+    /// `[class]`
+    UpdateClassPermission = 25,
+    /// `[record, mint]`
+    UpdateTokenizedRecordMetadata = 26,
+    /// `[record, mint]`
+    AddAdditionalMetadata = 27,
+    /// `[record, mint]`
+    RemoveAdditionalMetadata = 28,
+    /// `[class, royalty_config]`
+    CreateRoyaltyConfig = 29,
+    /// `[royalty_config]`
+    UpdateRoyaltyConfig = 30,
+    /// `[record]`
+    ResizeRecordDataOnly = 31,
+    /// `[lease]`
+    RenewRecordLease = 32,
+    /// `[record, access_list]`
+    CreateRecordAccessList = 33,
+    /// `[access_list]`
+    UpdateRecordAccessList = 34,
+    /// `[access_list]`
+    DeleteRecordAccessList = 35,
+    /// `[class]`
+    UnfreezeClass = 36,
+    /// `[class, group]`
+    UpdateGroupMetadata = 37,
+    /// `[old_class, new_class, old_record, new_record]`
+    TransferRecordToClass = 38,
+}
+
+impl EventType {
+    /// Logs the event with the keys of the affected accounts
+    #[inline(always)]
+    pub fn emit(self, keys: &[&Pubkey]) {
+        let event_type = [self as u8];
+
+        let mut fields: [&[u8]; MAX_EVENT_KEYS + 1] = [&[]; MAX_EVENT_KEYS + 1];
+        fields[0] = &event_type;
+
+        for (field, key) in fields[1..].iter_mut().zip(keys) {
+            *field = key.as_slice();
+        }
+
+        sol_log_data(&fields[..keys.len().min(MAX_EVENT_KEYS) + 1]);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{
+    constants::ADD_ADDITIONAL_METADATA_IX,
+    error::SrsError,
+    events::EventType,
+    state::{Class, ClassStats, OwnerType, Record, CLASS_OFFSET, OWNER_OFFSET, OWNER_TYPE_OFFSET},
+    token2022::{Mint, TokenMetadata, UpdateMetadata},
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+/// AddAdditionalMetadata instruction.
+///
+/// This function:
+/// 1. Validates the authority, record and mint
+/// 2. Tops up the mint account for the new entry
+/// 3. Adds the entry to the mint metadata, signed by the mint PDA, replacing
+///    the value of an existing entry with the same label
+/// 4. Applies the same change to the record data
+/// 5. Sets the record `updated_at` and increments the record `revision`
+/// 6. Applies the change of the record data length to the class stats
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will pay for the record and mint resizing
+/// 3. `record` - The tokenized record account to be updated
+/// 4. `mint` - The mint account of the record token
+/// 5. `class` - The class account of the record
+/// 6. `token_2022_program` - Required for updating the mint metadata
+/// 7. `system_program` - Required for account resizing operations
+/// 8. `class_stats` - The stats account of the class of the record
+///
+/// # Security
+/// 1. The authority must be the class authority, like for `UpdateRecordData`
+/// 2. The record must be tokenized and `mint` must be its mint
+/// 3. The record must not be expired
+pub struct AddAdditionalMetadataAccounts<'info> {
+    authority: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    mint: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
+    mint_bump: [u8; 1],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for AddAdditionalMetadataAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, record, mint, class, _token_2022_program, _system_program, class_stats] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the Record is correct
+        Record::check_program_id_and_discriminator(record)?;
+
+        // Check if the Mint is correct
+        Mint::check_program_id(mint)?;
+
+        let record_data = record.try_borrow_data()?;
+
+        // Check if the class is the correct class
+        if class
+            .key()
+            .ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the record is tokenized with this mint
+        if record_data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Token))
+            || mint
+                .key()
+                .ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(SrsError::NotTokenized.into());
+        }
+
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record_data)? };
+
+        // Check if the mint is the mint PDA of the record
+        let mint_bump = Record::check_mint_address(record, mint)?;
+
+        Ok(Self {
+            authority,
+            payer,
+            record,
+            mint,
+            class_stats,
+            mint_bump,
+        })
+    }
+}
+
+pub struct AddAdditionalMetadata<'info> {
+    accounts: AddAdditionalMetadataAccounts<'info>,
+    /// Length prefixed label
+    key: &'info [u8],
+    /// Length prefixed label followed by the length prefixed value
+    entry: &'info [u8],
+}
+
+/// Minimum length of instruction data required for AddAdditionalMetadata
+pub const ADD_ADDITIONAL_METADATA_MIN_IX_LENGTH: usize = size_of::<u32>() * 2;
+
+impl<'info> TryFrom<Context<'info>> for AddAdditionalMetadata<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = AddAdditionalMetadataAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < ADD_ADDITIONAL_METADATA_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `label`
+        let label_len: u32 = instruction_data.read()?;
+        instruction_data.read_str(label_len as usize)?;
+
+        // Deserialize `value`
+        let value_len: u32 = instruction_data.read()?;
+        instruction_data.read_str(value_len as usize)?;
+
+        if instruction_data.remaining_bytes() != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // The instruction data is already the serialized metadata entry
+        Ok(Self {
+            accounts,
+            key: &ctx.data[..size_of::<u32>() + label_len as usize],
+            entry: ctx.data,
+        })
+    }
+}
+
+impl<'info> AddAdditionalMetadata<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Add Additional Metadata");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let (range, len_offset, additional_metadata_len) = {
+            let record_data = self.accounts.record.try_borrow_data()?;
+            let current = unsafe { Record::get_data_unchecked(&record_data)? };
+            let metadata = TokenMetadata::from_bytes(current.as_bytes())?;
+
+            let len_offset = metadata.additional_metadata_len_offset();
+
+            // Replace the existing entry, or append a new one
+            let range = metadata.find_entry(self.key).unwrap_or_else(|| {
+                let end = len_offset + size_of::<u32>() + metadata.additional_metadata.len();
+                end..end
+            });
+
+            (range, len_offset, metadata.additional_metadata_len)
+        };
+
+        // Top up the mint so it stays rent exempt once Token2022 resizes it
+        let lamports = Rent::get()?.minimum_balance(
+            self.accounts.mint.data_len() + self.entry.len().saturating_sub(range.len()),
+        );
+
+        if self.accounts.mint.lamports() < lamports {
+            Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.mint,
+                lamports: lamports - self.accounts.mint.lamports(),
+            }
+            .invoke()?;
+        }
+
+        let bump = self.accounts.mint_bump;
+
+        let seeds = [
+            Seed::from(b"mint"),
+            Seed::from(self.accounts.record.key()),
+            Seed::from(&bump),
+        ];
+
+        UpdateMetadata {
+            metadata: self.accounts.mint,
+            update_authority: self.accounts.mint,
+            field: 3,
+            value: self.entry,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        let previous_data_len =
+            unsafe { Record::get_data_len_unchecked(&self.accounts.record.try_borrow_data()?) };
+
+        // Update the record data [this is safe, check safety docs]
+        unsafe {
+            if range.is_empty() {
+                Record::splice_data_unchecked(
+                    self.accounts.record,
+                    self.accounts.payer,
+                    len_offset..len_offset + size_of::<u32>(),
+                    &(additional_metadata_len + 1).to_le_bytes(),
+                )?;
+            }
+
+            Record::splice_data_unchecked(
+                self.accounts.record,
+                self.accounts.payer,
+                range,
+                self.entry,
+            )?;
+
+            ClassStats::resize_record(
+                self.accounts.class_stats,
+                self.accounts.record,
+                previous_data_len,
+            )?;
+
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_updated_at_unchecked(&mut data)?;
+            Record::increment_revision_unchecked(&mut data)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                ADD_ADDITIONAL_METADATA_IX,
+                self.accounts.authority.key(),
+            )?;
+        }
+
+        EventType::AddAdditionalMetadata.emit(&[
+            self.accounts.record.key(),
+            self.accounts.mint.key(),
+        ]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    constants::MAX_BATCH_SIZE,
+    error::SrsError,
+    instructions::create_record::{CreateRecord, CreateRecordAccounts},
+    state::Class,
+    utils::{ByteReader, Context},
+};
+
+/// BatchCreateRecord instruction.
+///
+/// This function:
+/// 1. Checks the class permission once for the whole batch
+/// 2. Deserializes each `(seed, data, expiry)` entry in order
+/// 3. Creates and initializes each record following the `CreateRecord` rules
+///
+/// # Accounts
+/// 1. `authority` - The account that will own the records, and the class
+///    authority if the class is permissioned
+/// 2. `payer` - The account that will pay for the record accounts
+/// 3. `class` - The class account that the records belong to
+/// 4. `system_program` - Required for creating the record accounts
+/// 5. `class_stats` - The stats account of the class, created on the first record
+/// 6. `records` - [as remaining accounts] The new record accounts, in the
+///    order of the entries in the instruction data
+///
+/// # Security
+/// 1. Check if the class is permissioned, if so, the authority must be the
+///    class authority and a signer
+/// 2. The class must not be frozen
+/// 3. The batch must not hold more than `MAX_BATCH_SIZE` records
+/// 4. Each record is validated as in `CreateRecord`
+pub struct BatchCreateRecordAccounts<'info> {
+    authority: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
+    records: &'info [AccountInfo],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for BatchCreateRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, _system_program, class_stats, records @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check class permission
+        Class::check_permission(class, Some(authority))?;
+
+        Ok(Self {
+            authority,
+            payer,
+            class,
+            class_stats,
+            records,
+        })
+    }
+}
+
+pub struct BatchCreateRecord<'info> {
+    accounts: BatchCreateRecordAccounts<'info>,
+    count: usize,
+    entries: &'info [u8],
+}
+
+/// Minimum length of instruction data required for BatchCreateRecord
+pub const BATCH_CREATE_RECORD_MIN_IX_LENGTH: usize = size_of::<u8>();
+
+impl<'info> TryFrom<Context<'info>> for BatchCreateRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = BatchCreateRecordAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < BATCH_CREATE_RECORD_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `count`
+        let count = ByteReader::read_with_offset::<u8>(ctx.data, 0)? as usize;
+
+        if count > MAX_BATCH_SIZE {
+            return Err(SrsError::BatchTooLarge.into());
+        }
+
+        // Check that every entry has a record account
+        if accounts.records.len() < count {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        Ok(Self {
+            accounts,
+            count,
+            entries: &ctx.data[size_of::<u8>()..],
+        })
+    }
+}
+
+impl<'info> BatchCreateRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Batch Create Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let mut entries = ByteReader::new(self.entries);
+
+        for record in &self.accounts.records[..self.count] {
+            // Deserialize `seed`
+            let seed: &[u8] = entries.read_bytes_with_length()?;
+
+            // Deserialize `data`
+            let data_len: u32 = entries.read()?;
+            let data: &str = entries.read_str(data_len as usize)?;
+
+            // Deserialize `expiry`
+            let expiry: i64 = entries.read()?;
+
+            let accounts = CreateRecordAccounts::new_unchecked(
+                self.accounts.authority,
+                self.accounts.payer,
+                self.accounts.class,
+                record,
+                self.accounts.class_stats,
+            );
+
+            CreateRecord::new(accounts, expiry, false, seed, data)?.execute()?;
+        }
+
+        Ok(())
+    }
+}
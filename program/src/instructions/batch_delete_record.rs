@@ -0,0 +1,122 @@
+use crate::{
+    constants::MAX_BATCH_SIZE,
+    error::SrsError,
+    events::EventType,
+    state::{ClassStats, Record},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo, log::sol_log_64, program_error::ProgramError, ProgramResult,
+};
+
+/// BatchDeleteRecord instruction.
+///
+/// This function:
+/// 1. Validates the authority against every record of the batch
+/// 2. Removes each record from the class stats
+/// 3. Reallocates each record account data to 0 bytes and transfers its
+///    lamports to the payer
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to delete the records (must be a signer)
+/// 2. `payer` - The account that will get refunded for the record accounts
+/// 3. `class` - [optional] The class of the records, required for delegated deletes
+/// 4. `class_stats` - The stats account of the class of the records
+/// 5. `records` - [as remaining accounts] The record accounts to be deleted
+///
+/// # Security
+/// 1. The authority must be either:
+///    a. The owner of every record, or
+///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 2. If any record fails the authority check, the whole batch is aborted with
+///    the error of that record and its index is logged
+/// 3. The batch must not hold more than `MAX_BATCH_SIZE` records
+/// 4. Tokenized records must be deleted through `DeleteRecord`
+/// 5. The batch must not hold the same record twice
+pub struct BatchDeleteRecordAccounts<'info> {
+    payer: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
+    records: &'info [AccountInfo],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for BatchDeleteRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, class_stats, records @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if records.is_empty() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        if records.len() > MAX_BATCH_SIZE {
+            return Err(SrsError::BatchTooLarge.into());
+        }
+
+        // Check that no record is deleted twice
+        for (index, record) in records.iter().enumerate() {
+            if records[..index]
+                .iter()
+                .any(|other| other.key().eq(record.key()))
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+
+        let class = Some(class).filter(|class| class.key().ne(&crate::ID));
+
+        // Check if authority is the owner or has a delegate of every record
+        for (index, record) in records.iter().enumerate() {
+            Record::check_owner_or_delegate(record, class, authority)
+                .inspect_err(|_| sol_log_64(index as u64, 0, 0, 0, 0))?;
+        }
+
+        Ok(Self {
+            payer,
+            class_stats,
+            records,
+        })
+    }
+}
+
+pub struct BatchDeleteRecord<'info> {
+    accounts: BatchDeleteRecordAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for BatchDeleteRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = BatchDeleteRecordAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> BatchDeleteRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Batch Delete Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        for record in self.accounts.records {
+            ClassStats::remove_record(self.accounts.class_stats, record)?;
+
+            // Safety: The account has already been validated
+            unsafe {
+                Record::delete_record_unchecked(record, self.accounts.payer)?;
+            }
+
+            EventType::DeleteRecord.emit(&[record.key()]);
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 use crate::{
+    constants::BURN_TOKENIZED_RECORD_IX,
+    events::EventType,
     state::{OwnerType, Record},
     token2022::{BurnChecked, CloseAccount, ThawAccount, Token},
     utils::Context,
@@ -9,7 +11,6 @@ use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::try_find_program_address,
     ProgramResult,
 };
 
@@ -34,18 +35,23 @@ use pinocchio::{
 ///    a. The record owner, or
 ///    b. if the class is permissioned, the authority must be the permissioned authority
 pub struct BurnTokenizedRecordAccounts<'info> {
+    authority: &'info AccountInfo,
     destination: &'info AccountInfo,
     record: &'info AccountInfo,
     mint: &'info AccountInfo,
+    mint_bump: [u8; 1],
     token_account: &'info AccountInfo,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for BurnTokenizedRecordAccounts<'info> {
+/// Required accounts of BurnTokenizedRecord, the class of a delegate follows as optional account
+pub const BURN_TOKENIZED_RECORD_FIXED_ACCOUNTS: usize = 6;
+
+impl<'info> TryFrom<&Context<'info>> for BurnTokenizedRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, destination, mint, token_account, record, _token_2022_program, rest @ ..] =
-            accounts
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, destination, mint, token_account, record, _token_2022_program] =
+            ctx.accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
@@ -53,16 +59,21 @@ impl<'info> TryFrom<&'info [AccountInfo]> for BurnTokenizedRecordAccounts<'info>
         // Check if authority is the record owner or has a delegate
         Record::check_owner_or_delegate_tokenized(
             record,
-            rest.first(),
+            ctx.remaining_accounts.first(),
             authority,
             mint,
             token_account,
         )?;
 
+        // Check if the mint is the mint PDA of the record
+        let mint_bump = Record::check_mint_address(record, mint)?;
+
         Ok(Self {
+            authority,
             destination,
             record,
             mint,
+            mint_bump,
             token_account,
         })
     }
@@ -77,7 +88,7 @@ impl<'info> TryFrom<Context<'info>> for BurnTokenizedRecord<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = BurnTokenizedRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = BurnTokenizedRecordAccounts::try_from(&ctx)?;
 
         Ok(Self { accounts })
     }
@@ -91,11 +102,7 @@ impl<'info> BurnTokenizedRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        let bump = [
-            try_find_program_address(&[b"mint", self.accounts.record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-        ];
+        let bump = self.accounts.mint_bump;
 
         let seeds = [
             Seed::from(b"mint"),
@@ -153,8 +160,20 @@ impl<'info> BurnTokenizedRecord<'info> {
                 &mut self.accounts.record.try_borrow_mut_data()?,
                 OwnerType::Pubkey,
             )?;
+            Record::update_mint_bump_unchecked(
+                &mut self.accounts.record.try_borrow_mut_data()?,
+                0,
+            )?;
+            Record::record_mutation_unchecked(
+                &mut self.accounts.record.try_borrow_mut_data()?,
+                BURN_TOKENIZED_RECORD_IX,
+                self.accounts.authority.key(),
+            )?;
         };
 
+        EventType::BurnTokenizedRecord
+            .emit(&[self.accounts.record.key(), self.accounts.mint.key()]);
+
         Ok(())
     }
 }
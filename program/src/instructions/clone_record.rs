@@ -0,0 +1,263 @@
+#[cfg(not(feature = "perf"))]
+use crate::constants::MAX_SEED_LEN;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    error::SrsError,
+    events::EventType,
+    state::{
+        Class, ClassStats, OwnerType, Record, RecordMutation, CLASS_OFFSET, MAX_RECENT_MUTATIONS,
+        MAX_RECORD_SIZE, NON_TRANSFERABLE_OFFSET, OWNER_OFFSET, OWNER_TYPE_OFFSET,
+    },
+    utils::{ByteReader, Context},
+};
+
+/// CloneRecord instruction.
+///
+/// This function:
+/// 1. Validates the authority over the source record
+/// 2. Derives the PDA for the destination record from the new seed
+/// 3. Creates the destination record account
+/// 4. Copies the source record data into the destination record
+/// 5. Creates the class stats account if needed and counts the new record
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to clone the record (must be a signer)
+/// 2. `payer` - The account that will pay for the destination record account
+/// 3. `source_record` - The record account to be cloned
+/// 4. `destination_record` - The new record account to be created
+/// 5. `class` - The class of the source record
+/// 6. `system_program` - Required for creating the destination record
+/// 7. `class_stats` - The stats account of the class, created on the first record
+///
+/// # Security
+/// 1. The authority must be either:
+///    a. The source record owner, or
+///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 2. If the class is permissioned, the authority must be the class authority
+/// 3. The class must not be frozen
+/// 4. The source record must not be tokenized or expired
+/// 5. The class must not already hold `max_records` records
+/// 6. The destination record must be the record PDA of the class and the new seed
+/// 7. The destination record account must not exceed `MAX_RECORD_SIZE`
+pub struct CloneRecordAccounts<'info> {
+    payer: &'info AccountInfo,
+    source_record: &'info AccountInfo,
+    destination_record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CloneRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, source_record, destination_record, class, _system_program, class_stats] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the source record owner or has a delegate
+        Record::check_owner_or_delegate(source_record, Some(class), authority)?;
+
+        // Check class permission, creating a record in a permissioned class
+        // requires the class authority
+        Class::check_permission(class, Some(authority))?;
+
+        let data = source_record.try_borrow_data()?;
+
+        // Check that the class of the source record is the class passed in
+        if data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()].ne(class.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Tokenized records are owned by their mint and cannot be cloned
+        if data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Pubkey)) {
+            return Err(SrsError::AlreadyTokenized.into());
+        }
+
+        // Check if the source record has expired
+        unsafe { Record::check_not_expired_unchecked(&data)? };
+
+        Ok(Self {
+            payer,
+            source_record,
+            destination_record,
+            class,
+            class_stats,
+        })
+    }
+}
+
+pub struct CloneRecord<'info> {
+    accounts: CloneRecordAccounts<'info>,
+    seed: &'info [u8],
+}
+
+/// Minimum length of instruction data required for CloneRecord
+pub const CLONE_RECORD_MIN_IX_LENGTH: usize = size_of::<u8>() * 2;
+
+impl<'info> TryFrom<Context<'info>> for CloneRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CloneRecordAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < CLONE_RECORD_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `seed`
+        let seed: &[u8] = ByteReader::new(ctx.data).read_bytes_with_length()?;
+
+        #[cfg(not(feature = "perf"))]
+        if seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self { accounts, seed })
+    }
+}
+
+impl<'info> CloneRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Clone Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Create the stats account of the class if it does not exist yet
+        ClassStats::initialize_if_needed(
+            self.accounts.class_stats,
+            self.accounts.class.key(),
+            self.accounts.payer,
+        )?;
+
+        // Check the class record count limit
+        unsafe {
+            Class::check_record_count_unchecked(
+                &self.accounts.class.try_borrow_data()?,
+                &self.accounts.class_stats.try_borrow_data()?,
+            )?;
+        }
+
+        let source_data = self.accounts.source_record.try_borrow_data()?;
+        let data = unsafe { Record::get_data_unchecked(&source_data)? };
+
+        let space = Record::MINIMUM_RECORD_SIZE + self.seed.len() + data.len();
+
+        // Check that the destination record account does not exceed the maximum record size
+        if space > MAX_RECORD_SIZE {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let rent = Rent::get()?.minimum_balance(space);
+        let lamports = rent.saturating_sub(self.accounts.destination_record.lamports());
+
+        let seeds = [b"record", self.accounts.class.key().as_ref(), self.seed];
+
+        let (address, bump) =
+            try_find_program_address(&seeds, &crate::ID).ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the destination record is the record PDA of the class and seed
+        if address.ne(self.accounts.destination_record.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
+
+        let seeds = [
+            Seed::from(b"record"),
+            Seed::from(self.accounts.class.key()),
+            Seed::from(self.seed),
+            Seed::from(&bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        // Create the account with our program as owner
+        if self.accounts.destination_record.lamports() > 0 {
+            Allocate {
+                account: self.accounts.destination_record,
+                space: space as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: self.accounts.destination_record,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+
+            if self.accounts.destination_record.lamports() < lamports {
+                Transfer {
+                    from: self.accounts.payer,
+                    to: self.accounts.destination_record,
+                    lamports: lamports - self.accounts.destination_record.lamports(),
+                }
+                .invoke()?;
+            }
+        } else {
+            CreateAccount {
+                from: self.accounts.payer,
+                to: self.accounts.destination_record,
+                lamports,
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let record = Record {
+            class: *self.accounts.class.key(),
+            owner_type: OwnerType::Pubkey,
+            owner: source_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]
+                .try_into()
+                .unwrap(),
+            is_frozen: false,
+            non_transferable: source_data[NON_TRANSFERABLE_OFFSET] == 1,
+            expiry: 0,
+            created_at: now,
+            updated_at: now,
+            revision: 0,
+            mutation_cursor: 0,
+            recent_mutations: [RecordMutation::default(); MAX_RECENT_MUTATIONS],
+            bump: bump[0],
+            mint_bump: 0,
+            seed: self.seed,
+            data,
+        };
+
+        unsafe {
+            record.initialize_unchecked(self.accounts.destination_record)?;
+            ClassStats::add_record_unchecked(self.accounts.class_stats, data.len())?;
+        }
+
+        EventType::CloneRecord.emit(&[
+            self.accounts.class.key(),
+            self.accounts.source_record.key(),
+            self.accounts.destination_record.key(),
+        ]);
+
+        Ok(())
+    }
+}
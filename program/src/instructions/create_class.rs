@@ -1,5 +1,5 @@
 #[cfg(not(feature = "perf"))]
-use crate::constants::{MAX_METADATA_LEN, MAX_SEED_LEN};
+use crate::constants::MAX_METADATA_LEN;
 #[cfg(not(feature = "perf"))]
 use pinocchio::log::sol_log;
 
@@ -15,8 +15,9 @@ use pinocchio::{
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
 use crate::{
-    state::Class,
-    utils::{ByteReader, Context},
+    events::EventType,
+    state::{Class, ClassRegistry, ClassStats},
+    utils::{is_system_program, ByteReader, Context},
 };
 
 /// CreateClass instruction.
@@ -27,25 +28,42 @@ use crate::{
 /// 3. Creates the new account
 /// 4. Transfers the minimum rent needed to make the account rent-exempt
 /// 5. Initializes the class data
+/// 6. Appends the class to the class registry of the authority, creating the
+///    registry and its page if needed
+/// 7. Creates the class stats account, which counts every record of the class
 ///
 /// # Accounts
 /// 1. `authority` - The account that will own the class (must be a signer)
 /// 2. `payer` - The account that will pay for the class account
 /// 3. `class` - The new class account to be created
+/// 4. `class_registry` - The class registry of the authority, created on the first class
+/// 5. `class_registry_page` - The page of the class registry the class is appended to
+/// 6. `system_program` - Required for creating the class and registry accounts
+/// 7. `class_stats` - The stats account of the class
 ///
 /// # Security
 /// 1. The authority account must be a signer
+/// 2. The authority must not be the zero address
+/// 3. `class` must be the class PDA of the authority and name
+/// 4. `class_registry` and `class_registry_page` must be the registry PDAs of
+///    the authority, the page being the one the next class is appended to
+/// 5. `class_stats` must be the class stats PDA of the class
 pub struct CreateClassAccounts<'info> {
     authority: &'info AccountInfo,
     payer: &'info AccountInfo,
     class: &'info AccountInfo,
+    class_registry: &'info AccountInfo,
+    class_registry_page: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
 }
 
 impl<'info> TryFrom<&'info [AccountInfo]> for CreateClassAccounts<'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, payer, class, _system_program] = accounts else {
+        let [authority, payer, class, class_registry, class_registry_page, _system_program, class_stats] =
+            accounts
+        else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -54,28 +72,42 @@ impl<'info> TryFrom<&'info [AccountInfo]> for CreateClassAccounts<'info> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        // Check that the authority is not the zero address
+        if is_system_program(authority.key()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
         Ok(Self {
             authority,
             payer,
             class,
+            class_registry,
+            class_registry_page,
+            class_stats,
         })
     }
 }
 
 const IS_PERMISSIONED_OFFSET: usize = 0;
 const IS_FROZEN_OFFSET: usize = IS_PERMISSIONED_OFFSET + size_of::<bool>();
-const NAME_LEN_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const MAX_RECORD_SIZE_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const MAX_RECORDS_OFFSET: usize = MAX_RECORD_SIZE_OFFSET + size_of::<u32>();
+const NAME_LEN_OFFSET: usize = MAX_RECORDS_OFFSET + size_of::<u32>();
 
 pub struct CreateClass<'info> {
     accounts: CreateClassAccounts<'info>,
     is_permissioned: bool,
     is_frozen: bool,
+    max_record_size: u32,
+    max_records: u32,
     name: &'info str,
+    metadata_schema_uri: Option<&'info str>,
     metadata: &'info str,
 }
 
 /// Minimum length of instruction data required for CreateClass
-pub const CREATE_CLASS_MIN_IX_LENGTH: usize = size_of::<bool>() * 2 + size_of::<u8>();
+pub const CREATE_CLASS_MIN_IX_LENGTH: usize =
+    size_of::<bool>() * 2 + size_of::<u32>() * 2 + size_of::<u8>() * 2;
 
 impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
     type Error = ProgramError;
@@ -96,6 +128,12 @@ impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
         // Deserialize `is_frozen`
         let is_frozen: bool = ByteReader::read_with_offset(ctx.data, IS_FROZEN_OFFSET)?;
 
+        // Deserialize `max_record_size`
+        let max_record_size: u32 = ByteReader::read_with_offset(ctx.data, MAX_RECORD_SIZE_OFFSET)?;
+
+        // Deserialize `max_records`
+        let max_records: u32 = ByteReader::read_with_offset(ctx.data, MAX_RECORDS_OFFSET)?;
+
         // Read the variable length data
         let mut variable_data: ByteReader<'info> =
             ByteReader::new_with_offset(ctx.data, NAME_LEN_OFFSET);
@@ -108,6 +146,10 @@ impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
             return Err(ProgramError::InvalidArgument);
         }
 
+        // Read the metadata schema uri, an empty string means no schema
+        let metadata_schema_uri: &'info str = variable_data.read_str_with_length()?;
+        let metadata_schema_uri = (!metadata_schema_uri.is_empty()).then_some(metadata_schema_uri);
+
         // Read the remaining data as metadata
         let metadata: &'info str = variable_data.read_str(variable_data.remaining_bytes())?;
 
@@ -120,7 +162,10 @@ impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
             accounts,
             is_permissioned,
             is_frozen,
+            max_record_size,
+            max_records,
             name,
+            metadata_schema_uri,
             metadata,
         })
     }
@@ -134,7 +179,10 @@ impl<'info> CreateClass<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        let space = Class::MINIMUM_CLASS_SIZE + self.name.len() + self.metadata.len();
+        let space = Class::MINIMUM_CLASS_SIZE
+            + self.name.len()
+            + self.metadata_schema_uri.map_or(0, str::len)
+            + self.metadata.len();
         let rent = Rent::get()?.minimum_balance(space);
         let lamports = rent.saturating_sub(self.accounts.class.lamports());
 
@@ -144,9 +192,16 @@ impl<'info> CreateClass<'info> {
             self.name.as_bytes(),
         ];
 
-        let bump: [u8; 1] = [try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1];
+        let (address, bump) =
+            try_find_program_address(&seeds, &crate::ID).ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the class is the class PDA of the authority and name, a
+        // signer keypair would otherwise be created as a class
+        if address.ne(self.accounts.class.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
 
         let seeds = [
             Seed::from(b"class"),
@@ -188,16 +243,40 @@ impl<'info> CreateClass<'info> {
                 owner: &crate::ID,
             }
             .invoke_signed(&signers)?;
-        }        
+        }
 
         let class = Class {
             authority: *self.accounts.authority.key(),
             is_permissioned: self.is_permissioned,
             is_frozen: self.is_frozen,
+            transfer_fee_bps: 0,
+            fee_destination: [0u8; 32],
+            max_record_size: self.max_record_size,
+            max_records: self.max_records,
+            bump: bump[0],
             name: self.name,
+            metadata_schema_uri: self.metadata_schema_uri,
             metadata: self.metadata,
         };
 
-        unsafe { class.initialize_unchecked(self.accounts.class) }
+        unsafe { class.initialize_unchecked(self.accounts.class)? };
+
+        ClassRegistry::append_class(
+            self.accounts.class_registry,
+            self.accounts.class_registry_page,
+            self.accounts.authority.key(),
+            self.accounts.class.key(),
+            self.accounts.payer,
+        )?;
+
+        ClassStats::initialize_if_needed(
+            self.accounts.class_stats,
+            self.accounts.class.key(),
+            self.accounts.payer,
+        )?;
+
+        EventType::CreateClass.emit(&[self.accounts.class.key(), self.accounts.authority.key()]);
+
+        Ok(())
     }
 }
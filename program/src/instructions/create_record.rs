@@ -5,12 +5,16 @@ use pinocchio::log::sol_log;
 
 use core::mem::size_of;
 use pinocchio::{
-    account_info::AccountInfo, instruction::{Seed, Signer}, log::sol_log_64, program_error::ProgramError, pubkey::try_find_program_address, sysvars::{rent::Rent, Sysvar}, ProgramResult
+    account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::try_find_program_address, sysvars::{clock::Clock, rent::Rent, Sysvar}, ProgramResult
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
 use crate::{
-    state::{Class, OwnerType, Record},
+    events::EventType,
+    state::{
+        Class, ClassStats, OwnerType, Record, RecordAccessList, RecordMutation,
+        MAX_RECENT_MUTATIONS, MAX_RECORD_SIZE,
+    },
     utils::{ByteReader, Context},
 };
 
@@ -21,55 +25,106 @@ use crate::{
 /// 2. Derives the PDA for the record account
 /// 3. Creates the new account
 /// 4. Initializes the record data
+/// 5. Creates the class stats account if needed and counts the new record
 ///
 /// # Accounts
 /// 1. `owner` - The account that will own the record
 /// 2. `payer` - The account that will pay for the record account
 /// 3. `class` - The class account that this record belongs to
 /// 4. `record` - The new record account to be created
-/// 5. `authority` - [as remaining accounts] The authority account of the class
+/// 5. `system_program` - Required for creating the record account
+/// 6. `class_stats` - The stats account of the class, created on the first record
+/// 7. `authority` - [as remaining accounts] The authority account of the class
+/// 8. `access_list` - [as remaining accounts] The record access list of the record
 ///
 /// # Security
 /// 1. Check if the class is permissioned, if so, the instruction must pass
-///    the class authority as signer in the remaining accounts
+///    the class authority as signer in the remaining accounts, or a record
+///    access list listing the owner, who must then sign
 /// 2. The class must not be frozen
+/// 3. The record data must not exceed the class `max_record_size`
+/// 4. The class must not already hold `max_records` records
+/// 5. The record account must not exceed `MAX_RECORD_SIZE`
+/// 6. A non-zero expiry must be in the future
+/// 7. `record` must be the record PDA of the class and seed
 pub struct CreateRecordAccounts<'info> {
     owner: &'info AccountInfo,
     payer: &'info AccountInfo,
     class: &'info AccountInfo,
     record: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for CreateRecordAccounts<'info> {
+/// Required accounts of CreateRecord, the class authority and record access list follow as optional
+/// accounts
+pub const CREATE_RECORD_FIXED_ACCOUNTS: usize = 6;
+
+impl<'info> TryFrom<&Context<'info>> for CreateRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [owner, payer, class, record, _system_program, rest @ ..] = accounts else {
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [owner, payer, class, record, _system_program, class_stats] = ctx.accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        sol_log_64(0, 0, 0, 0, 0);
-
-        // Check class permission
-        Class::check_permission(class, rest.first())?;
+        // Check if the owner is listed in the record access list
+        // (the SRS program id is passed as a placeholder when no access list is provided)
+        let is_listed = match ctx
+            .remaining_accounts
+            .get(1)
+            .filter(|access_list| access_list.key().ne(&crate::ID))
+        {
+            Some(access_list) => {
+                owner.is_signer() && RecordAccessList::contains(access_list, record, owner.key())?
+            }
+            None => false,
+        };
 
-        sol_log_64(0, 0, 0, 0, 0);
+        // Check class permission, listed owners do not need the class authority
+        if is_listed {
+            Class::check_not_frozen(class)?;
+        } else {
+            Class::check_permission(class, ctx.remaining_accounts.first())?;
+        }
 
         Ok(Self {
             owner,
             payer,
             class,
             record,
+            class_stats,
         })
     }
 }
 
+impl<'info> CreateRecordAccounts<'info> {
+    /// Builds the accounts of a record whose class permission was already
+    /// checked by the caller
+    pub(crate) fn new_unchecked(
+        owner: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        class: &'info AccountInfo,
+        record: &'info AccountInfo,
+        class_stats: &'info AccountInfo,
+    ) -> Self {
+        Self {
+            owner,
+            payer,
+            class,
+            record,
+            class_stats,
+        }
+    }
+}
+
 const EXPIRY_OFFSET: usize = 0;
-const SEED_LEN_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const NON_TRANSFERABLE_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const SEED_LEN_OFFSET: usize = NON_TRANSFERABLE_OFFSET + size_of::<bool>();
 
 pub struct CreateRecord<'info> {
     accounts: CreateRecordAccounts<'info>,
     expiry: i64,
+    non_transferable: bool,
     seed: &'info [u8],
     data: &'info str,
 }
@@ -81,10 +136,8 @@ impl<'info> TryFrom<Context<'info>> for CreateRecord<'info> {
     type Error = ProgramError;
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
-        sol_log_64(0, 0, 0, 0, 0);
-
         // Deserialize our accounts array
-        let accounts = CreateRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = CreateRecordAccounts::try_from(&ctx)?;
 
         // Check minimum instruction data length
         #[cfg(not(feature = "perf"))]
@@ -95,6 +148,10 @@ impl<'info> TryFrom<Context<'info>> for CreateRecord<'info> {
         // Deserialize `expiry`
         let expiry: i64 = ByteReader::read_with_offset(ctx.data, EXPIRY_OFFSET)?;
 
+        // Deserialize `non_transferable`
+        let non_transferable: bool =
+            ByteReader::read_with_offset(ctx.data, NON_TRANSFERABLE_OFFSET)?;
+
         // Deserialize variable length data
         let mut variable_data: ByteReader<'info> =
             ByteReader::new_with_offset(ctx.data, SEED_LEN_OFFSET);
@@ -102,24 +159,50 @@ impl<'info> TryFrom<Context<'info>> for CreateRecord<'info> {
         // Deserialize `seed`
         let seed: &[u8] = variable_data.read_bytes_with_length()?;
 
+        // Deserialize `data`
+        let data: &str = variable_data.read_str(variable_data.remaining_bytes())?;
+
+        Self::new(accounts, expiry, non_transferable, seed, data)
+    }
+}
+
+impl<'info> CreateRecord<'info> {
+    /// Validates the record arguments against the class and builds the
+    /// instruction, shared with `BatchCreateRecord`
+    pub(crate) fn new(
+        accounts: CreateRecordAccounts<'info>,
+        expiry: i64,
+        non_transferable: bool,
+        seed: &'info [u8],
+        data: &'info str,
+    ) -> Result<Self, ProgramError> {
+        // Check that the record is not created already expired
+        if expiry != 0 && expiry <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
         #[cfg(not(feature = "perf"))]
         if seed.len() > MAX_SEED_LEN {
             return Err(ProgramError::InvalidArgument);
         }
 
-        // Deserialize `data`
-        let data: &str = variable_data.read_str(variable_data.remaining_bytes())?;
+        // Check that the record account does not exceed the maximum record size
+        if Record::MINIMUM_RECORD_SIZE + seed.len() + data.len() > MAX_RECORD_SIZE {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // Check the data against the class record size limit
+        unsafe { Class::check_record_size_unchecked(&accounts.class.try_borrow_data()?, data.len())? };
 
         Ok(Self {
             accounts,
             expiry,
+            non_transferable,
             seed,
             data,
         })
     }
-}
 
-impl<'info> CreateRecord<'info> {
     pub fn process(ctx: Context<'info>) -> ProgramResult {
         #[cfg(not(feature = "perf"))]
         sol_log("Create Record");
@@ -127,15 +210,37 @@ impl<'info> CreateRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
+        // Create the stats account of the class if it does not exist yet
+        ClassStats::initialize_if_needed(
+            self.accounts.class_stats,
+            self.accounts.class.key(),
+            self.accounts.payer,
+        )?;
+
+        // Check the class record count limit
+        unsafe {
+            Class::check_record_count_unchecked(
+                &self.accounts.class.try_borrow_data()?,
+                &self.accounts.class_stats.try_borrow_data()?,
+            )?;
+        }
+
         let space = Record::MINIMUM_RECORD_SIZE + self.seed.len() + self.data.len();
         let rent = Rent::get()?.minimum_balance(space);
         let lamports = rent.saturating_sub(self.accounts.record.lamports());
 
         let seeds = [b"record", self.accounts.class.key().as_ref(), self.seed];
 
-        let bump: [u8; 1] = [try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1];
+        let (address, bump) =
+            try_find_program_address(&seeds, &crate::ID).ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the record is the record PDA of the class and seed, a
+        // signer keypair would otherwise be created as a record
+        if address.ne(self.accounts.record.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
 
         let seeds = [
             Seed::from(b"record"),
@@ -179,16 +284,37 @@ impl<'info> CreateRecord<'info> {
             .invoke_signed(&signers)?;
         }    
 
+        let now = Clock::get()?.unix_timestamp;
+
         let record = Record {
             class: *self.accounts.class.key(),
             owner_type: OwnerType::Pubkey,
             owner: *self.accounts.owner.key(),
             is_frozen: false,
+            non_transferable: self.non_transferable,
             expiry: self.expiry,
+            created_at: now,
+            updated_at: now,
+            revision: 0,
+            mutation_cursor: 0,
+            recent_mutations: [RecordMutation::default(); MAX_RECENT_MUTATIONS],
+            bump: bump[0],
+            mint_bump: 0,
             seed: self.seed,
             data: self.data,
         };
 
-        unsafe { record.initialize_unchecked(self.accounts.record) }
+        unsafe {
+            record.initialize_unchecked(self.accounts.record)?;
+            ClassStats::add_record_unchecked(self.accounts.class_stats, self.data.len())?;
+        }
+
+        EventType::CreateRecord.emit(&[
+            self.accounts.class.key(),
+            self.accounts.record.key(),
+            self.accounts.owner.key(),
+        ]);
+
+        Ok(())
     }
 }
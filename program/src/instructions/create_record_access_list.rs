@@ -0,0 +1,187 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    events::EventType,
+    state::{Class, Record, RecordAccessList},
+    utils::{ByteReader, Context},
+};
+
+/// CreateRecordAccessList instruction.
+///
+/// This function:
+/// 1. Validates the class authority and the record address
+/// 2. Derives the PDA for the record access list account
+/// 3. Creates the new account
+/// 4. Initializes the access list with the listed accounts
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will pay for the access list account
+/// 3. `class` - The class of the record
+/// 4. `record` - The record the access list applies to, which may not exist yet
+/// 5. `access_list` - The new record access list account to be created
+/// 6. `system_program` - Required for creating the access list account
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The record must be the record PDA of the class for the given seed
+/// 3. The access list must not list more than 32 accounts
+/// 4. The access list must be the access list PDA of the record
+pub struct CreateRecordAccessListAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    record: &'info AccountInfo,
+    access_list: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CreateRecordAccessListAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, record, access_list, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        Ok(Self {
+            payer,
+            class,
+            record,
+            access_list,
+        })
+    }
+}
+
+pub struct CreateRecordAccessList<'info> {
+    accounts: CreateRecordAccessListAccounts<'info>,
+    entries: &'info [u8],
+}
+
+/// Minimum length of instruction data required for CreateRecordAccessList
+pub const CREATE_RECORD_ACCESS_LIST_MIN_IX_LENGTH: usize = size_of::<u8>() * 2;
+
+impl<'info> TryFrom<Context<'info>> for CreateRecordAccessList<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CreateRecordAccessListAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < CREATE_RECORD_ACCESS_LIST_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `seed`
+        let seed: &[u8] = instruction_data.read_bytes_with_length()?;
+
+        // Check if the record is the record of the class for this seed
+        Record::check_address(accounts.record, accounts.class.key(), seed)?;
+
+        // Deserialize `entries`
+        let entries_len: u8 = instruction_data.read()?;
+        let entries: &[u8] =
+            instruction_data.read_bytes(entries_len as usize * size_of::<Pubkey>())?;
+
+        RecordAccessList::check_entries(entries)?;
+
+        Ok(Self { accounts, entries })
+    }
+}
+
+impl<'info> CreateRecordAccessList<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Record Access List");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let space = RecordAccessList::size(self.entries.len());
+        let rent = Rent::get()?.minimum_balance(space);
+        let lamports = rent.saturating_sub(self.accounts.access_list.lamports());
+
+        let seeds = [b"acl", self.accounts.record.key().as_ref()];
+
+        let (address, bump) = try_find_program_address(&seeds, &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the access list is the access list PDA of the record
+        if address.ne(self.accounts.access_list.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
+
+        let seeds = [
+            Seed::from(b"acl"),
+            Seed::from(self.accounts.record.key()),
+            Seed::from(&bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        // Create the account with our program as owner
+        if self.accounts.access_list.lamports() > 0 {
+            Allocate {
+                account: self.accounts.access_list,
+                space: space as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: self.accounts.access_list,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+
+            if self.accounts.access_list.lamports() < lamports {
+                Transfer {
+                    from: self.accounts.payer,
+                    to: self.accounts.access_list,
+                    lamports: lamports - self.accounts.access_list.lamports(),
+                }
+                .invoke()?;
+            }
+        } else {
+            CreateAccount {
+                from: self.accounts.payer,
+                to: self.accounts.access_list,
+                lamports,
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        let access_list = RecordAccessList {
+            class: *self.accounts.class.key(),
+            record: *self.accounts.record.key(),
+            entries: self.entries,
+        };
+
+        unsafe { access_list.initialize_unchecked(self.accounts.access_list)? };
+
+        EventType::CreateRecordAccessList
+            .emit(&[self.accounts.record.key(), self.accounts.access_list.key()]);
+
+        Ok(())
+    }
+}
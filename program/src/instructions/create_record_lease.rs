@@ -0,0 +1,92 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+
+use crate::{
+    instructions::grant_record_lease::{GrantRecordLease, GrantRecordLeaseAccounts},
+    state::RecordLease,
+    utils::{ByteReader, Context},
+};
+
+/// CreateRecordLease instruction.
+///
+/// This function:
+/// 1. Maps the `can_update` and `can_freeze` flags to lease permissions
+/// 2. Creates the lease as `GrantRecordLease` does
+///
+/// # Accounts
+/// 1. `authority` - The class authority granting the lease (must be a signer)
+/// 2. `payer` - The account that will pay for the lease account
+/// 3. `record` - The record the lease grants access to
+/// 4. `class` - The class of the record
+/// 5. `lease` - The new record lease account to be created
+/// 6. `system_program` - Required for creating the lease account
+///
+/// # Security
+/// 1. The lease is validated as in `GrantRecordLease`
+/// 2. The lease must allow the lessee to update or to freeze the record
+pub struct CreateRecordLease<'info> {
+    lease: GrantRecordLease<'info>,
+}
+
+const LESSEE_OFFSET: usize = 0;
+const EXPIRY_OFFSET: usize = LESSEE_OFFSET + size_of::<Pubkey>();
+const CAN_UPDATE_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const CAN_FREEZE_OFFSET: usize = CAN_UPDATE_OFFSET + size_of::<u8>();
+
+/// Minimum length of instruction data required for CreateRecordLease
+pub const CREATE_RECORD_LEASE_MIN_IX_LENGTH: usize =
+    size_of::<Pubkey>() + size_of::<i64>() + size_of::<u8>() * 2;
+
+impl<'info> TryFrom<Context<'info>> for CreateRecordLease<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = GrantRecordLeaseAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < CREATE_RECORD_LEASE_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `lessee`
+        let lessee: Pubkey = ByteReader::read_with_offset(ctx.data, LESSEE_OFFSET)?;
+
+        // Deserialize `expiry`
+        let expiry: i64 = ByteReader::read_with_offset(ctx.data, EXPIRY_OFFSET)?;
+
+        // Deserialize `can_update`
+        let can_update: bool = ByteReader::read_with_offset(ctx.data, CAN_UPDATE_OFFSET)?;
+
+        // Deserialize `can_freeze`
+        let can_freeze: bool = ByteReader::read_with_offset(ctx.data, CAN_FREEZE_OFFSET)?;
+
+        let mut permissions = 0;
+        if can_update {
+            permissions |= RecordLease::PERMISSION_UPDATE_DATA;
+        }
+        if can_freeze {
+            permissions |= RecordLease::PERMISSION_FREEZE;
+        }
+
+        Ok(Self {
+            lease: GrantRecordLease::new(accounts, lessee, expiry, permissions)?,
+        })
+    }
+}
+
+impl<'info> CreateRecordLease<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Record Lease");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        self.lease.execute()
+    }
+}
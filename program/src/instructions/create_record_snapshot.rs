@@ -0,0 +1,179 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::try_find_program_address,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    events::EventType,
+    state::{Record, RecordSnapshot},
+    utils::{sha256, Context},
+};
+
+/// CreateRecordSnapshot instruction.
+///
+/// This function:
+/// 1. Validates the record owner
+/// 2. Derives the PDA for the snapshot account from the record revision
+/// 3. Creates the new account
+/// 4. Stores the record revision, the current slot and timestamp and the hash
+///    of the record data
+///
+/// # Accounts
+/// 1. `authority` - The owner of the record (must be a signer)
+/// 2. `payer` - The account that will pay for the snapshot account
+/// 3. `record` - The record to take a snapshot of
+/// 4. `snapshot` - The new record snapshot account to be created
+/// 5. `system_program` - Required for creating the snapshot account
+///
+/// # Security
+/// 1. The authority must be the record owner
+/// 2. Only one snapshot can exist per record revision
+/// 3. The snapshot must be the snapshot PDA of the record revision
+pub struct CreateRecordSnapshotAccounts<'info> {
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    snapshot: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CreateRecordSnapshotAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, record, snapshot, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the record owner
+        Record::check_owner_or_delegate(record, None, authority)?;
+
+        Ok(Self {
+            payer,
+            record,
+            snapshot,
+        })
+    }
+}
+
+pub struct CreateRecordSnapshot<'info> {
+    accounts: CreateRecordSnapshotAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CreateRecordSnapshot<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CreateRecordSnapshotAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CreateRecordSnapshot<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Record Snapshot");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let (revision, data_hash) = {
+            let data = self.accounts.record.try_borrow_data()?;
+            unsafe {
+                (
+                    Record::get_revision_unchecked(&data),
+                    sha256(Record::get_data_unchecked(&data)?.as_bytes()),
+                )
+            }
+        };
+
+        let space = RecordSnapshot::RECORD_SNAPSHOT_SIZE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let lamports = rent.saturating_sub(self.accounts.snapshot.lamports());
+
+        let revision_bytes = revision.to_le_bytes();
+
+        let seeds: [&[u8]; 3] = [
+            b"snapshot",
+            self.accounts.record.key().as_ref(),
+            &revision_bytes,
+        ];
+
+        let (address, bump) = try_find_program_address(&seeds, &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the snapshot is the snapshot PDA of the record revision
+        if address.ne(self.accounts.snapshot.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
+
+        let seeds = [
+            Seed::from(b"snapshot"),
+            Seed::from(self.accounts.record.key()),
+            Seed::from(&revision_bytes),
+            Seed::from(&bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        // Create the account with our program as owner
+        if self.accounts.snapshot.lamports() > 0 {
+            Allocate {
+                account: self.accounts.snapshot,
+                space: space as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: self.accounts.snapshot,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+
+            if self.accounts.snapshot.lamports() < lamports {
+                Transfer {
+                    from: self.accounts.payer,
+                    to: self.accounts.snapshot,
+                    lamports: lamports - self.accounts.snapshot.lamports(),
+                }
+                .invoke()?;
+            }
+        } else {
+            CreateAccount {
+                from: self.accounts.payer,
+                to: self.accounts.snapshot,
+                lamports,
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        let clock = Clock::get()?;
+
+        let snapshot = RecordSnapshot {
+            record: *self.accounts.record.key(),
+            revision,
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            data_hash,
+        };
+
+        unsafe { snapshot.initialize_unchecked(self.accounts.snapshot)? };
+
+        EventType::CreateRecordSnapshot
+            .emit(&[self.accounts.record.key(), self.accounts.snapshot.key()]);
+
+        Ok(())
+    }
+}
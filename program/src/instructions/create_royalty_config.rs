@@ -0,0 +1,193 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    events::EventType,
+    state::{Class, RoyaltyConfig},
+    utils::{is_system_program, ByteReader, Context},
+};
+
+/// CreateRoyaltyConfig instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Derives the PDA for the royalty config account
+/// 3. Creates the new account
+/// 4. Initializes the royalty config with the basis points and destination
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will pay for the royalty config account
+/// 3. `class` - The class the royalties apply to
+/// 4. `royalty_config` - The new royalty config account to be created
+/// 5. `system_program` - Required for creating the royalty config account
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The basis points must not exceed 10000
+/// 3. The destination must not be the zero address
+/// 4. The royalty config must be the royalty config PDA of the class
+pub struct CreateRoyaltyConfigAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    royalty_config: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CreateRoyaltyConfigAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, royalty_config, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        Ok(Self {
+            payer,
+            class,
+            royalty_config,
+        })
+    }
+}
+
+const BASIS_POINTS_OFFSET: usize = 0;
+const DESTINATION_OFFSET: usize = BASIS_POINTS_OFFSET + size_of::<u16>();
+
+pub struct CreateRoyaltyConfig<'info> {
+    accounts: CreateRoyaltyConfigAccounts<'info>,
+    basis_points: u16,
+    destination: Pubkey,
+}
+
+/// Minimum length of instruction data required for CreateRoyaltyConfig
+pub const CREATE_ROYALTY_CONFIG_MIN_IX_LENGTH: usize = size_of::<u16>() + size_of::<Pubkey>();
+
+impl<'info> TryFrom<Context<'info>> for CreateRoyaltyConfig<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CreateRoyaltyConfigAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < CREATE_ROYALTY_CONFIG_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `basis_points`
+        let basis_points: u16 = ByteReader::read_with_offset(ctx.data, BASIS_POINTS_OFFSET)?;
+
+        // Deserialize `destination`
+        let destination: Pubkey = ByteReader::read_with_offset(ctx.data, DESTINATION_OFFSET)?;
+
+        // Check that the royalty does not exceed the transfer value
+        if basis_points > RoyaltyConfig::MAX_BASIS_POINTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Check that the royalties are not sent to the zero address
+        if is_system_program(&destination) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            accounts,
+            basis_points,
+            destination,
+        })
+    }
+}
+
+impl<'info> CreateRoyaltyConfig<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Royalty Config");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let space = RoyaltyConfig::ROYALTY_CONFIG_SIZE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let lamports = rent.saturating_sub(self.accounts.royalty_config.lamports());
+
+        let seeds = [b"royalty", self.accounts.class.key().as_ref()];
+
+        let (address, bump) = try_find_program_address(&seeds, &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the royalty config is the royalty config PDA of the class
+        if address.ne(self.accounts.royalty_config.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
+
+        let seeds = [
+            Seed::from(b"royalty"),
+            Seed::from(self.accounts.class.key()),
+            Seed::from(&bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        // Create the account with our program as owner
+        if self.accounts.royalty_config.lamports() > 0 {
+            Allocate {
+                account: self.accounts.royalty_config,
+                space: space as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: self.accounts.royalty_config,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+
+            if self.accounts.royalty_config.lamports() < lamports {
+                Transfer {
+                    from: self.accounts.payer,
+                    to: self.accounts.royalty_config,
+                    lamports: lamports - self.accounts.royalty_config.lamports(),
+                }
+                .invoke()?;
+            }
+        } else {
+            CreateAccount {
+                from: self.accounts.payer,
+                to: self.accounts.royalty_config,
+                lamports,
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        let royalty_config = RoyaltyConfig {
+            class: *self.accounts.class.key(),
+            basis_points: self.basis_points,
+            destination: self.destination,
+        };
+
+        unsafe { royalty_config.initialize_unchecked(self.accounts.royalty_config)? };
+
+        EventType::CreateRoyaltyConfig
+            .emit(&[self.accounts.class.key(), self.accounts.royalty_config.key()]);
+
+        Ok(())
+    }
+}
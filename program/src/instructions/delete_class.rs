@@ -0,0 +1,93 @@
+use crate::{
+    events::EventType,
+    state::{Class, ClassRegistryPage, ClassStats},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// DeleteClass instruction.
+///
+/// This function:
+/// 1. Checks that the class is not frozen and does not hold any record
+/// 2. Removes the class from its class registry page
+/// 3. Reallocates the class account data to 0 bytes
+/// 4. Transfers the lamports from the class to the payer
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to delete the class (must be a signer)
+/// 2. `payer` - The account that will get refunded for the class account
+/// 3. `class` - The class account to be deleted
+/// 4. `class_registry_page` - The class registry page holding the class
+/// 5. `class_stats` - The stats account of the class
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. A frozen class cannot be deleted
+/// 3. The class must be listed in `class_registry_page`
+/// 4. The class stats must not count any live record, so that no record outlives its class
+pub struct DeleteClassAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    class_registry_page: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for DeleteClassAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, class_registry_page, class_stats] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Account Checks
+        Class::check_authority(class, authority)?;
+
+        // Check if the class still holds records
+        ClassStats::check_no_records(class_stats, class.key())?;
+
+        Ok(Self {
+            payer,
+            class,
+            class_registry_page,
+        })
+    }
+}
+
+pub struct DeleteClass<'info> {
+    accounts: DeleteClassAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for DeleteClass<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = DeleteClassAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> DeleteClass<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Delete Class");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        ClassRegistryPage::remove_class(
+            self.accounts.class_registry_page,
+            self.accounts.class.key(),
+        )?;
+
+        // Safety: The account has already been validated
+        unsafe { Class::delete_class_unchecked(self.accounts.class, self.accounts.payer)? };
+
+        EventType::DeleteClass.emit(&[self.accounts.class.key()]);
+
+        Ok(())
+    }
+}
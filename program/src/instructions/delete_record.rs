@@ -1,6 +1,10 @@
-use crate::{state::Record, utils::Context};
+use crate::{
+    events::EventType,
+    state::{ClassStats, Record},
+    utils::Context,
+};
 #[cfg(not(feature = "perf"))]
-use pinocchio::{log::sol_log, sysvars::{Sysvar, rent::Rent}};
+use pinocchio::log::sol_log;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
 
 /// DeleteRecord instruction.
@@ -10,14 +14,16 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramR
 /// 2. Transfers the lamports from the record to the payer
 /// 3. If the record has an authority delegate, it will close the delegate account
 ///    as well
+/// 4. Removes the record from the class stats
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to delete the record (must be a signer)
 /// 2. `payer` - The account that will get refunded for the record account
 /// 3. `record` - The record account to be deleted
-/// 4. `class` - [optional] The class of the record to be deleted
-/// 5. `token2022_program` - [optional] The token2022 program to be used to close the mint account
-/// 6. `mint` - [optional] The mint of the record to be deleted
+/// 4. `class_stats` - The stats account of the class of the record
+/// 5. `class` - [optional] The class of the record to be deleted
+/// 6. `token2022_program` - [optional] The token2022 program to be used to close the mint account
+/// 7. `mint` - [optional] The mint of the record to be deleted
 ///
 /// # Security
 /// 1. The authority must be either:
@@ -26,22 +32,33 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramR
 pub struct DeleteRecordAccounts<'info> {
     payer: &'info AccountInfo,
     record: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for DeleteRecordAccounts<'info> {
+/// Required accounts of DeleteRecord, the class of a delegate and the mint of a tokenized record
+/// follow as optional accounts
+pub const DELETE_RECORD_FIXED_ACCOUNTS: usize = 4;
+
+impl<'info> TryFrom<&Context<'info>> for DeleteRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, payer, record, rest @ ..] = accounts else {
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, payer, record, class_stats] = ctx.accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         // Check if authority is the record owner or has a delegate
-        Record::check_owner_or_delegate_or_deleted(record, rest.first(), authority, rest.last())?;
+        Record::check_owner_or_delegate_or_deleted(
+            record,
+            ctx.remaining_accounts.first(),
+            authority,
+            ctx.remaining_accounts.get(2),
+        )?;
 
         Ok(Self {
             payer,
             record,
+            class_stats,
         })
     }
 }
@@ -55,7 +72,7 @@ impl<'info> TryFrom<Context<'info>> for DeleteRecord<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = DeleteRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = DeleteRecordAccounts::try_from(&ctx)?;
 
         Ok(Self { accounts })
     }
@@ -69,11 +86,15 @@ impl<'info> DeleteRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
+        ClassStats::remove_record(self.accounts.class_stats, self.accounts.record)?;
+
         // Safety: The account has already been validated
         unsafe {
             Record::delete_record_unchecked(self.accounts.record, self.accounts.payer)?;
         }
 
+        EventType::DeleteRecord.emit(&[self.accounts.record.key()]);
+
         Ok(())
     }
 }
@@ -0,0 +1,84 @@
+use crate::{
+    events::EventType,
+    state::{Class, RecordAccessList},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// DeleteRecordAccessList instruction.
+///
+/// This function:
+/// 1. Validates the class authority and the access list
+/// 2. Reallocates the access list account data to 0 bytes
+/// 3. Transfers the lamports from the access list to the payer
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will get refunded for the access list account
+/// 3. `class` - The class of the record
+/// 4. `access_list` - The record access list account to be closed
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The access list must belong to the class
+pub struct DeleteRecordAccessListAccounts<'info> {
+    payer: &'info AccountInfo,
+    access_list: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for DeleteRecordAccessListAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, access_list] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the access list belongs to the class
+        RecordAccessList::check_class(access_list, class)?;
+
+        Ok(Self { payer, access_list })
+    }
+}
+
+pub struct DeleteRecordAccessList<'info> {
+    accounts: DeleteRecordAccessListAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for DeleteRecordAccessList<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = DeleteRecordAccessListAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> DeleteRecordAccessList<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Delete Record Access List");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        unsafe {
+            RecordAccessList::delete_record_access_list_unchecked(
+                self.accounts.access_list,
+                self.accounts.payer,
+            )?;
+        }
+
+        EventType::DeleteRecordAccessList.emit(&[self.accounts.access_list.key()]);
+
+        Ok(())
+    }
+}
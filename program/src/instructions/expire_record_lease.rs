@@ -0,0 +1,89 @@
+use crate::{
+    events::EventType,
+    state::{Class, RecordLease},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// ExpireRecordLease instruction.
+///
+/// This function:
+/// 1. Validates that the lease belongs to the record and has expired, is stale or outlived the
+///    record
+/// 2. Reallocates the lease account data to 0 bytes
+/// 3. Transfers the lamports from the lease to the class authority
+///
+/// # Accounts
+/// 1. `authority` - The class authority that gets refunded for the lease account
+/// 2. `record` - The record the lease grants access to, which may have been closed
+/// 3. `class` - The class the lease was granted under
+/// 4. `lease` - The expired record lease account to be closed
+///
+/// # Security
+/// 1. Callable by anyone, but only once the lease has expired, the record was closed, or the
+///    lease was granted over a record transferred, recreated or moved to another class since then
+/// 2. The rent is always refunded to the authority of the class the lease was granted under
+pub struct ExpireRecordLeaseAccounts<'info> {
+    authority: &'info AccountInfo,
+    lease: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for ExpireRecordLeaseAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, record, class, lease] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the lease belongs to the record and was granted under the class
+        RecordLease::check_record_and_class(lease, record, class)?;
+
+        // Check if the authority is the class authority, without requiring a signature
+        Class::check_authority_key(class, authority.key())?;
+
+        // Check if the lease has expired, outlived the record or was granted over a previous
+        // record, owner or class
+        if !RecordLease::is_closable(lease, record)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { authority, lease })
+    }
+}
+
+pub struct ExpireRecordLease<'info> {
+    accounts: ExpireRecordLeaseAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for ExpireRecordLease<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = ExpireRecordLeaseAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ExpireRecordLease<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Expire Record Lease");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        unsafe {
+            RecordLease::delete_record_lease_unchecked(self.accounts.lease, self.accounts.authority)?
+        };
+
+        EventType::ExpireRecordLease.emit(&[self.accounts.lease.key()]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    constants::EXTEND_RECORD_EXPIRY_IX,
+    error::SrsError,
+    events::EventType,
+    state::{OwnerType, Record, OWNER_TYPE_OFFSET},
+    utils::{ByteReader, Context},
+};
+#[cfg(not(feature = "perf"))]
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+/// ExtendRecordExpiry instruction.
+///
+/// This function:
+/// 1. Validates the authority and record
+/// 2. Pushes the record expiry forward to `new_expiry`
+/// 3. Sets the record `updated_at` to the current timestamp
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to extend the record (must be a signer)
+/// 2. `record` - The record account to be extended
+/// 3. `class` - [optional] The class of the record, required for delegated extensions
+///
+/// # Security
+/// 1. The authority must be either:
+///    a. The record owner, or
+///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 2. The record must not be tokenized
+/// 3. The record must not be frozen
+/// 4. The new expiry must be in the future and not earlier than the current
+///    expiry, a record that never expires cannot be extended
+pub struct ExtendRecordExpiryAccounts<'info> {
+    authority: &'info AccountInfo,
+    record: &'info AccountInfo,
+}
+
+/// Required accounts of ExtendRecordExpiry, the class of a delegate follows as optional account
+pub const EXTEND_RECORD_EXPIRY_FIXED_ACCOUNTS: usize = 2;
+
+impl<'info> TryFrom<&Context<'info>> for ExtendRecordExpiryAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, record] = ctx.accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the record owner or has a delegate
+        Record::check_owner_or_delegate(record, ctx.remaining_accounts.first(), authority)?;
+
+        // Check that the record is not tokenized
+        if record.try_borrow_data()?[OWNER_TYPE_OFFSET].eq(&u8::from(OwnerType::Token)) {
+            return Err(SrsError::AlreadyTokenized.into());
+        }
+
+        Ok(Self { authority, record })
+    }
+}
+
+pub struct ExtendRecordExpiry<'info> {
+    accounts: ExtendRecordExpiryAccounts<'info>,
+    new_expiry: i64,
+}
+
+impl<'info> TryFrom<Context<'info>> for ExtendRecordExpiry<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = ExtendRecordExpiryAccounts::try_from(&ctx)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < size_of::<i64>() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `new_expiry`
+        let new_expiry: i64 = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        // Check that the new expiry is in the future
+        if new_expiry <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // Check that the new expiry does not shorten the record lifetime
+        let current_expiry =
+            unsafe { Record::get_expiry_unchecked(&accounts.record.try_borrow_data()?) };
+        if current_expiry == 0 || new_expiry < current_expiry {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            accounts,
+            new_expiry,
+        })
+    }
+}
+
+impl<'info> ExtendRecordExpiry<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Extend Record Expiry");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Update the record expiry [this is safe, check safety docs]
+        unsafe {
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_expiry_unchecked(&mut data, self.new_expiry)?;
+            Record::update_updated_at_unchecked(&mut data)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                EXTEND_RECORD_EXPIRY_IX,
+                self.accounts.authority.key(),
+            )?;
+        }
+
+        EventType::ExtendRecordExpiry.emit(&[self.accounts.record.key()]);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    events::EventType,
     state::Class,
     utils::{ByteReader, Context},
 };
@@ -14,6 +15,11 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramR
 /// 2. Updates the frozen status
 /// 3. Saves the updated state
 ///
+/// Without instruction data the class is frozen, and the instruction fails
+/// if it is already frozen. The `is_frozen` argument is still accepted for
+/// backwards compatibility, but is deprecated in favour of `FreezeClass`
+/// without data and `UnfreezeClass`.
+///
 /// # Accounts
 /// 1. `authority` - The account that has permission to freeze/unfreeze the class (must be a signer)
 /// 2. `class` - The class account to be frozen/unfrozen
@@ -42,11 +48,12 @@ impl<'info> TryFrom<&'info [AccountInfo]> for FreezeClassAccounts<'info> {
 const IS_FROZEN_OFFSET: usize = 0;
 pub struct FreezeClass<'info> {
     accounts: FreezeClassAccounts<'info>,
-    is_frozen: bool,
+    /// `None` for the explicit freeze, `Some` for the deprecated combined form
+    is_frozen: Option<bool>,
 }
 
-/// Minimum length of instruction data required for FreezeClass
-pub const FREEZE_CLASS_MIN_IX_LENGTH: usize = size_of::<u8>();
+/// Length of instruction data of the deprecated combined FreezeClass
+pub const FREEZE_CLASS_DEPRECATED_IX_LENGTH: usize = size_of::<u8>();
 
 impl<'info> TryFrom<Context<'info>> for FreezeClass<'info> {
     type Error = ProgramError;
@@ -55,14 +62,12 @@ impl<'info> TryFrom<Context<'info>> for FreezeClass<'info> {
         // Deserialize our accounts array
         let accounts = FreezeClassAccounts::try_from(ctx.accounts)?;
 
-        // Check minimum instruction data length
-        #[cfg(not(feature = "perf"))]
-        if ctx.data.len() < FREEZE_CLASS_MIN_IX_LENGTH {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        // Deserialize `is_frozen`
-        let is_frozen: bool = ByteReader::read_with_offset(ctx.data, IS_FROZEN_OFFSET)?;
+        // Deserialize the deprecated `is_frozen`, if present
+        let is_frozen = if ctx.data.len() >= FREEZE_CLASS_DEPRECATED_IX_LENGTH {
+            Some(ByteReader::read_with_offset(ctx.data, IS_FROZEN_OFFSET)?)
+        } else {
+            None
+        };
 
         Ok(Self {
             accounts,
@@ -79,11 +84,72 @@ impl<'info> FreezeClass<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        unsafe {
-            Class::update_is_frozen_unchecked(
-                self.accounts.class,
-                self.is_frozen,
-            )
-        }
+        let is_frozen = match self.is_frozen {
+            Some(is_frozen) => is_frozen,
+            None => {
+                // Check if the class is already frozen
+                Class::check_not_frozen(self.accounts.class)?;
+
+                true
+            }
+        };
+
+        unsafe { Class::update_is_frozen_unchecked(self.accounts.class, is_frozen)? };
+
+        let event = if is_frozen {
+            EventType::FreezeClass
+        } else {
+            EventType::UnfreezeClass
+        };
+        event.emit(&[self.accounts.class.key()]);
+
+        Ok(())
+    }
+}
+
+/// UnfreezeClass instruction.
+///
+/// This function:
+/// 1. Checks that the class is frozen
+/// 2. Unfreezes the class
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to unfreeze the class (must be a signer)
+/// 2. `class` - The class account to be unfrozen
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. The class must be frozen, otherwise the instruction fails with `ClassNotFrozen`
+pub struct UnfreezeClass<'info> {
+    accounts: FreezeClassAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for UnfreezeClass<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = FreezeClassAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> UnfreezeClass<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Unfreeze Class");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Check if the class is frozen
+        Class::check_frozen(self.accounts.class)?;
+
+        unsafe { Class::update_is_frozen_unchecked(self.accounts.class, false)? };
+
+        EventType::UnfreezeClass.emit(&[self.accounts.class.key()]);
+
+        Ok(())
     }
 }
@@ -1,5 +1,10 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
 use crate::{
-    state::{Class, Record, CLASS_OFFSET},
+    constants::FREEZE_RECORD_IX,
+    events::EventType,
+    state::{Class, Record, RecordLease, CLASS_OFFSET},
     utils::{ByteReader, Context},
 };
 use core::mem::size_of;
@@ -16,22 +21,43 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// 1. `authority` - The account that has permission to freeze/unfreeze the record (must be a signer)
 /// 2. `record` - The record account to be frozen/unfrozen
 /// 3. `class` - The class of the record to be frozen/unfrozen
+/// 4. `lease` - [optional] An active record lease held by the authority
 ///
 /// # Security
-/// The authority must be the class authority
+/// 1. The authority must be either:
+///    a. The class authority, or
+///    b. The lessee of an active record lease granting the freeze permission
+/// 2. The record must not be expired
 pub struct FreezeRecordAccounts<'info> {
+    authority: &'info AccountInfo,
     record: &'info AccountInfo,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for FreezeRecordAccounts<'info> {
+/// Required accounts of FreezeRecord, the record lease follows as optional account
+pub const FREEZE_RECORD_FIXED_ACCOUNTS: usize = 3;
+
+impl<'info> TryFrom<&Context<'info>> for FreezeRecordAccounts<'info> {
     type Error = ProgramError;
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, record, class] = accounts else {
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, record, class] = ctx.accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // Check if authority is the class authority
-        Class::check_authority(class, authority)?;
+        // Check if authority is the class authority or the lessee of an active lease
+        // (the SRS program id is passed as a placeholder when no lease is provided)
+        match ctx
+            .remaining_accounts
+            .first()
+            .filter(|lease| lease.key().ne(&crate::ID))
+        {
+            Some(lease) => {
+                let (permissions, _) = RecordLease::check_lessee(lease, record, authority)?;
+                if permissions & RecordLease::PERMISSION_FREEZE == 0 {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            }
+            None => Class::check_authority(class, authority)?,
+        }
 
         // Check if the Record is correct
         Record::check_program_id_and_discriminator(record)?;
@@ -41,7 +67,10 @@ impl<'info> TryFrom<&'info [AccountInfo]> for FreezeRecordAccounts<'info> {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        Ok(Self { record })
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record.try_borrow_data()?)? };
+
+        Ok(Self { authority, record })
     }
 }
 
@@ -60,7 +89,7 @@ impl<'info> TryFrom<Context<'info>> for FreezeRecord<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = FreezeRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = FreezeRecordAccounts::try_from(&ctx)?;
 
         // Check minimum instruction data length
         #[cfg(not(feature = "perf"))]
@@ -88,10 +117,17 @@ impl<'info> FreezeRecord<'info> {
     pub fn execute(&self) -> ProgramResult {
         // Update the record to be frozen [this is safe, check safety docs]
         unsafe {
-            Record::update_is_frozen_unchecked(
-                &mut self.accounts.record.try_borrow_mut_data()?,
-                self.is_frozen,
-            )
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_is_frozen_unchecked(&mut data, self.is_frozen)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                FREEZE_RECORD_IX,
+                self.accounts.authority.key(),
+            )?;
         }
+
+        EventType::FreezeRecord.emit(&[self.accounts.record.key()]);
+
+        Ok(())
     }
 }
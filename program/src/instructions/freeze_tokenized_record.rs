@@ -1,19 +1,26 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
 use crate::{
+    error::SrsError,
+    events::EventType,
     state::{Class, Record, CLASS_OFFSET, OWNER_OFFSET},
     token2022::{FreezeAccount, ThawAccount, Token},
     utils::{ByteReader, Context},
 };
 use core::mem::size_of;
 use pinocchio::{
-    account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{try_find_program_address, Pubkey}, ProgramResult
+    account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::Pubkey, ProgramResult
 };
 
-/// FreezeRecord instruction.
+/// FreezeTokenizedRecord instruction.
 ///
 /// This function:
-/// 1. Loads the current record state
-/// 2. Updates the frozen status
-/// 3. Saves the updated state
+/// 1. Loads the current frozen status of the token account
+/// 2. Freezes or thaws the token account, signed by the mint PDA
+///
+/// Like `FreezeRecord`, it is idempotent: freezing a frozen token account or
+/// thawing a thawed one succeeds without changing it.
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to freeze/unfreeze the record (must be a signer)
@@ -24,9 +31,11 @@ use pinocchio::{
 /// 6. `token_2022_program` - Required for freezing/unfreezing the token account
 ///
 /// # Security
-/// The authority must be: the class authority
+/// 1. The authority must be: the class authority
+/// 2. The record must not be expired
 pub struct FreezeTokenizedRecordAccounts<'info> {
     mint: &'info AccountInfo,
+    mint_bump: [u8; 1],
     token_account: &'info AccountInfo,
     record: &'info AccountInfo,
 }
@@ -50,13 +59,20 @@ impl<'info> TryFrom<&'info [AccountInfo]> for FreezeTokenizedRecordAccounts<'inf
             return Err(ProgramError::InvalidAccountData);
         }
 
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record_data)? };
+
         // Check if the token is linked to the record
         if mint.key().ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::NotTokenized.into());
         }
 
+        // Check if the mint is the mint PDA of the record
+        let mint_bump = Record::check_mint_address(record, mint)?;
+
         Ok(Self {
             mint,
+            mint_bump,
             token_account,
             record,
         })
@@ -112,11 +128,7 @@ impl<'info> FreezeTokenizedRecord<'info> {
             return Ok(());
         }
 
-        let bump = [
-            try_find_program_address(&[b"mint", self.accounts.record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-        ];
+        let bump = self.accounts.mint_bump;
 
         let seeds = [
             Seed::from(b"mint"),
@@ -142,6 +154,9 @@ impl<'info> FreezeTokenizedRecord<'info> {
             .invoke_signed(&signers)?;
         }
 
+        EventType::FreezeTokenizedRecord
+            .emit(&[self.accounts.record.key(), self.accounts.token_account.key()]);
+
         Ok(())
     }
 }
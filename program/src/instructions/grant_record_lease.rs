@@ -0,0 +1,238 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    events::EventType,
+    state::{Class, Record, RecordLease, CLASS_OFFSET, OWNER_OFFSET},
+    utils::{ByteReader, Context},
+};
+
+/// GrantRecordLease instruction.
+///
+/// This function:
+/// 1. Validates the class authority and the record
+/// 2. Derives the PDA for the record lease account from the record and the lessee
+/// 3. Creates the new account
+/// 4. Initializes the lease with the lessee, expiry and permissions, bound to the current owner,
+///    creation timestamp and class of the record
+///
+/// # Accounts
+/// 1. `authority` - The class authority granting the lease (must be a signer)
+/// 2. `payer` - The account that will pay for the lease account
+/// 3. `record` - The record the lease grants access to
+/// 4. `class` - The class of the record
+/// 5. `lease` - The new record lease account to be created
+/// 6. `system_program` - Required for creating the lease account
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The lease must expire in the future and grant at least one known permission
+/// 3. The lease must be the lease PDA of the record and the lessee
+pub struct GrantRecordLeaseAccounts<'info> {
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    lease: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for GrantRecordLeaseAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, record, class, lease, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the Record is correct
+        Record::check_program_id_and_discriminator(record)?;
+
+        // Check if the class is the correct class
+        if class.key().ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            payer,
+            record,
+            class,
+            lease,
+        })
+    }
+}
+
+const LESSEE_OFFSET: usize = 0;
+const EXPIRES_AT_OFFSET: usize = LESSEE_OFFSET + size_of::<Pubkey>();
+const PERMISSIONS_OFFSET: usize = EXPIRES_AT_OFFSET + size_of::<i64>();
+
+pub struct GrantRecordLease<'info> {
+    accounts: GrantRecordLeaseAccounts<'info>,
+    lessee: Pubkey,
+    expires_at: i64,
+    permissions: u8,
+}
+
+/// Minimum length of instruction data required for GrantRecordLease
+pub const GRANT_RECORD_LEASE_MIN_IX_LENGTH: usize =
+    size_of::<Pubkey>() + size_of::<i64>() + size_of::<u8>();
+
+impl<'info> TryFrom<Context<'info>> for GrantRecordLease<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = GrantRecordLeaseAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < GRANT_RECORD_LEASE_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `lessee`
+        let lessee: Pubkey = ByteReader::read_with_offset(ctx.data, LESSEE_OFFSET)?;
+
+        // Deserialize `expires_at`
+        let expires_at: i64 = ByteReader::read_with_offset(ctx.data, EXPIRES_AT_OFFSET)?;
+
+        // Deserialize `permissions`
+        let permissions: u8 = ByteReader::read_with_offset(ctx.data, PERMISSIONS_OFFSET)?;
+
+        Self::new(accounts, lessee, expires_at, permissions)
+    }
+}
+
+impl<'info> GrantRecordLease<'info> {
+    /// Validates the lease arguments and builds the instruction, shared with
+    /// `CreateRecordLease`
+    pub(crate) fn new(
+        accounts: GrantRecordLeaseAccounts<'info>,
+        lessee: Pubkey,
+        expires_at: i64,
+        permissions: u8,
+    ) -> Result<Self, ProgramError> {
+        // Check that the lease grants at least one known permission
+        if permissions == 0 || permissions & !RecordLease::PERMISSION_ALL != 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Check that the lease expires in the future
+        if expires_at <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            accounts,
+            lessee,
+            expires_at,
+            permissions,
+        })
+    }
+
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Grant Record Lease");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let space = RecordLease::RECORD_LEASE_SIZE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let lamports = rent.saturating_sub(self.accounts.lease.lamports());
+
+        let seeds = [
+            b"lease",
+            self.accounts.record.key().as_ref(),
+            self.lessee.as_ref(),
+        ];
+
+        let (address, bump) = try_find_program_address(&seeds, &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        // Check if the lease is the lease PDA of the record and the lessee
+        if address.ne(self.accounts.lease.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let bump: [u8; 1] = [bump];
+
+        let seeds = [
+            Seed::from(b"lease"),
+            Seed::from(self.accounts.record.key()),
+            Seed::from(&self.lessee),
+            Seed::from(&bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        // Create the account with our program as owner
+        if self.accounts.lease.lamports() > 0 {
+            Allocate {
+                account: self.accounts.lease,
+                space: space as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: self.accounts.lease,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+
+            if self.accounts.lease.lamports() < lamports {
+                Transfer {
+                    from: self.accounts.payer,
+                    to: self.accounts.lease,
+                    lamports: lamports - self.accounts.lease.lamports(),
+                }
+                .invoke()?;
+            }
+        } else {
+            CreateAccount {
+                from: self.accounts.payer,
+                to: self.accounts.lease,
+                lamports,
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        // Bind the lease to the current owner, creation and class of the record
+        let record_data = self.accounts.record.try_borrow_data()?;
+        let lease = RecordLease {
+            record: *self.accounts.record.key(),
+            lessee: self.lessee,
+            expires_at: self.expires_at,
+            permissions: self.permissions,
+            record_owner: record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            record_created_at: unsafe { Record::get_created_at_unchecked(&record_data) },
+            record_class: *self.accounts.class.key(),
+        };
+
+        unsafe { lease.initialize_unchecked(self.accounts.lease)? };
+
+        EventType::GrantRecordLease.emit(&[
+            self.accounts.record.key(),
+            self.accounts.lease.key(),
+            &self.lessee,
+        ]);
+
+        Ok(())
+    }
+}
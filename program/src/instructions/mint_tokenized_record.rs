@@ -5,13 +5,16 @@ use pinocchio::log::sol_log;
 use pinocchio_associated_token_account::instructions::Create;
 
 use crate::{
-    state::{OwnerType, Record, CLASS_OFFSET, IS_FROZEN_OFFSET, OWNER_OFFSET},
+    constants::{MAX_METADATA_LEN, MINT_TOKENIZED_RECORD_IX},
+    error::SrsError,
+    events::EventType,
+    state::{Class, OwnerType, Record, CLASS_OFFSET, IS_FROZEN_OFFSET, IS_PERMISSIONED_OFFSET, NON_TRANSFERABLE_OFFSET, OWNER_OFFSET},
     token2022::{
         constants::{
-            TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN, TOKEN_2022_GROUP_LEN, TOKEN_2022_GROUP_POINTER_LEN, TOKEN_2022_MEMBER_LEN, TOKEN_2022_MEMBER_POINTER_LEN, TOKEN_2022_METADATA_LEN, TOKEN_2022_METADATA_POINTER_LEN, TOKEN_2022_MINT_BASE_LEN, TOKEN_2022_MINT_LEN, TOKEN_2022_PERMANENT_DELEGATE_LEN, TOKEN_2022_PROGRAM_ID
-        }, FreezeAccount, InitializeGroup, InitializeGroupMemberPointer, InitializeGroupPointer, InitializeMember, InitializeMetadata, InitializeMetadataPointer, InitializeMint2, InitializeMintCloseAuthority, InitializePermanentDelegate, Mint, MintToChecked, Token, UpdateMetadata
+            TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN, TOKEN_2022_GROUP_LEN, TOKEN_2022_GROUP_POINTER_LEN, TOKEN_2022_MEMBER_LEN, TOKEN_2022_MEMBER_POINTER_LEN, TOKEN_2022_METADATA_LEN, TOKEN_2022_METADATA_POINTER_LEN, TOKEN_2022_MINT_BASE_LEN, TOKEN_2022_MINT_LEN, TOKEN_2022_NON_TRANSFERABLE_LEN, TOKEN_2022_PERMANENT_DELEGATE_LEN, TOKEN_2022_PROGRAM_ID
+        }, FreezeAccount, InitializeGroup, InitializeGroupMemberPointer, InitializeGroupPointer, InitializeMember, InitializeMetadata, InitializeMetadataPointer, InitializeMint2, InitializeMintCloseAuthority, InitializeNonTransferableMint, InitializePermanentDelegate, Mint, MintToChecked, Token, UpdateMetadata
     },
-    utils::Context, ID,
+    utils::{ByteWriter, Context}, ID,
 };
 use pinocchio::{
     account_info::AccountInfo,
@@ -23,14 +26,28 @@ use pinocchio::{
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
+/// Symbol of the group mint of a class
+const GROUP_SYMBOL: &[u8] = b"GRP";
+
+/// Maximum length of the group metadata data: the class name, the group symbol and the class
+/// metadata, each prefixed by its `u32` length
+const GROUP_METADATA_MAX_LEN: usize = size_of::<u32>() * 3
+    + Class::MAX_CLASS_NAME_LEN
+    + GROUP_SYMBOL.len()
+    + MAX_METADATA_LEN;
+
 /// MintTokenizedRecord instruction.
 ///
 /// This instruction:
 /// 1. Validates the authority and record
 /// 2. Updates the record's status to Tokenized
 /// 3. Creates a Token2022 token mint
-/// 4. Creates a Token2022 token account
+/// 4. Creates a Token2022 token account for the recipient
 /// 5. Mints a token to the token account
+/// 6. If the record is non-transferable, the mint is created with the
+///    Token2022 NonTransferable extension
+/// 7. If the class group does not exist yet, creates the group mint with the
+///    class name and metadata as its Token2022 metadata
 ///
 /// # Accounts
 /// 1. `owner` - The owner of the record
@@ -40,32 +57,44 @@ use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 /// 5. `mint` - The mint account of the record token
 /// 6. `class` - The class of the record
 /// 7. `group` - The group of the record
-/// 8. `token_account` - The associated token account where we mint the record token to
-/// 9. `token_2022_program` - The Token2022 program
-/// 10. `system_program` - Required for initializing our accounts
+/// 8. `token_account` - The associated token account of the recipient where we mint the record token to
+/// 9. `associated_token_program` - The Associated Token program
+/// 10. `token_2022_program` - The Token2022 program
+/// 11. `system_program` - Required for initializing our accounts
+/// 12. `recipient` - [optional] The wallet receiving the record token, defaults to the owner
 ///
 /// # Security
 /// 1. The authority must be:
 ///    a. The record's owner, or
 ///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 2. If the recipient is not the owner and the class is permissioned, the authority
+///    must be the class authority
+/// 3. The record must not be expired
+/// 4. `associated_token_program` must be the Associated Token program
 pub struct MintTokenizedRecordAccounts<'info> {
-    owner: &'info AccountInfo,
+    authority: &'info AccountInfo,
+    recipient: &'info AccountInfo,
     payer: &'info AccountInfo,
     record: &'info AccountInfo,
     mint: &'info AccountInfo,
+    mint_bump: [u8; 1],
     class: &'info AccountInfo,
     group: &'info AccountInfo,
+    group_bump: [u8; 1],
     token_account: &'info AccountInfo,
     token_2022_program: &'info AccountInfo,
     system_program: &'info AccountInfo,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info> {
+/// Required accounts of MintTokenizedRecord, the recipient follows as optional account
+pub const MINT_TOKENIZED_RECORD_FIXED_ACCOUNTS: usize = 11;
+
+impl<'info> TryFrom<&Context<'info>> for MintTokenizedRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [owner, payer, authority, record, mint, class, group, token_account, _associated_token_program, token_2022_program, system_program] =
-            accounts
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [owner, payer, authority, record, mint, class, group, token_account, associated_token_program, token_2022_program, system_program] =
+            ctx.accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
@@ -77,7 +106,7 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
 
         // Check if the owner of the record is the same as the owner of the token account
         if record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()].ne(owner.key()) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::NotRecordOwner.into());
         }
 
         // Check that the class of the record is the same as the class passed in 
@@ -85,7 +114,30 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
             return Err(ProgramError::InvalidAccountData);
         }
 
-        let seeds = [owner.key(), TOKEN_2022_PROGRAM_ID.as_ref(), mint.key()];
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record_data)? };
+
+        // Default the recipient to the owner (the SRS program id is passed as a placeholder)
+        let recipient = ctx.remaining_accounts
+            .first()
+            .filter(|recipient| recipient.key().ne(&crate::ID))
+            .unwrap_or(owner);
+
+        // Minting to someone else than the owner must be approved by the class authority
+        // if the class is permissioned
+        if recipient.key().ne(owner.key()) && class.try_borrow_data()?[IS_PERMISSIONED_OFFSET] == 1 {
+            Class::check_authority(class, authority)?;
+        }
+
+        // Check if the associated token program is the Associated Token program
+        if associated_token_program
+            .key()
+            .ne(&pinocchio_associated_token_account::ID)
+        {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let seeds = [recipient.key(), TOKEN_2022_PROGRAM_ID.as_ref(), mint.key()];
         let (token_account_address, _) =
             find_program_address(&seeds, &pinocchio_associated_token_account::ID);
 
@@ -93,18 +145,26 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
             return Err(ProgramError::InvalidAccountData);
         }
 
-        let group_key = find_program_address(&[b"group", class.key()], &ID).0;
+        let (group_key, group_bump) = try_find_program_address(&[b"group", class.key()], &ID)
+            .ok_or(ProgramError::InvalidArgument)?;
         if group_key.ne(group.key()) {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        // Check if the mint is the mint PDA of the record, its bump is cached in the record
+        // for the other tokenized record instructions
+        let mint_bump = Record::find_mint_address(record, mint)?;
+
         Ok(Self {
-            owner,
+            authority,
+            recipient,
             payer,
             record,
             mint,
+            mint_bump,
             class,
             group,
+            group_bump: [group_bump],
             token_account,
             token_2022_program,
             system_program,
@@ -114,6 +174,7 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
 
 pub struct MintTokenizedRecord<'info> {
     accounts: MintTokenizedRecordAccounts<'info>,
+    non_transferable: bool,
 }
 
 impl<'info> TryFrom<Context<'info>> for MintTokenizedRecord<'info> {
@@ -121,9 +182,16 @@ impl<'info> TryFrom<Context<'info>> for MintTokenizedRecord<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = MintTokenizedRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = MintTokenizedRecordAccounts::try_from(&ctx)?;
 
-        Ok(Self { accounts })
+        // Soulbound records are minted as non-transferable tokens
+        let non_transferable =
+            accounts.record.try_borrow_data()?[NON_TRANSFERABLE_OFFSET] == 1;
+
+        Ok(Self {
+            accounts,
+            non_transferable,
+        })
     }
 }
 
@@ -136,8 +204,8 @@ impl<'info> MintTokenizedRecord<'info> {
 
     pub fn execute(&self) -> ProgramResult {
         // Get Mint length
-        let mint_bump = self.derive_mint_address_bump()?;
-        let group_bump = self.derive_group_address_bump()?;
+        let mint_bump = self.accounts.mint_bump;
+        let group_bump = self.accounts.group_bump;
 
         // Check if the group already exists
         if !Mint::check_discriminator(self.accounts.group)? {
@@ -145,16 +213,27 @@ impl<'info> MintTokenizedRecord<'info> {
             self.create_group_mint_account(&group_bump)?;
             // Initialize the group pointer extension
             self.initialize_group_pointer()?;
+            // Initialize the group metadata pointer extension
+            self.initialize_group_metadata_pointer()?;
             // Initialize the group mint account
             self.initialize_group_mint_account()?;
             // Initialize the group
             self.initialize_group(&group_bump)?;
+            // Initialize the group metadata from the class
+            self.initialize_group_metadata(&group_bump)?;
         }
 
+        // Check that the group can still accept new members
+        self.check_group_capacity()?;
+
         // Create mint account
         self.create_mint_account(&mint_bump)?;
         // Initialize mint close authority extension
         self.initialize_mint_close_authority()?;
+        // Initialize the non-transferable extension for soulbound records
+        if self.non_transferable {
+            self.initialize_non_transferable_mint()?;
+        }
         // Initialize permanent delegate extension
         self.initialize_permanent_delegate()?;
         // Initialize the metadata pointer extension
@@ -194,31 +273,46 @@ impl<'info> MintTokenizedRecord<'info> {
         record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]
             .clone_from_slice(self.accounts.mint.key());
 
-        // 3. Update the record_type to be tokenized
-        unsafe { Record::update_owner_type_unchecked(&mut record_data, OwnerType::Token) }
-    }
-
-    fn derive_mint_address_bump(&self) -> Result<[u8; 1], ProgramError> {
-        let seeds = [b"mint", self.accounts.record.key().as_ref()];
-
-        Ok([try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1])
-    }
+        // 3. Update the record_type to be tokenized and cache the mint bump
+        unsafe { Record::update_owner_type_unchecked(&mut record_data, OwnerType::Token)? };
+        unsafe { Record::update_mint_bump_unchecked(&mut record_data, mint_bump[0])? };
+
+        // 4. Log the mutation in the record audit log
+        unsafe {
+            Record::record_mutation_unchecked(
+                &mut record_data,
+                MINT_TOKENIZED_RECORD_IX,
+                self.accounts.authority.key(),
+            )?;
+        }
 
-    fn derive_group_address_bump(&self) -> Result<[u8; 1], ProgramError> {
-        let seeds = [b"group", self.accounts.class.key().as_ref()];
+        EventType::MintTokenizedRecord.emit(&[
+            self.accounts.record.key(),
+            self.accounts.mint.key(),
+            self.accounts.recipient.key(),
+        ]);
 
-        Ok([try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1])
+        Ok(())
     }
 
     fn create_group_mint_account(&self, bump: &[u8; 1]) -> Result<(), ProgramError> {
         // Space of all our static extensions
-        let space = TOKEN_2022_MINT_LEN + TOKEN_2022_MINT_BASE_LEN + TOKEN_2022_GROUP_POINTER_LEN;
+        let space = TOKEN_2022_MINT_LEN
+            + TOKEN_2022_MINT_BASE_LEN
+            + TOKEN_2022_GROUP_POINTER_LEN
+            + TOKEN_2022_METADATA_POINTER_LEN;
+
+        // To avoid resizing the group, we calculate the correct lamports for the group and its
+        // metadata AOT, the metadata data being followed by an empty additional metadata vector
+        let metadata_len = {
+            let class_data = self.accounts.class.try_borrow_data()?;
+            let (name, metadata) = unsafe { Class::get_name_and_metadata_unchecked(&class_data)? };
+            size_of::<u32>() * 4 + name.len() + GROUP_SYMBOL.len() + metadata.len()
+        };
 
-        let lamports = Rent::get()?.minimum_balance(space + TOKEN_2022_GROUP_LEN);
+        let lamports = Rent::get()?.minimum_balance(
+            space + TOKEN_2022_GROUP_LEN + TOKEN_2022_METADATA_LEN + metadata_len,
+        );
 
         let seeds = [
             Seed::from(b"group"),
@@ -272,6 +366,49 @@ impl<'info> MintTokenizedRecord<'info> {
         .invoke()
     }
 
+    fn initialize_group_metadata_pointer(&self) -> Result<(), ProgramError> {
+        InitializeMetadataPointer {
+            mint: self.accounts.group,
+            authority: self.accounts.group.key(),
+            metadata_address: self.accounts.group.key(),
+        }
+        .invoke()
+    }
+
+    fn initialize_group_metadata(&self, bump: &[u8; 1]) -> Result<(), ProgramError> {
+        // Serialize the class name and metadata as the group name and uri
+        let mut metadata_data = [0u8; GROUP_METADATA_MAX_LEN];
+        let metadata_data_len = {
+            let class_data = self.accounts.class.try_borrow_data()?;
+            let (name, metadata) = unsafe { Class::get_name_and_metadata_unchecked(&class_data)? };
+
+            let mut writer = ByteWriter::new_with_offset(&mut metadata_data, 0);
+            for field in [name, GROUP_SYMBOL, metadata] {
+                writer.write(field.len() as u32)?;
+                writer.write_bytes(field)?;
+            }
+
+            GROUP_METADATA_MAX_LEN - writer.remaining_bytes()
+        };
+
+        let seeds = [
+            Seed::from(b"group"),
+            Seed::from(self.accounts.class.key()),
+            Seed::from(bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        InitializeMetadata {
+            metadata: self.accounts.group,
+            mint: self.accounts.group,
+            update_authority: self.accounts.group,
+            mint_authority: self.accounts.group,
+            metadata_data: &metadata_data[..metadata_data_len],
+        }
+        .invoke_signed(&signers)
+    }
+
     fn initialize_group(&self, bump: &[u8; 1]) -> Result<(), ProgramError> {
         let seeds = [
             Seed::from(b"group"),
@@ -308,7 +445,12 @@ impl<'info> MintTokenizedRecord<'info> {
             + TOKEN_2022_PERMANENT_DELEGATE_LEN
             + TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN
             + TOKEN_2022_METADATA_POINTER_LEN
-            + TOKEN_2022_MEMBER_POINTER_LEN;
+            + TOKEN_2022_MEMBER_POINTER_LEN
+            + if self.non_transferable {
+                TOKEN_2022_NON_TRANSFERABLE_LEN
+            } else {
+                0
+            };
 
 
         // To avoid resizing the mint, we calculate the correct lamports for our token AOT with:
@@ -363,6 +505,12 @@ impl<'info> MintTokenizedRecord<'info> {
             .invoke_signed(&signers)?;
         }
 
+        // Check that the mint was allocated with the space of its static extensions
+        #[cfg(not(feature = "perf"))]
+        if self.accounts.mint.data_len() != space {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         Ok(())
     }
 
@@ -374,6 +522,13 @@ impl<'info> MintTokenizedRecord<'info> {
         .invoke()
     }
 
+    fn initialize_non_transferable_mint(&self) -> Result<(), ProgramError> {
+        InitializeNonTransferableMint {
+            mint: self.accounts.mint,
+        }
+        .invoke()
+    }
+
     fn initialize_mint_close_authority(&self) -> Result<(), ProgramError> {
         InitializeMintCloseAuthority {
             mint: self.accounts.mint,
@@ -464,7 +619,8 @@ impl<'info> MintTokenizedRecord<'info> {
                 UpdateMetadata {
                     metadata: self.accounts.mint,
                     update_authority: self.accounts.mint,
-                    additional_metadata: &entry_data,
+                    field: 3,
+                    value: &entry_data,
                 }
                 .invoke_signed(&signers)?;
             }
@@ -473,6 +629,17 @@ impl<'info> MintTokenizedRecord<'info> {
         Ok(())
     }
 
+    fn check_group_capacity(&self) -> Result<(), ProgramError> {
+        let group_data = self.accounts.group.try_borrow_data()?;
+        let (size, max_size) = unsafe { Mint::get_group_size_unchecked(&group_data)? };
+
+        if size >= max_size {
+            return Err(SrsError::ClassCapacityExceeded.into());
+        }
+
+        Ok(())
+    }
+
     fn initialize_group_member(
         &self,
         group_bump: &[u8; 1],
@@ -514,7 +681,7 @@ impl<'info> MintTokenizedRecord<'info> {
             unsafe { Token::check_discriminator_unchecked(&data)? };
 
             // Check Ownership
-            if unsafe { Token::get_owner_unchecked(&data)? }.ne(self.accounts.owner.key()) {
+            if unsafe { Token::get_owner_unchecked(&data)? }.ne(self.accounts.recipient.key()) {
                 return Err(ProgramError::InvalidAccountData);
             }
 
@@ -524,7 +691,7 @@ impl<'info> MintTokenizedRecord<'info> {
         Create {
             funding_account: self.accounts.payer,
             account: self.accounts.token_account,
-            wallet: self.accounts.owner,
+            wallet: self.accounts.recipient,
             mint: self.accounts.mint,
             system_program: self.accounts.system_program,
             token_program: self.accounts.token_2022_program,
@@ -7,13 +7,28 @@ pub use update_class::UpdateClassAuthority;
 
 pub mod freeze_class;
 pub use freeze_class::FreezeClass;
+pub use freeze_class::UnfreezeClass;
+
+pub mod update_class_permission;
+pub use update_class_permission::UpdateClassPermission;
+
+pub mod delete_class;
+pub use delete_class::DeleteClass;
 
 pub mod create_record;
 pub use create_record::CreateRecord;
 
+pub mod batch_create_record;
+pub use batch_create_record::BatchCreateRecord;
+
 pub mod update_record;
 pub use update_record::UpdateRecordData;
+pub use update_record::UpdateRecordDataWithRevision;
 pub use update_record::UpdateRecordExpiry;
+pub use update_record::ResizeRecordDataOnly;
+
+pub mod extend_record_expiry;
+pub use extend_record_expiry::ExtendRecordExpiry;
 
 pub mod transfer_record;
 pub use transfer_record::TransferRecord;
@@ -24,6 +39,21 @@ pub use freeze_record::FreezeRecord;
 pub mod delete_record;
 pub use delete_record::DeleteRecord;
 
+pub mod batch_delete_record;
+pub use batch_delete_record::BatchDeleteRecord;
+
+pub mod reclaim_expired_record;
+pub use reclaim_expired_record::ReclaimExpiredRecord;
+
+pub mod clone_record;
+pub use clone_record::CloneRecord;
+
+pub mod rename_record;
+pub use rename_record::RenameRecord;
+
+pub mod create_record_snapshot;
+pub use create_record_snapshot::CreateRecordSnapshot;
+
 pub mod mint_tokenized_record;
 pub use mint_tokenized_record::*;
 
@@ -35,3 +65,48 @@ pub use freeze_tokenized_record::*;
 
 pub mod burn_tokenized_record;
 pub use burn_tokenized_record::*;
+
+pub mod update_tokenized_record_metadata;
+pub use update_tokenized_record_metadata::*;
+
+pub mod add_additional_metadata;
+pub use add_additional_metadata::*;
+
+pub mod remove_additional_metadata;
+pub use remove_additional_metadata::*;
+
+pub mod update_group_metadata;
+pub use update_group_metadata::*;
+
+pub mod create_royalty_config;
+pub use create_royalty_config::CreateRoyaltyConfig;
+
+pub mod update_royalty_config;
+pub use update_royalty_config::UpdateRoyaltyConfig;
+
+pub mod grant_record_lease;
+pub use grant_record_lease::GrantRecordLease;
+
+pub mod revoke_record_lease;
+pub use revoke_record_lease::RevokeRecordLease;
+
+pub mod renew_record_lease;
+pub use renew_record_lease::RenewRecordLease;
+
+pub mod expire_record_lease;
+pub use expire_record_lease::ExpireRecordLease;
+
+pub mod create_record_access_list;
+pub use create_record_access_list::CreateRecordAccessList;
+
+pub mod update_record_access_list;
+pub use update_record_access_list::UpdateRecordAccessList;
+
+pub mod delete_record_access_list;
+pub use delete_record_access_list::DeleteRecordAccessList;
+
+pub mod transfer_record_to_class;
+pub use transfer_record_to_class::TransferRecordToClass;
+
+pub mod create_record_lease;
+pub use create_record_lease::CreateRecordLease;
@@ -0,0 +1,103 @@
+use crate::{
+    error::SrsError,
+    events::EventType,
+    state::{ClassStats, OwnerType, Record, OWNER_TYPE_OFFSET},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// ReclaimExpiredRecord instruction.
+///
+/// This function:
+/// 1. Checks that the record has expired
+/// 2. Reallocates the record account data to 0 bytes
+/// 3. Transfers the lamports from the record to the payer
+/// 4. Removes the record from the class stats
+///
+/// # Accounts
+/// 1. `payer` - The account that will get refunded for the record account (must be a signer)
+/// 2. `record` - The expired record account to be reclaimed
+/// 3. `class_stats` - The stats account of the class of the record
+///
+/// # Security
+/// 1. The record must have a non-zero expiry that is in the past
+/// 2. Tokenized records cannot be reclaimed, they must be burned instead
+pub struct ReclaimExpiredRecordAccounts<'info> {
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for ReclaimExpiredRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, record, class_stats] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if payer is a signer
+        if !payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check if the record is a valid record account
+        Record::check_program_id_and_discriminator(record)?;
+
+        let data = record.try_borrow_data()?;
+
+        // Tokenized records hold a mint that must be burned first
+        if data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Pubkey)) {
+            return Err(SrsError::AlreadyTokenized.into());
+        }
+
+        // Check if the record has expired
+        if !unsafe { Record::is_expired_unchecked(&data)? } {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            payer,
+            record,
+            class_stats,
+        })
+    }
+}
+
+pub struct ReclaimExpiredRecord<'info> {
+    accounts: ReclaimExpiredRecordAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for ReclaimExpiredRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = ReclaimExpiredRecordAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ReclaimExpiredRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Reclaim Expired Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        ClassStats::remove_record(self.accounts.class_stats, self.accounts.record)?;
+
+        // Safety: The account has already been validated
+        unsafe {
+            Record::delete_record_unchecked(self.accounts.record, self.accounts.payer)?;
+        }
+
+        EventType::ReclaimExpiredRecord.emit(&[self.accounts.record.key()]);
+
+        Ok(())
+    }
+}
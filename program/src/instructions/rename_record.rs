@@ -0,0 +1,243 @@
+#[cfg(not(feature = "perf"))]
+use crate::constants::MAX_SEED_LEN;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    constants::RENAME_RECORD_IX,
+    error::SrsError,
+    events::EventType,
+    state::{
+        Class, OwnerType, Record, CLASS_OFFSET, IS_FROZEN_OFFSET, MAX_RECORD_SIZE,
+        OWNER_TYPE_OFFSET,
+    },
+    utils::{ByteReader, Context},
+};
+
+/// RenameRecord instruction.
+///
+/// This function:
+/// 1. Validates the authority over the old record
+/// 2. Derives the PDA for the new record from the new seed
+/// 3. Creates the new record account
+/// 4. Copies the old record into the new record with the new seed
+/// 5. Closes the old record and refunds its lamports to the payer
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to rename the record (must be a signer)
+/// 2. `payer` - The account that will pay for the new record account and get
+///    refunded for the old one
+/// 3. `old_record` - The record account to be renamed
+/// 4. `new_record` - The new record account to be created
+/// 5. `class` - The class of the record
+/// 6. `system_program` - Required for creating the new record
+///
+/// # Security
+/// 1. The authority must be either:
+///    a. The record owner, or
+///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 2. If the class is permissioned, the authority must be the class authority
+/// 3. The class must not be frozen
+/// 4. The record must not be tokenized, frozen or expired
+/// 5. The new record must be the record PDA of the class and the new seed
+/// 6. The new record account must not exceed `MAX_RECORD_SIZE`
+pub struct RenameRecordAccounts<'info> {
+    authority: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    old_record: &'info AccountInfo,
+    new_record: &'info AccountInfo,
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for RenameRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, old_record, new_record, class, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the record owner or has a delegate
+        Record::check_owner_or_delegate(old_record, Some(class), authority)?;
+
+        // Check class permission, a new record in a permissioned class
+        // requires the class authority
+        Class::check_permission(class, Some(authority))?;
+
+        // Check that the class is not frozen
+        Class::check_not_frozen(class)?;
+
+        let data = old_record.try_borrow_data()?;
+
+        // Check that the class of the record is the class passed in
+        if data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()].ne(class.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Tokenized records are bound to their mint and cannot be renamed
+        if data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Pubkey)) {
+            return Err(SrsError::AlreadyTokenized.into());
+        }
+
+        // Check if the record is frozen
+        if data[IS_FROZEN_OFFSET].eq(&1u8) {
+            return Err(SrsError::RecordFrozen.into());
+        }
+
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&data)? };
+
+        Ok(Self {
+            authority,
+            payer,
+            old_record,
+            new_record,
+            class,
+        })
+    }
+}
+
+pub struct RenameRecord<'info> {
+    accounts: RenameRecordAccounts<'info>,
+    seed: &'info [u8],
+}
+
+/// Minimum length of instruction data required for RenameRecord
+pub const RENAME_RECORD_MIN_IX_LENGTH: usize = size_of::<u8>() * 2;
+
+impl<'info> TryFrom<Context<'info>> for RenameRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = RenameRecordAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < RENAME_RECORD_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `seed`
+        let seed: &[u8] = ByteReader::new(ctx.data).read_bytes_with_length()?;
+
+        #[cfg(not(feature = "perf"))]
+        if seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self { accounts, seed })
+    }
+}
+
+impl<'info> RenameRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Rename Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        {
+            let old_data = self.accounts.old_record.try_borrow_data()?;
+            let data_len = unsafe { Record::get_data_unchecked(&old_data)?.len() };
+
+            let space = Record::MINIMUM_RECORD_SIZE + self.seed.len() + data_len;
+
+            // Check that the new record account does not exceed the maximum record size
+            if space > MAX_RECORD_SIZE {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            let rent = Rent::get()?.minimum_balance(space);
+            let lamports = rent.saturating_sub(self.accounts.new_record.lamports());
+
+            let seeds = [b"record", self.accounts.class.key().as_ref(), self.seed];
+
+            let (address, bump) = try_find_program_address(&seeds, &crate::ID)
+                .ok_or(ProgramError::InvalidArgument)?;
+
+            // Check if the new record is the record PDA of the class and new seed
+            if address.ne(self.accounts.new_record.key()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let bump: [u8; 1] = [bump];
+
+            let seeds = [
+                Seed::from(b"record"),
+                Seed::from(self.accounts.class.key()),
+                Seed::from(self.seed),
+                Seed::from(&bump),
+            ];
+
+            let signers = [Signer::from(&seeds)];
+
+            // Create the account with our program as owner
+            if self.accounts.new_record.lamports() > 0 {
+                Allocate {
+                    account: self.accounts.new_record,
+                    space: space as u64,
+                }
+                .invoke_signed(&signers)?;
+
+                Assign {
+                    account: self.accounts.new_record,
+                    owner: &crate::ID,
+                }
+                .invoke_signed(&signers)?;
+
+                if self.accounts.new_record.lamports() < lamports {
+                    Transfer {
+                        from: self.accounts.payer,
+                        to: self.accounts.new_record,
+                        lamports: lamports - self.accounts.new_record.lamports(),
+                    }
+                    .invoke()?;
+                }
+            } else {
+                CreateAccount {
+                    from: self.accounts.payer,
+                    to: self.accounts.new_record,
+                    lamports,
+                    space: space as u64,
+                    owner: &crate::ID,
+                }
+                .invoke_signed(&signers)?;
+            }
+
+            // Copy the old record under its new seed [this is safe, check safety docs]
+            unsafe {
+                let mut new_data = self.accounts.new_record.try_borrow_mut_data()?;
+                Record::copy_with_seed_unchecked(&old_data, &mut new_data, self.seed, bump[0])?;
+                Record::update_updated_at_unchecked(&mut new_data)?;
+                Record::record_mutation_unchecked(
+                    &mut new_data,
+                    RENAME_RECORD_IX,
+                    self.accounts.authority.key(),
+                )?;
+            }
+        }
+
+        // Close the old record, the borrow on its data has been released above
+        unsafe { Record::delete_record_unchecked(self.accounts.old_record, self.accounts.payer)? };
+
+        EventType::RenameRecord.emit(&[
+            self.accounts.old_record.key(),
+            self.accounts.new_record.key(),
+        ]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events::EventType,
+    state::{Class, Record, RecordLease, CLASS_OFFSET},
+    utils::{ByteReader, Context},
+};
+
+/// RenewRecordLease instruction.
+///
+/// This function:
+/// 1. Validates the class authority, the record and the lease
+/// 2. Updates the lease expiry
+///
+/// # Accounts
+/// 1. `authority` - The class authority renewing the lease (must be a signer)
+/// 2. `record` - The record the lease grants access to
+/// 3. `class` - The class of the record
+/// 4. `lease` - The record lease account to be renewed
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The lease must belong to the record and must have been granted over its current owner,
+///    creation and class
+/// 3. The new expiry must be in the future
+pub struct RenewRecordLeaseAccounts<'info> {
+    lease: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for RenewRecordLeaseAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, record, class, lease] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the Record is correct
+        Record::check_program_id_and_discriminator(record)?;
+
+        // Check if the class is the correct class
+        if class.key().ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the lease belongs to the record
+        RecordLease::check_record(lease, record)?;
+
+        // Check if the lease was granted over the current record, owner and class at this address
+        if unsafe { RecordLease::is_stale_unchecked(&lease.try_borrow_data()?, &record.try_borrow_data()?) } {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self { lease })
+    }
+}
+
+const EXPIRES_AT_OFFSET: usize = 0;
+
+pub struct RenewRecordLease<'info> {
+    accounts: RenewRecordLeaseAccounts<'info>,
+    expires_at: i64,
+}
+
+/// Minimum length of instruction data required for RenewRecordLease
+pub const RENEW_RECORD_LEASE_MIN_IX_LENGTH: usize = size_of::<i64>();
+
+impl<'info> TryFrom<Context<'info>> for RenewRecordLease<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = RenewRecordLeaseAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < RENEW_RECORD_LEASE_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `expires_at`
+        let expires_at: i64 = ByteReader::read_with_offset(ctx.data, EXPIRES_AT_OFFSET)?;
+
+        // Check that the lease expires in the future
+        if expires_at <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            accounts,
+            expires_at,
+        })
+    }
+}
+
+impl<'info> RenewRecordLease<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Renew Record Lease");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let mut data = self.accounts.lease.try_borrow_mut_data()?;
+
+        // Safety: The account has already been validated
+        unsafe { RecordLease::update_expires_at_unchecked(&mut data, self.expires_at)? };
+
+        EventType::RenewRecordLease.emit(&[self.accounts.lease.key()]);
+
+        Ok(())
+    }
+}
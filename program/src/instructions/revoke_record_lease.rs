@@ -0,0 +1,82 @@
+use crate::{
+    events::EventType,
+    state::{Class, RecordLease},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// RevokeRecordLease instruction.
+///
+/// This function:
+/// 1. Validates the class authority, the record and the lease
+/// 2. Reallocates the lease account data to 0 bytes
+/// 3. Transfers the lamports from the lease to the payer
+///
+/// # Accounts
+/// 1. `authority` - The class authority revoking the lease (must be a signer)
+/// 2. `payer` - The account that will get refunded for the lease account
+/// 3. `record` - The record the lease grants access to, which may have been closed
+/// 4. `class` - The class the lease was granted under
+/// 5. `lease` - The record lease account to be closed
+///
+/// # Security
+/// 1. The authority must be the authority of the class the lease was granted under
+/// 2. The lease must belong to the record, which does not need to be live anymore
+pub struct RevokeRecordLeaseAccounts<'info> {
+    payer: &'info AccountInfo,
+    lease: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for RevokeRecordLeaseAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, record, class, lease] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the lease belongs to the record and was granted under the class
+        RecordLease::check_record_and_class(lease, record, class)?;
+
+        Ok(Self { payer, lease })
+    }
+}
+
+pub struct RevokeRecordLease<'info> {
+    accounts: RevokeRecordLeaseAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for RevokeRecordLease<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = RevokeRecordLeaseAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> RevokeRecordLease<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Revoke Record Lease");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        unsafe {
+            RecordLease::delete_record_lease_unchecked(self.accounts.lease, self.accounts.payer)?
+        };
+
+        EventType::RevokeRecordLease.emit(&[self.accounts.lease.key()]);
+
+        Ok(())
+    }
+}
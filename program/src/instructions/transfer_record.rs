@@ -1,6 +1,8 @@
 use crate::{
-    state::Record,
-    utils::{ByteReader, Context},
+    constants::{TRANSFER_FEE_BASE_LAMPORTS, TRANSFER_RECORD_IX},
+    events::EventType,
+    state::{Class, Record, CLASS_OFFSET},
+    utils::{is_system_program, ByteReader, Context},
 };
 use core::mem::size_of;
 #[cfg(not(feature = "perf"))]
@@ -8,6 +10,7 @@ use pinocchio::log::sol_log;
 use pinocchio::{
     account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
 };
+use pinocchio_system::instructions::Transfer;
 
 /// TransferRecord instruction.
 ///
@@ -15,32 +18,165 @@ use pinocchio::{
 /// 1. Loads the current record state
 /// 2. Updates the owner to the new owner
 /// 3. Saves the updated state
+/// 4. Pays the class transfer fee on `TRANSFER_FEE_BASE_LAMPORTS`, if the class is passed and
+///    charges one
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to transfer the record (must be a signer)
 /// 2. `record` - The record account to be transferred
-/// 3. `class` - [optional] The class of the record to be transferred
+/// 3. `class` - [optional] The class of the record to be transferred, required to pay the
+///    transfer fee
+/// 4. `payer` - [optional] The account paying the transfer fee (must be a signer), required when
+///    the class charges a transfer fee
+/// 5. `fee_destination` - [optional] The fee destination of the class, required when the class
+///    charges a transfer fee
+/// 6. `system_program` - [optional] Required for paying the transfer fee
 ///
 /// # Security
 /// 1. The authority must be either:
 ///    a. The record owner, or
 ///    b. if the class is permissioned, the authority can be the permissioned authority
 /// 2. The record must not be frozen
+/// 3. The record must not be expired
+/// 4. The record must not be non-transferable
+/// 5. The new owner must not be the zero address
+/// 6. When passed, the class must be the class of the record and, when the class charges a
+///    transfer fee, the fee destination must be the one of the class
+///
+/// The class is only read when it is passed, so transfers with only the required accounts keep
+/// working and pay no transfer fee. The fee is honored by the callers passing the class rather
+/// than enforced.
 pub struct TransferRecordAccounts<'info> {
+    authority: &'info AccountInfo,
     record: &'info AccountInfo,
+    transfer_fee: Option<TransferFeeAccounts<'info>>,
+}
+
+pub struct TransferFeeAccounts<'info> {
+    class: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    fee_destination: &'info AccountInfo,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for TransferRecordAccounts<'info> {
+impl<'info> TransferFeeAccounts<'info> {
+    /// Reads the transfer fee accounts of a record transfer
+    ///
+    /// The class must be the class of the record. Returns `None` when it charges no transfer fee,
+    /// otherwise the payer and the fee destination of the class are required, an omitted account
+    /// being passed as the SRS program id placeholder.
+    pub fn try_from_accounts(
+        record: &AccountInfo,
+        class: &'info AccountInfo,
+        payer: Option<&'info AccountInfo>,
+        fee_destination: Option<&'info AccountInfo>,
+    ) -> Result<Option<Self>, ProgramError> {
+        // Check if the class is the class of the record
+        if class
+            .key()
+            .ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Class::check_program_id(class)?;
+
+        let class_data = class.try_borrow_data()?;
+
+        unsafe { Class::check_discriminator_unchecked(&class_data)? };
+
+        // Classes without a transfer fee charge nothing
+        if unsafe { Class::get_transfer_fee_bps_unchecked(&class_data) } == 0 {
+            return Ok(None);
+        }
+
+        let (Some(payer), Some(fee_destination)) = (
+            payer.filter(|payer| payer.key().ne(&crate::ID)),
+            fee_destination.filter(|fee_destination| fee_destination.key().ne(&crate::ID)),
+        ) else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the fee destination is the fee destination of the class
+        if fee_destination
+            .key()
+            .ne(unsafe { Class::get_fee_destination_unchecked(&class_data) })
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the payer is signer
+        if !payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Some(Self {
+            class,
+            payer,
+            fee_destination,
+        }))
+    }
+
+    /// Pays the transfer fee of the class on `TRANSFER_FEE_BASE_LAMPORTS` to the fee destination
+    pub fn pay(&self) -> ProgramResult {
+        let transfer_fee_bps =
+            unsafe { Class::get_transfer_fee_bps_unchecked(&self.class.try_borrow_data()?) };
+
+        let fee = TRANSFER_FEE_BASE_LAMPORTS * transfer_fee_bps as u64
+            / Class::MAX_TRANSFER_FEE_BPS as u64;
+
+        Transfer {
+            from: self.payer,
+            to: self.fee_destination,
+            lamports: fee,
+        }
+        .invoke()
+    }
+}
+
+/// Required accounts of TransferRecord, the class and the transfer fee accounts follow as
+/// optional remaining accounts
+pub const TRANSFER_RECORD_FIXED_ACCOUNTS: usize = 2;
+
+impl<'info> TryFrom<&Context<'info>> for TransferRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, record, rest @ ..] = accounts else {
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, record] = ctx.accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        Record::check_owner_or_delegate(record, rest.first(), authority)?;
+        // The SRS program id is passed as a placeholder when the class is omitted
+        let class = ctx
+            .remaining_accounts
+            .first()
+            .filter(|class| class.key().ne(&crate::ID));
+
+        Record::check_owner_or_delegate(record, class, authority)?;
+
+        let data = record.try_borrow_data()?;
+
+        // Check if the record is non-transferable
+        unsafe { Record::check_transferable_unchecked(&data)? };
+
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&data)? };
+
+        // Only a passed class can charge a transfer fee
+        let transfer_fee = match class {
+            Some(class) => TransferFeeAccounts::try_from_accounts(
+                record,
+                class,
+                ctx.remaining_accounts.get(1),
+                ctx.remaining_accounts.get(2),
+            )?,
+            None => None,
+        };
 
-        Ok(Self { record })
+        Ok(Self {
+            authority,
+            record,
+            transfer_fee,
+        })
     }
 }
 
@@ -59,7 +195,7 @@ impl<'info> TryFrom<Context<'info>> for TransferRecord<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = TransferRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = TransferRecordAccounts::try_from(&ctx)?;
 
         // Check minimum instruction data length
         #[cfg(not(feature = "perf"))]
@@ -70,6 +206,11 @@ impl<'info> TryFrom<Context<'info>> for TransferRecord<'info> {
         // Deserialize new owner
         let new_owner: Pubkey = ByteReader::read_with_offset(ctx.data, NEW_OWNER_OFFSET)?;
 
+        // Check that the new owner is not the zero address
+        if is_system_program(&new_owner) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
         Ok(Self {
             accounts,
             new_owner,
@@ -87,10 +228,21 @@ impl<'info> TransferRecord<'info> {
     pub fn execute(&self) -> ProgramResult {
         // Update the record to be transferred [this is safe, check safety docs]
         unsafe {
-            Record::update_owner_unchecked(
-                &mut self.accounts.record.try_borrow_mut_data()?,
-                &self.new_owner,
-            )
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_owner_unchecked(&mut data, &self.new_owner)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                TRANSFER_RECORD_IX,
+                self.accounts.authority.key(),
+            )?;
+        }
+
+        if let Some(transfer_fee) = &self.accounts.transfer_fee {
+            transfer_fee.pay()?;
         }
+
+        EventType::TransferRecord.emit(&[self.accounts.record.key(), &self.new_owner]);
+
+        Ok(())
     }
 }
@@ -0,0 +1,272 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    constants::TRANSFER_RECORD_TO_CLASS_IX,
+    error::SrsError,
+    events::EventType,
+    state::{
+        Class, ClassStats, OwnerType, Record, CLASS_OFFSET, IS_FROZEN_OFFSET, OWNER_TYPE_OFFSET,
+    },
+    utils::{ByteWriter, Context},
+};
+
+/// TransferRecordToClass instruction.
+///
+/// This function:
+/// 1. Validates the authority over both classes
+/// 2. Derives the PDA for the new record under the new class, with the seed
+///    of the old record
+/// 3. Creates the new record account
+/// 4. Copies the old record into the new record and points it to the new class
+/// 5. Moves the record from the stats of the old class to the stats of the
+///    new class, creating the latter if needed
+/// 6. Closes the old record and refunds its lamports to the payer
+///
+/// # Accounts
+/// 1. `authority` - The authority of both classes (must be a signer)
+/// 2. `payer` - The account that will pay for the new record account and get
+///    refunded for the old one
+/// 3. `old_class` - The class of the record
+/// 4. `new_class` - The class the record is transferred to
+/// 5. `old_record` - The record account to be transferred
+/// 6. `new_record` - The new record account to be created
+/// 7. `system_program` - Required for creating the new record
+/// 8. `old_class_stats` - The stats account of the old class
+/// 9. `new_class_stats` - The stats account of the new class, created on its first record
+///
+/// # Security
+/// 1. The authority must be the class authority of both classes and sign
+/// 2. The classes must be distinct and not frozen
+/// 3. The record must belong to `old_class` and must not be tokenized, frozen or expired
+/// 4. The record data must not exceed the `max_record_size` of the new class
+/// 5. The new class must not already hold `max_records` records
+/// 6. `new_record` must be the record PDA of the new class and the record seed
+pub struct TransferRecordToClassAccounts<'info> {
+    authority: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    old_class: &'info AccountInfo,
+    new_class: &'info AccountInfo,
+    old_record: &'info AccountInfo,
+    new_record: &'info AccountInfo,
+    old_class_stats: &'info AccountInfo,
+    new_class_stats: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for TransferRecordToClassAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, old_class, new_class, old_record, new_record, _system_program, old_class_stats, new_class_stats] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // A record transferred to its own class would be created at its own address
+        if old_class.key().eq(new_class.key()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Check that the authority is the class authority of both classes
+        Class::check_authority(old_class, authority)?;
+        Class::check_authority(new_class, authority)?;
+
+        // Check that neither class is frozen
+        Class::check_not_frozen(old_class)?;
+        Class::check_not_frozen(new_class)?;
+
+        Record::check_program_id_and_discriminator(old_record)?;
+
+        let data = old_record.try_borrow_data()?;
+
+        // Check that the class of the record is the old class passed in
+        if data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()].ne(old_class.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Tokenized records are bound to their mint and cannot change class
+        if data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Pubkey)) {
+            return Err(SrsError::AlreadyTokenized.into());
+        }
+
+        // Check if the record is frozen
+        if data[IS_FROZEN_OFFSET].eq(&1u8) {
+            return Err(SrsError::RecordFrozen.into());
+        }
+
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&data)? };
+
+        // Check the data against the record size limit of the new class
+        unsafe {
+            Class::check_record_size_unchecked(
+                &new_class.try_borrow_data()?,
+                Record::get_data_unchecked(&data)?.len(),
+            )?
+        };
+
+        Ok(Self {
+            authority,
+            payer,
+            old_class,
+            new_class,
+            old_record,
+            new_record,
+            old_class_stats,
+            new_class_stats,
+        })
+    }
+}
+
+pub struct TransferRecordToClass<'info> {
+    accounts: TransferRecordToClassAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for TransferRecordToClass<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = TransferRecordToClassAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> TransferRecordToClass<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Transfer Record To Class");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Create the stats account of the new class if it does not exist yet
+        ClassStats::initialize_if_needed(
+            self.accounts.new_class_stats,
+            self.accounts.new_class.key(),
+            self.accounts.payer,
+        )?;
+
+        // Check the record count limit of the new class
+        unsafe {
+            Class::check_record_count_unchecked(
+                &self.accounts.new_class.try_borrow_data()?,
+                &self.accounts.new_class_stats.try_borrow_data()?,
+            )?;
+        }
+
+        // Remove the record from the stats of the old class before it is closed
+        ClassStats::remove_record(self.accounts.old_class_stats, self.accounts.old_record)?;
+
+        let data_len = {
+            let old_data = self.accounts.old_record.try_borrow_data()?;
+            let seed = unsafe { Record::get_seed_unchecked(&old_data) };
+            let data_len = unsafe { Record::get_data_unchecked(&old_data)?.len() };
+
+            let space = Record::MINIMUM_RECORD_SIZE + seed.len() + data_len;
+            let rent = Rent::get()?.minimum_balance(space);
+            let lamports = rent.saturating_sub(self.accounts.new_record.lamports());
+
+            let seeds = [b"record", self.accounts.new_class.key().as_ref(), seed];
+
+            let (address, bump) = try_find_program_address(&seeds, &crate::ID)
+                .ok_or(ProgramError::InvalidArgument)?;
+
+            // Check if the new record is the record PDA of the new class and seed
+            if address.ne(self.accounts.new_record.key()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let bump: [u8; 1] = [bump];
+
+            let seeds = [
+                Seed::from(b"record"),
+                Seed::from(self.accounts.new_class.key()),
+                Seed::from(seed),
+                Seed::from(&bump),
+            ];
+
+            let signers = [Signer::from(&seeds)];
+
+            // Create the account with our program as owner
+            if self.accounts.new_record.lamports() > 0 {
+                Allocate {
+                    account: self.accounts.new_record,
+                    space: space as u64,
+                }
+                .invoke_signed(&signers)?;
+
+                Assign {
+                    account: self.accounts.new_record,
+                    owner: &crate::ID,
+                }
+                .invoke_signed(&signers)?;
+
+                if self.accounts.new_record.lamports() < lamports {
+                    Transfer {
+                        from: self.accounts.payer,
+                        to: self.accounts.new_record,
+                        lamports: lamports - self.accounts.new_record.lamports(),
+                    }
+                    .invoke()?;
+                }
+            } else {
+                CreateAccount {
+                    from: self.accounts.payer,
+                    to: self.accounts.new_record,
+                    lamports,
+                    space: space as u64,
+                    owner: &crate::ID,
+                }
+                .invoke_signed(&signers)?;
+            }
+
+            // Copy the old record under the new class [this is safe, check safety docs]
+            unsafe {
+                let mut new_data = self.accounts.new_record.try_borrow_mut_data()?;
+                Record::copy_with_seed_unchecked(&old_data, &mut new_data, seed, bump[0])?;
+                ByteWriter::write_with_offset(
+                    &mut new_data,
+                    CLASS_OFFSET,
+                    *self.accounts.new_class.key(),
+                )?;
+                Record::update_updated_at_unchecked(&mut new_data)?;
+                Record::record_mutation_unchecked(
+                    &mut new_data,
+                    TRANSFER_RECORD_TO_CLASS_IX,
+                    self.accounts.authority.key(),
+                )?;
+            }
+
+            data_len
+        };
+
+        // Close the old record, the borrow on its data has been released above
+        unsafe {
+            Record::delete_record_unchecked(self.accounts.old_record, self.accounts.payer)?;
+            ClassStats::add_record_unchecked(self.accounts.new_class_stats, data_len)?;
+        }
+
+        EventType::TransferRecordToClass.emit(&[
+            self.accounts.old_class.key(),
+            self.accounts.new_class.key(),
+            self.accounts.old_record.key(),
+            self.accounts.new_record.key(),
+        ]);
+
+        Ok(())
+    }
+}
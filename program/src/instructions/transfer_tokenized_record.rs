@@ -1,20 +1,33 @@
-use crate::{state::Record, token2022::TransferChecked, utils::Context};
+use crate::{
+    events::EventType,
+    instructions::transfer_record::TransferFeeAccounts,
+    state::{Record, RoyaltyConfig, CLASS_OFFSET},
+    token2022::{
+        constants::{TOKENIZED_RECORD_AMOUNT, TOKENIZED_RECORD_DECIMALS},
+        Mint, TransferChecked,
+    },
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
 #[cfg(not(feature = "perf"))]
 use pinocchio::log::sol_log;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::try_find_program_address,
+    pubkey::Pubkey,
     ProgramResult,
 };
+use pinocchio_system::instructions::Transfer;
 
-/// TransferRecord instruction.
+/// TransferTokenizedRecord instruction.
 ///
 /// This function:
-/// 1. Loads the current record state
-/// 2. Updates the owner to the new owner
-/// 3. Saves the updated state
+/// 1. Transfers the token of the record mint to the new token account
+/// 2. Pays the class royalty on `token_value_lamports`, if the royalty config of the class is
+///    passed and charges one
+/// 3. Pays the class transfer fee on `TRANSFER_FEE_BASE_LAMPORTS`, if the class is passed and
+///    charges one
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to update the record (must be a signer)
@@ -22,27 +35,154 @@ use pinocchio::{
 /// 3. `token_account` - The token account that is linked to the record
 /// 4. `new_token_account` - The new owner of the token account
 /// 5. `record` - The record account to be updated
-/// 6. `system_program` - Required for account resizing operations
-/// 7. `class` - [optional] The class of the token account
+/// 6. `token_2022_program` - Required for transferring the token
+/// 7. `class` - [optional] The class of the record, required with the royalty config
+/// 8. `royalty_config` - [optional] The royalty config PDA of the class, which may not be
+///    initialized
+/// 9. `payer` - [optional] The account paying the royalty and the transfer fee (must be a
+///    signer), required when the class charges either
+/// 10. `destination` - [optional] The royalty destination of the royalty config, required when
+///     the class charges a royalty
+/// 11. `system_program` - [optional] Required for paying the royalty and the transfer fee
+/// 12. `fee_destination` - [optional] The fee destination of the class, required when the class
+///     charges a transfer fee
 ///
 /// # Security
 /// 1. The authority must be:
 ///    a. The mint's owner, or
 ///    b. if the class is permissioned, the authority must be the permissioned authority
 /// 2. The record must not be frozen
+/// 3. The record must not be non-transferable
+/// 4. The record must not be expired
+/// 5. When passed, the class must be the class of the record and, when the class charges a
+///    transfer fee, the fee destination must be the one of the class
+/// 6. When passed, the royalty config must be the royalty config PDA of the class and the
+///    destination must be the one of the royalty config
+///
+/// Transfers with only the required accounts and no instruction data keep working and pay
+/// neither the royalty nor the transfer fee. The royalty is charged on the sale value declared
+/// by the caller in the optional trailing `token_value_lamports`, which the program cannot
+/// verify, so it is honored by the marketplaces passing the royalty config rather than enforced.
 pub struct TransferTokenizedRecordAccounts<'info> {
     mint: &'info AccountInfo,
+    mint_bump: [u8; 1],
     token_account: &'info AccountInfo,
     new_token_account: &'info AccountInfo,
     record: &'info AccountInfo,
+    royalty: Option<RoyaltyAccounts<'info>>,
+    transfer_fee: Option<TransferFeeAccounts<'info>>,
+}
+
+pub struct RoyaltyAccounts<'info> {
+    royalty_config: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    destination: &'info AccountInfo,
+}
+
+impl<'info> RoyaltyAccounts<'info> {
+    /// Reads the optional royalty accounts of a record transfer
+    ///
+    /// Returns `None` when the royalty config is not passed, when the class has no royalty config
+    /// or when it charges no royalty. Otherwise the class must be the class of the record, the
+    /// royalty config its `["royalty", class]` PDA, and the payer and the destination of the
+    /// royalty config are required.
+    pub fn try_from_accounts(
+        record: &AccountInfo,
+        class: Option<&AccountInfo>,
+        royalty_config: Option<&'info AccountInfo>,
+        payer: Option<&'info AccountInfo>,
+        destination: Option<&'info AccountInfo>,
+    ) -> Result<Option<Self>, ProgramError> {
+        // The SRS program id is passed as a placeholder when the royalty config is omitted
+        let Some(royalty_config) =
+            royalty_config.filter(|royalty_config| royalty_config.key().ne(&crate::ID))
+        else {
+            return Ok(None);
+        };
+
+        // The royalty config is checked against the class of the record
+        let class = class.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Check if the class is the class of the record
+        if class
+            .key()
+            .ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the royalty config is the royalty config PDA of the class
+        RoyaltyConfig::check_address(royalty_config, class.key())?;
+
+        // Classes without a royalty config charge no royalty
+        if !RoyaltyConfig::is_initialized(royalty_config)? {
+            return Ok(None);
+        }
+
+        let royalty_data = royalty_config.try_borrow_data()?;
+        if unsafe { RoyaltyConfig::get_basis_points_unchecked(&royalty_data) } == 0 {
+            return Ok(None);
+        }
+
+        let (Some(payer), Some(destination)) = (
+            payer.filter(|payer| payer.key().ne(&crate::ID)),
+            destination.filter(|destination| destination.key().ne(&crate::ID)),
+        ) else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the destination is the royalty destination
+        if destination
+            .key()
+            .ne(unsafe { RoyaltyConfig::get_destination_unchecked(&royalty_data) })
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the payer is signer
+        if !payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Some(Self {
+            royalty_config,
+            payer,
+            destination,
+        }))
+    }
+
+    /// Pays the royalty on a transfer of `value_lamports` to the royalty destination
+    pub fn pay(&self, value_lamports: u64) -> ProgramResult {
+        let basis_points = unsafe {
+            RoyaltyConfig::get_basis_points_unchecked(&self.royalty_config.try_borrow_data()?)
+        };
+
+        let fee = (value_lamports as u128 * basis_points as u128
+            / RoyaltyConfig::MAX_BASIS_POINTS as u128) as u64;
+
+        if fee == 0 {
+            return Ok(());
+        }
+
+        Transfer {
+            from: self.payer,
+            to: self.destination,
+            lamports: fee,
+        }
+        .invoke()
+    }
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for TransferTokenizedRecordAccounts<'info> {
+/// Required accounts of TransferTokenizedRecord, the class, the royalty accounts and the fee
+/// destination follow as optional remaining accounts
+pub const TRANSFER_TOKENIZED_RECORD_FIXED_ACCOUNTS: usize = 6;
+
+impl<'info> TryFrom<&Context<'info>> for TransferTokenizedRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, mint, token_account, new_token_account, record, _system_program, rest @ ..] =
-            accounts
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, mint, token_account, new_token_account, record, _token_2022_program] =
+            ctx.accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
@@ -51,26 +191,58 @@ impl<'info> TryFrom<&'info [AccountInfo]> for TransferTokenizedRecordAccounts<'i
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        // The SRS program id is passed as a placeholder when the class is omitted
+        let class = ctx
+            .remaining_accounts
+            .first()
+            .filter(|class| class.key().ne(&crate::ID));
+
         // Check if authority is the record owner or has a delegate
-        Record::check_owner_or_delegate_tokenized(
+        Record::check_owner_or_delegate_tokenized(record, class, authority, mint, token_account)?;
+
+        // Check if the record is non-transferable
+        unsafe { Record::check_transferable_unchecked(&record.try_borrow_data()?)? };
+
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record.try_borrow_data()?)? };
+
+        let royalty = RoyaltyAccounts::try_from_accounts(
             record,
-            rest.first(),
-            authority,
-            mint,
-            token_account,
+            class,
+            ctx.remaining_accounts.get(1),
+            ctx.remaining_accounts.get(2),
+            ctx.remaining_accounts.get(3),
         )?;
 
+        // Only a passed class can charge a transfer fee
+        let transfer_fee = match class {
+            Some(class) => TransferFeeAccounts::try_from_accounts(
+                record,
+                class,
+                ctx.remaining_accounts.get(2),
+                ctx.remaining_accounts.get(5),
+            )?,
+            None => None,
+        };
+
+        // Check if the mint is the mint PDA of the record
+        let mint_bump = Record::check_mint_address(record, mint)?;
+
         Ok(Self {
             mint,
+            mint_bump,
             token_account,
             new_token_account,
             record,
+            royalty,
+            transfer_fee,
         })
     }
 }
 
 pub struct TransferTokenizedRecord<'info> {
     accounts: TransferTokenizedRecordAccounts<'info>,
+    token_value_lamports: u64,
 }
 
 impl<'info> TryFrom<Context<'info>> for TransferTokenizedRecord<'info> {
@@ -78,9 +250,19 @@ impl<'info> TryFrom<Context<'info>> for TransferTokenizedRecord<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = TransferTokenizedRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = TransferTokenizedRecordAccounts::try_from(&ctx)?;
+
+        // Deserialize the optional `token_value_lamports`, transfers without it carry no value
+        let token_value_lamports: u64 = if ctx.data.is_empty() {
+            0
+        } else {
+            ByteReader::new(ctx.data).read()?
+        };
 
-        Ok(Self { accounts })
+        Ok(Self {
+            accounts,
+            token_value_lamports,
+        })
     }
 }
 
@@ -92,11 +274,7 @@ impl<'info> TransferTokenizedRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        let bump = [
-            try_find_program_address(&[b"mint", self.accounts.record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-        ];
+        let bump = self.accounts.mint_bump;
 
         let seeds = [
             Seed::from(b"mint"),
@@ -106,16 +284,38 @@ impl<'info> TransferTokenizedRecord<'info> {
 
         let signers = [Signer::from(&seeds)];
 
+        // SRS mints are always NFT-style (1 supply, 0 decimals), so the transfer
+        // moves the single token of the mint
+        if Mint::get_supply(self.accounts.mint)? != TOKENIZED_RECORD_AMOUNT
+            || Mint::get_decimals(self.accounts.mint)? != TOKENIZED_RECORD_DECIMALS
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         TransferChecked {
             source: self.accounts.token_account,
             mint: self.accounts.mint,
             destination: self.accounts.new_token_account,
             authority: self.accounts.mint,
-            amount: 1,
-            decimals: 0,
+            amount: TOKENIZED_RECORD_AMOUNT,
+            decimals: TOKENIZED_RECORD_DECIMALS,
         }
         .invoke_signed(&signers)?;
 
+        if let Some(royalty) = &self.accounts.royalty {
+            royalty.pay(self.token_value_lamports)?;
+        }
+
+        if let Some(transfer_fee) = &self.accounts.transfer_fee {
+            transfer_fee.pay()?;
+        }
+
+        EventType::TransferTokenizedRecord.emit(&[
+            self.accounts.record.key(),
+            self.accounts.token_account.key(),
+            self.accounts.new_token_account.key(),
+        ]);
+
         Ok(())
     }
 }
@@ -1,7 +1,11 @@
-use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
 use crate::constants::MAX_METADATA_LEN;
+use crate::events::EventType;
 use crate::state::Class;
-use crate::utils::{ByteReader, Context};
+use crate::utils::{is_system_program, ByteReader, Context};
+use core::mem::size_of;
 use pinocchio::pubkey::Pubkey;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
 
@@ -9,7 +13,7 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramR
 ///
 /// This function:
 /// 1. Loads the current class state
-/// 2. Updates the metadata
+/// 2. Updates the record limits, the transfer fee, the metadata schema uri and the metadata
 /// 3. Saves the updated state
 ///
 /// # Accounts
@@ -20,6 +24,9 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramR
 ///
 /// # Security
 /// 1. The authority must be a signer and should be the owner of the class
+/// 2. A new authority must not be the zero address
+/// 3. The transfer fee must not exceed 10000 basis points, and a class charging a transfer fee
+///    must have a fee destination
 pub struct UpdateClassAccounts<'info> {
     payer: &'info AccountInfo,
     class: &'info AccountInfo,
@@ -42,6 +49,11 @@ impl<'info> TryFrom<&'info [AccountInfo]> for UpdateClassAccounts<'info> {
 // UpdateClassMetadata
 pub struct UpdateClassMetadata<'info> {
     accounts: UpdateClassAccounts<'info>,
+    max_record_size: u32,
+    max_records: u32,
+    transfer_fee_bps: u16,
+    fee_destination: Pubkey,
+    metadata_schema_uri: Option<&'info str>,
     metadata: &'info str,
 }
 
@@ -54,6 +66,25 @@ impl<'info> TryFrom<Context<'info>> for UpdateClassMetadata<'info> {
         // Create a byte reader
         let mut data = ByteReader::new(ctx.data);
 
+        // Deserialize record limits, 0 means unlimited
+        let max_record_size: u32 = data.read()?;
+        let max_records: u32 = data.read()?;
+
+        // Deserialize transfer fee, 0 means no fee
+        let transfer_fee_bps: u16 = data.read()?;
+        let fee_destination: Pubkey = data.read()?;
+
+        // Validate transfer fee
+        if transfer_fee_bps > Class::MAX_TRANSFER_FEE_BPS
+            || (transfer_fee_bps > 0 && is_system_program(&fee_destination))
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize metadata schema uri, an empty string means no schema
+        let metadata_schema_uri = data.read_str_with_length()?;
+        let metadata_schema_uri = (!metadata_schema_uri.is_empty()).then_some(metadata_schema_uri);
+
         // Deserialize metadata
         let metadata = data.read_str(data.remaining_bytes())?;
 
@@ -62,7 +93,15 @@ impl<'info> TryFrom<Context<'info>> for UpdateClassMetadata<'info> {
             return Err(ProgramError::InvalidInstructionData);
         }
 
-        Ok(UpdateClassMetadata { accounts, metadata })
+        Ok(UpdateClassMetadata {
+            accounts,
+            max_record_size,
+            max_records,
+            transfer_fee_bps,
+            fee_destination,
+            metadata_schema_uri,
+            metadata,
+        })
     }
 }
 
@@ -75,12 +114,29 @@ impl<'info> UpdateClassMetadata<'info> {
 
     pub fn execute(&self) -> ProgramResult {
         unsafe {
+            Class::update_limits_unchecked(
+                self.accounts.class,
+                self.max_record_size,
+                self.max_records,
+            )?;
+
+            Class::update_transfer_fee_unchecked(
+                self.accounts.class,
+                self.transfer_fee_bps,
+                self.fee_destination,
+            )?;
+
             Class::update_metadata_unchecked(
                 self.accounts.class,
                 self.accounts.payer,
+                self.metadata_schema_uri,
                 self.metadata,
-            )
+            )?;
         }
+
+        EventType::UpdateClassMetadata.emit(&[self.accounts.class.key()]);
+
+        Ok(())
     }
 }
 
@@ -103,9 +159,19 @@ impl<'info> TryFrom<Context<'info>> for UpdateClassAuthority<'info> {
         }
 
         // Deserialize authority
-        let authority = ctx.data[0..size_of::<Pubkey>()].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+        let authority = ctx.data[0..size_of::<Pubkey>()]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        // Check that the new authority is not the zero address
+        if is_system_program(&authority) {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        Ok(UpdateClassAuthority { accounts, authority })
+        Ok(UpdateClassAuthority {
+            accounts,
+            authority,
+        })
     }
 }
 
@@ -118,10 +184,11 @@ impl<'info> UpdateClassAuthority<'info> {
 
     pub fn execute(&self) -> ProgramResult {
         unsafe {
-            Class::update_authority_unchecked(
-                self.accounts.class,
-                self.authority,
-            )
+            Class::update_authority_unchecked(self.accounts.class, self.authority)?;
         }
+
+        EventType::UpdateClassAuthority.emit(&[self.accounts.class.key(), &self.authority]);
+
+        Ok(())
     }
 }
@@ -0,0 +1,92 @@
+use crate::{
+    events::EventType,
+    state::Class,
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// UpdateClassPermission instruction.
+///
+/// This function:
+/// 1. Loads the current class state
+/// 2. Updates the permissioned status
+/// 3. Saves the updated state
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to update the class (must be a signer)
+/// 2. `class` - The class account to be updated
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. The class must not be frozen
+pub struct UpdateClassPermissionAccounts<'info> {
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateClassPermissionAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Account Checks
+        Class::check_authority(class, authority)?;
+
+        Ok(Self { class })
+    }
+}
+
+const IS_PERMISSIONED_OFFSET: usize = 0;
+pub struct UpdateClassPermission<'info> {
+    accounts: UpdateClassPermissionAccounts<'info>,
+    is_permissioned: bool,
+}
+
+/// Minimum length of instruction data required for UpdateClassPermission
+pub const UPDATE_CLASS_PERMISSION_MIN_IX_LENGTH: usize = size_of::<u8>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateClassPermission<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateClassPermissionAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_CLASS_PERMISSION_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `is_permissioned`
+        let is_permissioned: bool = ByteReader::read_with_offset(ctx.data, IS_PERMISSIONED_OFFSET)?;
+
+        Ok(Self {
+            accounts,
+            is_permissioned,
+        })
+    }
+}
+
+impl<'info> UpdateClassPermission<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Class Permission");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        unsafe {
+            Class::update_is_permissioned_unchecked(self.accounts.class, self.is_permissioned)?;
+        }
+
+        EventType::UpdateClassPermission.emit(&[self.accounts.class.key()]);
+
+        Ok(())
+    }
+}
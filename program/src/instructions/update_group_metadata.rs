@@ -0,0 +1,161 @@
+use crate::{
+    constants::MAX_METADATA_LEN,
+    events::EventType,
+    state::Class,
+    token2022::{Mint, UpdateMetadata},
+    utils::{ByteWriter, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::try_find_program_address,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+/// Token metadata field holding the class metadata of a group
+const URI_FIELD: u8 = 2;
+
+/// UpdateGroupMetadata instruction.
+///
+/// This function:
+/// 1. Validates the authority, class and group
+/// 2. Tops up the group account for its new metadata size
+/// 3. Updates the group metadata uri to the current class metadata, signed
+///    by the group PDA
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will pay for the group resizing
+/// 3. `class` - The class account of the group
+/// 4. `group` - The group mint account of the class
+/// 5. `token_2022_program` - Required for updating the group metadata
+/// 6. `system_program` - Required for topping up the group account
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. `group` must be the group PDA of the class
+pub struct UpdateGroupMetadataAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    group: &'info AccountInfo,
+    group_bump: [u8; 1],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateGroupMetadataAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, group, _token_2022_program, _system_program] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the Group is correct
+        Mint::check_program_id(group)?;
+
+        // Check if the group is the group PDA of the class
+        let (address, bump) = try_find_program_address(&[b"group", class.key()], &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        if address.ne(group.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            payer,
+            class,
+            group,
+            group_bump: [bump],
+        })
+    }
+}
+
+pub struct UpdateGroupMetadata<'info> {
+    accounts: UpdateGroupMetadataAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for UpdateGroupMetadata<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateGroupMetadataAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> UpdateGroupMetadata<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Group Metadata");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Serialize the class metadata as a length prefixed uri
+        let mut uri = [0u8; size_of::<u32>() + MAX_METADATA_LEN];
+        let uri_len = {
+            let class_data = self.accounts.class.try_borrow_data()?;
+            let (_, metadata) = unsafe { Class::get_name_and_metadata_unchecked(&class_data)? };
+
+            let mut writer = ByteWriter::new_with_offset(&mut uri, 0);
+            writer.write(metadata.len() as u32)?;
+            writer.write_bytes(metadata)?;
+
+            size_of::<u32>() + metadata.len()
+        };
+
+        // Top up the group so it stays rent exempt once Token2022 resizes it
+        let current_uri_len = {
+            let group_data = self.accounts.group.try_borrow_data()?;
+            unsafe { Mint::get_token_metadata_unchecked(&group_data)? }.fields[URI_FIELD as usize]
+                .len()
+        };
+
+        let lamports = Rent::get()?.minimum_balance(
+            (self.accounts.group.data_len() + uri_len).saturating_sub(current_uri_len),
+        );
+
+        if self.accounts.group.lamports() < lamports {
+            Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.group,
+                lamports: lamports - self.accounts.group.lamports(),
+            }
+            .invoke()?;
+        }
+
+        let seeds = [
+            Seed::from(b"group"),
+            Seed::from(self.accounts.class.key()),
+            Seed::from(&self.accounts.group_bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        UpdateMetadata {
+            metadata: self.accounts.group,
+            update_authority: self.accounts.group,
+            field: URI_FIELD,
+            value: &uri[..uri_len],
+        }
+        .invoke_signed(&signers)?;
+
+        EventType::UpdateGroupMetadata.emit(&[
+            self.accounts.class.key(),
+            self.accounts.group.key(),
+        ]);
+
+        Ok(())
+    }
+}
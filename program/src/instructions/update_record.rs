@@ -1,11 +1,15 @@
-use core::mem::size_of;
 use crate::{
-    state::{Class, Record, CLASS_OFFSET},
+    constants::{RESIZE_RECORD_DATA_ONLY_IX, UPDATE_RECORD_DATA_IX, UPDATE_RECORD_EXPIRY_IX},
+    events::EventType,
+    state::{Class, ClassStats, Record, RecordLease, CLASS_OFFSET},
     utils::{ByteReader, Context},
 };
+use core::mem::size_of;
 #[cfg(not(feature = "perf"))]
 use pinocchio::log::sol_log;
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
 
 /// UpdateRecord instruction.
 ///
@@ -13,6 +17,9 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// 1. Validates the authority and record
 /// 2. Updates the record's data content
 /// 3. Resizes the account if needed
+/// 4. Sets the record `updated_at` to the current timestamp
+/// 5. Increments the record `revision`
+/// 6. Applies the change of the record data length to the class stats
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to update the record (must be a signer)
@@ -20,19 +27,40 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// 3. `record` - The record account to be updated
 /// 4. `class` - The class account of the record
 /// 5. `system_program` - Required for account resizing operations
-/// 
+/// 6. `class_stats` - The stats account of the class of the record, only for UpdateRecordData
+/// 7. `lease` - [optional] An active record lease held by the authority, passed right after
+///    `system_program` by the instructions without the class stats account
+///
 /// # Security
-/// 1. The authority must be the class authority
+/// 1. The authority must be either:
+///    a. The class authority, or
+///    b. The lessee of an active record lease granting the operation
+/// 2. The record must not be expired
+/// 3. A lessee can only set an expiry after the end of its lease, or no expiry at all
+/// 4. For UpdateRecordDataWithRevision, a non-zero `expected_revision` must match the record
+///    revision, otherwise the update fails with `StaleRevision`
 pub struct UpdateRecordAccounts<'info> {
+    authority: &'info AccountInfo,
     payer: &'info AccountInfo,
     record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    permissions: u8,
+    lease_expires_at: Option<i64>,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for UpdateRecordAccounts<'info> {
+/// Required accounts of the record update instructions, the record lease follows as optional
+/// account
+pub const UPDATE_RECORD_FIXED_ACCOUNTS: usize = 5;
+
+/// Required accounts of UpdateRecordData, the class stats account follows the required accounts
+/// of the record update instructions and the record lease follows as optional account
+pub const UPDATE_RECORD_DATA_FIXED_ACCOUNTS: usize = UPDATE_RECORD_FIXED_ACCOUNTS + 1;
+
+impl<'info> TryFrom<&Context<'info>> for UpdateRecordAccounts<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, payer, record, class, _system_program] = accounts else {
+    fn try_from(ctx: &Context<'info>) -> Result<Self, Self::Error> {
+        let [authority, payer, record, class, _system_program, ..] = ctx.accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -40,23 +68,52 @@ impl<'info> TryFrom<&'info [AccountInfo]> for UpdateRecordAccounts<'info> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Check if authority is the record owner or has a delegate
-        Class::check_authority(class, authority)?;
+        // Check if authority is the class authority or the lessee of an active lease
+        // (the SRS program id is passed as a placeholder when no lease is provided)
+        let (permissions, lease_expires_at) = match ctx
+            .remaining_accounts
+            .first()
+            .filter(|lease| lease.key().ne(&crate::ID))
+        {
+            Some(lease) => {
+                let (permissions, expires_at) =
+                    RecordLease::check_lessee(lease, record, authority)?;
+                (permissions, Some(expires_at))
+            }
+            None => {
+                Class::check_authority(class, authority)?;
+                (RecordLease::PERMISSION_ALL, None)
+            }
+        };
 
         // Check if the Record is correct
         Record::check_program_id_and_discriminator(record)?;
 
         // Check if the class is the correct class
-        if class.key().ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+        if class
+            .key()
+            .ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
             return Err(ProgramError::InvalidAccountData);
         }
 
-        Ok(Self { payer, record })
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record.try_borrow_data()?)? };
+
+        Ok(Self {
+            authority,
+            payer,
+            record,
+            class,
+            permissions,
+            lease_expires_at,
+        })
     }
 }
 
 pub struct UpdateRecordData<'info> {
     accounts: UpdateRecordAccounts<'info>,
+    class_stats: &'info AccountInfo,
     data: &'info str,
 }
 
@@ -65,7 +122,17 @@ impl<'info> TryFrom<Context<'info>> for UpdateRecordData<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = UpdateRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = UpdateRecordAccounts::try_from(&ctx)?;
+
+        // Check if the authority is allowed to update the data
+        if accounts.permissions & RecordLease::PERMISSION_UPDATE_DATA == 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // The class stats account follows the required accounts of the record update instructions
+        let [_, _, _, _, _, class_stats] = ctx.accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
 
         // Check ix data has minimum length and create a byte reader
         let mut instruction_data = ByteReader::new(ctx.data);
@@ -73,7 +140,16 @@ impl<'info> TryFrom<Context<'info>> for UpdateRecordData<'info> {
         // Deserialize `data`
         let data: &str = instruction_data.read_str(instruction_data.remaining_bytes())?;
 
-        Ok(Self { accounts, data })
+        // Check the data against the class record size limit
+        unsafe {
+            Class::check_record_size_unchecked(&accounts.class.try_borrow_data()?, data.len())?
+        };
+
+        Ok(Self {
+            accounts,
+            class_stats,
+            data,
+        })
     }
 }
 
@@ -85,10 +161,65 @@ impl<'info> UpdateRecordData<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
+        let previous_data_len =
+            unsafe { Record::get_data_len_unchecked(&self.accounts.record.try_borrow_data()?) };
+
         // Update the record data [this is safe, check safety docs]
         unsafe {
-            Record::update_data_unchecked(self.accounts.record, self.accounts.payer, self.data)
+            Record::update_data_unchecked(self.accounts.record, self.accounts.payer, self.data)?;
+
+            ClassStats::resize_record(self.class_stats, self.accounts.record, previous_data_len)?;
+
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_updated_at_unchecked(&mut data)?;
+            Record::increment_revision_unchecked(&mut data)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                UPDATE_RECORD_DATA_IX,
+                self.accounts.authority.key(),
+            )?;
         }
+
+        EventType::UpdateRecordData.emit(&[self.accounts.record.key()]);
+
+        Ok(())
+    }
+}
+
+/// UpdateRecordData with an optimistic lock on the record revision, its instruction data is the
+/// `expected_revision` followed by the data of UpdateRecordData
+pub struct UpdateRecordDataWithRevision<'info> {
+    update: UpdateRecordData<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for UpdateRecordDataWithRevision<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize `expected_revision`, the data of UpdateRecordData follows
+        let mut instruction_data = ByteReader::new(ctx.data);
+        let expected_revision: u32 = instruction_data.read()?;
+        let data = instruction_data.read_bytes(instruction_data.remaining_bytes())?;
+
+        let update = UpdateRecordData::try_from(Context { data, ..ctx })?;
+
+        // Check the record has not been updated since the expected revision
+        unsafe {
+            Record::check_revision_unchecked(
+                &update.accounts.record.try_borrow_data()?,
+                expected_revision,
+            )?
+        };
+
+        Ok(Self { update })
+    }
+}
+
+impl<'info> UpdateRecordDataWithRevision<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Record Data With Revision");
+        Self::try_from(ctx)?.update.execute()
     }
 }
 
@@ -102,7 +233,12 @@ impl<'info> TryFrom<Context<'info>> for UpdateRecordExpiry<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = UpdateRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = UpdateRecordAccounts::try_from(&ctx)?;
+
+        // Check if the authority is allowed to update the expiry
+        if accounts.permissions & RecordLease::PERMISSION_UPDATE_EXPIRY == 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
 
         // Check minimum instruction data length
         #[cfg(not(feature = "perf"))]
@@ -111,7 +247,19 @@ impl<'info> TryFrom<Context<'info>> for UpdateRecordExpiry<'info> {
         }
 
         // Deserialize `data`
-        let expiry = i64::from_le_bytes( ctx.data[0..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let expiry = i64::from_le_bytes(
+            ctx.data[0..8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        // Check that a lessee does not expire the record before the end of its lease, which
+        // would make the record reclaimable
+        if let Some(lease_expires_at) = accounts.lease_expires_at {
+            if expiry != 0 && expiry < lease_expires_at {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
 
         Ok(Self { accounts, expiry })
     }
@@ -127,7 +275,91 @@ impl<'info> UpdateRecordExpiry<'info> {
     pub fn execute(&self) -> ProgramResult {
         // Update the record data [this is safe, check safety docs]
         unsafe {
-            Record::update_expiry_unchecked(&mut self.accounts.record.try_borrow_mut_data()?, self.expiry)
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_expiry_unchecked(&mut data, self.expiry)?;
+            Record::update_updated_at_unchecked(&mut data)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                UPDATE_RECORD_EXPIRY_IX,
+                self.accounts.authority.key(),
+            )?;
+        }
+
+        EventType::UpdateRecordExpiry.emit(&[self.accounts.record.key()]);
+
+        Ok(())
+    }
+}
+
+/// Overwrites part of the record data in place, without resizing the record
+/// account. Fails with `InvalidInstructionData` if the patch does not fit in
+/// the current data.
+pub struct ResizeRecordDataOnly<'info> {
+    accounts: UpdateRecordAccounts<'info>,
+    byte_offset: u32,
+    patch: &'info str,
+}
+
+/// Minimum length of instruction data required for ResizeRecordDataOnly
+pub const RESIZE_RECORD_DATA_ONLY_MIN_IX_LENGTH: usize = size_of::<u32>();
+
+impl<'info> TryFrom<Context<'info>> for ResizeRecordDataOnly<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateRecordAccounts::try_from(&ctx)?;
+
+        // Check if the authority is allowed to update the data
+        if accounts.permissions & RecordLease::PERMISSION_UPDATE_DATA == 0 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < RESIZE_RECORD_DATA_ONLY_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `byte_offset`
+        let byte_offset: u32 = instruction_data.read()?;
+
+        // Deserialize `patch`
+        let patch: &str = instruction_data.read_str(instruction_data.remaining_bytes())?;
+
+        Ok(Self {
+            accounts,
+            byte_offset,
+            patch,
+        })
+    }
+}
+
+impl<'info> ResizeRecordDataOnly<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Resize Record Data Only");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Patch the record data in place [this is safe, check safety docs]
+        unsafe {
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::patch_data_unchecked(&mut data, self.byte_offset as usize, self.patch)?;
+            Record::update_updated_at_unchecked(&mut data)?;
+            Record::increment_revision_unchecked(&mut data)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                RESIZE_RECORD_DATA_ONLY_IX,
+                self.accounts.authority.key(),
+            )?;
         }
+
+        EventType::ResizeRecordDataOnly.emit(&[self.accounts.record.key()]);
+
+        Ok(())
     }
 }
@@ -0,0 +1,111 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    events::EventType,
+    state::{Class, RecordAccessList},
+    utils::{ByteReader, Context},
+};
+
+/// UpdateRecordAccessList instruction.
+///
+/// This function:
+/// 1. Validates the class authority and the access list
+/// 2. Resizes the access list account for the new entries
+/// 3. Replaces the listed accounts
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will pay or get refunded for the access list resizing
+/// 3. `class` - The class of the record
+/// 4. `access_list` - The record access list account to be updated
+/// 5. `system_program` - Required for account resizing operations
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The access list must belong to the class
+/// 3. The access list must not list more than 32 accounts
+pub struct UpdateRecordAccessListAccounts<'info> {
+    payer: &'info AccountInfo,
+    access_list: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateRecordAccessListAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, access_list, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the access list belongs to the class
+        RecordAccessList::check_class(access_list, class)?;
+
+        Ok(Self { payer, access_list })
+    }
+}
+
+pub struct UpdateRecordAccessList<'info> {
+    accounts: UpdateRecordAccessListAccounts<'info>,
+    entries: &'info [u8],
+}
+
+/// Minimum length of instruction data required for UpdateRecordAccessList
+pub const UPDATE_RECORD_ACCESS_LIST_MIN_IX_LENGTH: usize = size_of::<u8>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateRecordAccessList<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateRecordAccessListAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_RECORD_ACCESS_LIST_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `entries`
+        let entries_len: u8 = instruction_data.read()?;
+        let entries: &[u8] =
+            instruction_data.read_bytes(entries_len as usize * size_of::<Pubkey>())?;
+
+        RecordAccessList::check_entries(entries)?;
+
+        Ok(Self { accounts, entries })
+    }
+}
+
+impl<'info> UpdateRecordAccessList<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Record Access List");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        unsafe {
+            RecordAccessList::update_entries_unchecked(
+                self.accounts.access_list,
+                self.accounts.payer,
+                self.entries,
+            )?;
+        }
+
+        EventType::UpdateRecordAccessList.emit(&[self.accounts.access_list.key()]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    events::EventType,
+    state::{Class, RoyaltyConfig},
+    utils::{is_system_program, ByteReader, Context},
+};
+
+/// UpdateRoyaltyConfig instruction.
+///
+/// This function:
+/// 1. Validates the class authority and the royalty config
+/// 2. Updates the basis points and destination of the royalty config
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `class` - The class the royalties apply to
+/// 3. `royalty_config` - The royalty config account to be updated
+///
+/// # Security
+/// 1. The authority must be the class authority
+/// 2. The royalty config must belong to the class
+/// 3. The basis points must not exceed 10000
+/// 4. The destination must not be the zero address
+pub struct UpdateRoyaltyConfigAccounts<'info> {
+    royalty_config: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateRoyaltyConfigAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class, royalty_config] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the royalty config belongs to the class
+        RoyaltyConfig::check_class(royalty_config, class.key())?;
+
+        Ok(Self { royalty_config })
+    }
+}
+
+const BASIS_POINTS_OFFSET: usize = 0;
+const DESTINATION_OFFSET: usize = BASIS_POINTS_OFFSET + size_of::<u16>();
+
+pub struct UpdateRoyaltyConfig<'info> {
+    accounts: UpdateRoyaltyConfigAccounts<'info>,
+    basis_points: u16,
+    destination: Pubkey,
+}
+
+/// Minimum length of instruction data required for UpdateRoyaltyConfig
+pub const UPDATE_ROYALTY_CONFIG_MIN_IX_LENGTH: usize = size_of::<u16>() + size_of::<Pubkey>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateRoyaltyConfig<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateRoyaltyConfigAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_ROYALTY_CONFIG_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `basis_points`
+        let basis_points: u16 = ByteReader::read_with_offset(ctx.data, BASIS_POINTS_OFFSET)?;
+
+        // Deserialize `destination`
+        let destination: Pubkey = ByteReader::read_with_offset(ctx.data, DESTINATION_OFFSET)?;
+
+        // Check that the royalty does not exceed the transfer value
+        if basis_points > RoyaltyConfig::MAX_BASIS_POINTS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Check that the royalties are not sent to the zero address
+        if is_system_program(&destination) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            accounts,
+            basis_points,
+            destination,
+        })
+    }
+}
+
+impl<'info> UpdateRoyaltyConfig<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Royalty Config");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let mut data = self.accounts.royalty_config.try_borrow_mut_data()?;
+
+        unsafe { RoyaltyConfig::update_unchecked(&mut data, self.basis_points, &self.destination)? };
+
+        EventType::UpdateRoyaltyConfig.emit(&[self.accounts.royalty_config.key()]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    constants::UPDATE_TOKENIZED_RECORD_METADATA_IX,
+    error::SrsError,
+    events::EventType,
+    state::{Class, ClassStats, OwnerType, Record, CLASS_OFFSET, OWNER_OFFSET, OWNER_TYPE_OFFSET},
+    token2022::{Mint, RemoveKey, TokenMetadata, UpdateMetadata},
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+/// UpdateTokenizedRecordMetadata instruction.
+///
+/// This function:
+/// 1. Validates the authority, record and mint
+/// 2. Tops up the mint account for its new metadata size
+/// 3. Updates the mint metadata in place, signed by the mint PDA, removing
+///    the additional metadata keys that are no longer present
+/// 4. Updates the record data to the new metadata
+/// 5. Sets the record `updated_at` and increments the record `revision`
+/// 6. Applies the change of the record data length to the class stats
+///
+/// # Accounts
+/// 1. `authority` - The class authority (must be a signer)
+/// 2. `payer` - The account that will pay for the record and mint resizing
+/// 3. `record` - The tokenized record account to be updated
+/// 4. `mint` - The mint account of the record token
+/// 5. `class` - The class account of the record
+/// 6. `token_2022_program` - Required for updating the mint metadata
+/// 7. `system_program` - Required for account resizing operations
+/// 8. `class_stats` - The stats account of the class of the record
+///
+/// # Security
+/// 1. The authority must be the class authority, like for `UpdateRecordData`
+/// 2. The record must be tokenized and `mint` must be its mint
+/// 3. The record must not be expired
+/// 4. If a non-zero `expected_revision` is provided, it must match the record
+///    revision, otherwise the update fails with `StaleRevision`
+pub struct UpdateTokenizedRecordMetadataAccounts<'info> {
+    authority: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    mint: &'info AccountInfo,
+    class_stats: &'info AccountInfo,
+    mint_bump: [u8; 1],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateTokenizedRecordMetadataAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, record, mint, class, _token_2022_program, _system_program, class_stats] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check if the Record is correct
+        Record::check_program_id_and_discriminator(record)?;
+
+        // Check if the Mint is correct
+        Mint::check_program_id(mint)?;
+
+        let record_data = record.try_borrow_data()?;
+
+        // Check if the class is the correct class
+        if class.key().ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the record is tokenized with this mint
+        if record_data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Token))
+            || mint.key().ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(SrsError::NotTokenized.into());
+        }
+
+        // Check if the record has expired
+        unsafe { Record::check_not_expired_unchecked(&record_data)? };
+
+        // Check if the mint is the mint PDA of the record
+        let mint_bump = Record::check_mint_address(record, mint)?;
+
+        Ok(Self {
+            authority,
+            payer,
+            record,
+            mint,
+            class_stats,
+            mint_bump,
+        })
+    }
+}
+
+pub struct UpdateTokenizedRecordMetadata<'info> {
+    accounts: UpdateTokenizedRecordMetadataAccounts<'info>,
+    metadata: &'info str,
+}
+
+/// Minimum length of instruction data required for UpdateTokenizedRecordMetadata
+pub const UPDATE_TOKENIZED_RECORD_METADATA_MIN_IX_LENGTH: usize = size_of::<u32>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateTokenizedRecordMetadata<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateTokenizedRecordMetadataAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_TOKENIZED_RECORD_METADATA_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `expected_revision`
+        let expected_revision: u32 = instruction_data.read()?;
+
+        // Check the record has not been updated since the expected revision
+        unsafe {
+            Record::check_revision_unchecked(
+                &accounts.record.try_borrow_data()?,
+                expected_revision,
+            )?
+        };
+
+        // Deserialize `metadata`
+        let metadata: &str = instruction_data.read_str(instruction_data.remaining_bytes())?;
+
+        // Check that the metadata is well formed
+        TokenMetadata::from_bytes(metadata.as_bytes())
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        Ok(Self { accounts, metadata })
+    }
+}
+
+impl<'info> UpdateTokenizedRecordMetadata<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Tokenized Record Metadata");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        {
+            let record_data = self.accounts.record.try_borrow_data()?;
+            let current = unsafe { Record::get_data_unchecked(&record_data)? }.as_bytes();
+
+            let current = TokenMetadata::from_bytes(current)?;
+            let metadata = TokenMetadata::from_bytes(self.metadata.as_bytes())?;
+
+            // Top up the mint so it stays rent exempt once Token2022 resizes it
+            let current_len = current.fields.iter().map(|field| field.len()).sum::<usize>()
+                + current.additional_metadata.len();
+            let new_len = metadata.fields.iter().map(|field| field.len()).sum::<usize>()
+                + metadata.additional_metadata.len();
+
+            let lamports = Rent::get()?.minimum_balance(
+                (self.accounts.mint.data_len() + new_len).saturating_sub(current_len),
+            );
+
+            if self.accounts.mint.lamports() < lamports {
+                Transfer {
+                    from: self.accounts.payer,
+                    to: self.accounts.mint,
+                    lamports: lamports - self.accounts.mint.lamports(),
+                }
+                .invoke()?;
+            }
+
+            let bump = self.accounts.mint_bump;
+
+            let seeds = [
+                Seed::from(b"mint"),
+                Seed::from(self.accounts.record.key()),
+                Seed::from(&bump),
+            ];
+
+            let signers = [Signer::from(&seeds)];
+
+            // Remove the additional metadata keys that are not part of the new metadata
+            for (key, _) in current.entries() {
+                if metadata.entries().any(|(new_key, _)| new_key.eq(key)) {
+                    continue;
+                }
+
+                RemoveKey {
+                    metadata: self.accounts.mint,
+                    update_authority: self.accounts.mint,
+                    idempotent: true,
+                    key,
+                }
+                .invoke_signed(&signers)?;
+            }
+
+            // Update the name, symbol and uri
+            for (field, value) in metadata.fields.into_iter().enumerate() {
+                UpdateMetadata {
+                    metadata: self.accounts.mint,
+                    update_authority: self.accounts.mint,
+                    field: field as u8,
+                    value,
+                }
+                .invoke_signed(&signers)?;
+            }
+
+            // Insert or update every additional metadata entry
+            for (_, entry) in metadata.entries() {
+                UpdateMetadata {
+                    metadata: self.accounts.mint,
+                    update_authority: self.accounts.mint,
+                    field: 3,
+                    value: entry,
+                }
+                .invoke_signed(&signers)?;
+            }
+        }
+
+        let previous_data_len =
+            unsafe { Record::get_data_len_unchecked(&self.accounts.record.try_borrow_data()?) };
+
+        // Update the record data [this is safe, check safety docs]
+        unsafe {
+            Record::update_data_unchecked(self.accounts.record, self.accounts.payer, self.metadata)?;
+
+            ClassStats::resize_record(
+                self.accounts.class_stats,
+                self.accounts.record,
+                previous_data_len,
+            )?;
+
+            let mut data = self.accounts.record.try_borrow_mut_data()?;
+            Record::update_updated_at_unchecked(&mut data)?;
+            Record::increment_revision_unchecked(&mut data)?;
+            Record::record_mutation_unchecked(
+                &mut data,
+                UPDATE_TOKENIZED_RECORD_METADATA_IX,
+                self.accounts.authority.key(),
+            )?;
+        }
+
+        EventType::UpdateTokenizedRecordMetadata.emit(&[
+            self.accounts.record.key(),
+            self.accounts.mint.key(),
+        ]);
+
+        Ok(())
+    }
+}
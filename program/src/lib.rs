@@ -1,4 +1,21 @@
+//! # Features
+//!
+//! `perf` (enabled by default) trades diagnostics and redundant validation
+//! for compute units. With `perf`, the program skips:
+//!
+//! - the `sol_log` of the instruction name at the start of each instruction
+//! - the minimum instruction data length checks, short data instead fails
+//!   when it is deserialized
+//! - the `MAX_SEED_LEN` checks on record seeds, long seeds instead fail the
+//!   PDA derivation
+//! - the class name and metadata length checks of `CreateClass`
+//! - the allocated size check of new record mints
+//!
+//! The test suite runs against both builds, and the compute unit ceilings in
+//! `tests.rs` guard the `perf` build against new logging or checks on its hot
+//! paths.
 #![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), deny(clippy::std_instead_of_core))]
 use instructions::*;
 use pinocchio::{
     account_info::AccountInfo, default_allocator, program_entrypoint, program_error::ProgramError,
@@ -7,6 +24,8 @@ use pinocchio::{
 use utils::Context;
 
 pub mod constants;
+pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 #[cfg(test)]
@@ -38,20 +57,95 @@ fn process_instruction(
         .ok_or(ProgramError::InvalidInstructionData)?;
 
     match discriminator {
-        0 => CreateClass::process(Context { accounts, data }),
-        1 => UpdateClassMetadata::process(Context { accounts, data }),
-        2 => UpdateClassAuthority::process(Context { accounts, data }),
-        3 => FreezeClass::process(Context { accounts, data }),
-        4 => CreateRecord::process(Context { accounts, data }),
-        5 => UpdateRecordData::process(Context { accounts, data }),
-        6 => UpdateRecordExpiry::process(Context { accounts, data }),
-        7 => TransferRecord::process(Context { accounts, data }),
-        8 => DeleteRecord::process(Context { accounts, data }),
-        9 => FreezeRecord::process(Context { accounts, data }),
-        10 => MintTokenizedRecord::process(Context { accounts, data }),
-        11 => FreezeTokenizedRecord::process(Context { accounts, data }),
-        12 => TransferTokenizedRecord::process(Context { accounts, data }),
-        13 => BurnTokenizedRecord::process(Context { accounts, data }),
+        0 => CreateClass::process(Context::new(accounts, data)),
+        1 => UpdateClassMetadata::process(Context::new(accounts, data)),
+        2 => UpdateClassAuthority::process(Context::new(accounts, data)),
+        3 => FreezeClass::process(Context::new(accounts, data)),
+        4 => CreateRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            create_record::CREATE_RECORD_FIXED_ACCOUNTS,
+        )),
+        5 => UpdateRecordData::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            update_record::UPDATE_RECORD_DATA_FIXED_ACCOUNTS,
+        )),
+        6 => UpdateRecordExpiry::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            update_record::UPDATE_RECORD_FIXED_ACCOUNTS,
+        )),
+        7 => TransferRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            transfer_record::TRANSFER_RECORD_FIXED_ACCOUNTS,
+        )),
+        8 => DeleteRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            delete_record::DELETE_RECORD_FIXED_ACCOUNTS,
+        )),
+        9 => FreezeRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            freeze_record::FREEZE_RECORD_FIXED_ACCOUNTS,
+        )),
+        10 => MintTokenizedRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            mint_tokenized_record::MINT_TOKENIZED_RECORD_FIXED_ACCOUNTS,
+        )),
+        11 => FreezeTokenizedRecord::process(Context::new(accounts, data)),
+        12 => TransferTokenizedRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            transfer_tokenized_record::TRANSFER_TOKENIZED_RECORD_FIXED_ACCOUNTS,
+        )),
+        13 => BurnTokenizedRecord::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            burn_tokenized_record::BURN_TOKENIZED_RECORD_FIXED_ACCOUNTS,
+        )),
+        14 => GrantRecordLease::process(Context::new(accounts, data)),
+        15 => RevokeRecordLease::process(Context::new(accounts, data)),
+        16 => ExpireRecordLease::process(Context::new(accounts, data)),
+        17 => DeleteClass::process(Context::new(accounts, data)),
+        18 => ReclaimExpiredRecord::process(Context::new(accounts, data)),
+        19 => CloneRecord::process(Context::new(accounts, data)),
+        20 => CreateRecordSnapshot::process(Context::new(accounts, data)),
+        21 => BatchCreateRecord::process(Context::new(accounts, data)),
+        22 => BatchDeleteRecord::process(Context::new(accounts, data)),
+        23 => ExtendRecordExpiry::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            extend_record_expiry::EXTEND_RECORD_EXPIRY_FIXED_ACCOUNTS,
+        )),
+        24 => RenameRecord::process(Context::new(accounts, data)),
+        25 => UpdateClassPermission::process(Context::new(accounts, data)),
+        26 => UpdateTokenizedRecordMetadata::process(Context::new(accounts, data)),
+        27 => AddAdditionalMetadata::process(Context::new(accounts, data)),
+        28 => RemoveAdditionalMetadata::process(Context::new(accounts, data)),
+        29 => CreateRoyaltyConfig::process(Context::new(accounts, data)),
+        30 => UpdateRoyaltyConfig::process(Context::new(accounts, data)),
+        31 => ResizeRecordDataOnly::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            update_record::UPDATE_RECORD_FIXED_ACCOUNTS,
+        )),
+        32 => RenewRecordLease::process(Context::new(accounts, data)),
+        33 => CreateRecordAccessList::process(Context::new(accounts, data)),
+        34 => UpdateRecordAccessList::process(Context::new(accounts, data)),
+        35 => DeleteRecordAccessList::process(Context::new(accounts, data)),
+        36 => UnfreezeClass::process(Context::new(accounts, data)),
+        37 => UpdateGroupMetadata::process(Context::new(accounts, data)),
+        38 => TransferRecordToClass::process(Context::new(accounts, data)),
+        39 => CreateRecordLease::process(Context::new(accounts, data)),
+        40 => UpdateRecordDataWithRevision::process(Context::with_remaining_accounts(
+            accounts,
+            data,
+            update_record::UPDATE_RECORD_DATA_FIXED_ACCOUNTS,
+        )),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
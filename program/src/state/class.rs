@@ -1,4 +1,8 @@
-use crate::utils::{resize_account, ByteWriter};
+use crate::{
+    error::SrsError,
+    state::ClassStats,
+    utils::{resize_account, ByteReader, ByteWriter},
+};
 use core::{mem::size_of, str};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
@@ -6,7 +10,12 @@ const DISCRIMINATOR_OFFSET: usize = 0;
 const AUTHORITY_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
 pub const IS_PERMISSIONED_OFFSET: usize = AUTHORITY_OFFSET + size_of::<Pubkey>();
 const IS_FROZEN_OFFSET: usize = IS_PERMISSIONED_OFFSET + size_of::<bool>();
-const NAME_LEN_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const TRANSFER_FEE_BPS_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const FEE_DESTINATION_OFFSET: usize = TRANSFER_FEE_BPS_OFFSET + size_of::<u16>();
+const MAX_RECORD_SIZE_OFFSET: usize = FEE_DESTINATION_OFFSET + size_of::<Pubkey>();
+const MAX_RECORDS_OFFSET: usize = MAX_RECORD_SIZE_OFFSET + size_of::<u32>();
+const BUMP_OFFSET: usize = MAX_RECORDS_OFFSET + size_of::<u32>();
+const NAME_LEN_OFFSET: usize = BUMP_OFFSET + size_of::<u8>();
 
 #[repr(C)]
 pub struct Class<'info> {
@@ -16,17 +25,55 @@ pub struct Class<'info> {
     pub is_permissioned: bool,
     /// Whether the class is frozen or not
     pub is_frozen: bool,
+    /// Fee charged on record transfers, in basis points of `TRANSFER_FEE_BASE_LAMPORTS`
+    pub transfer_fee_bps: u16,
+    /// The account receiving the transfer fee
+    pub fee_destination: Pubkey,
+    /// Maximum size of a record's data in this class, 0 means unlimited
+    pub max_record_size: u32,
+    /// Maximum number of live records in this class, 0 means unlimited
+    pub max_records: u32,
+    /// The bump of the class PDA, cached to avoid deriving it again
+    pub bump: u8,
     /// Human-readable name for the class
     pub name: &'info str,
+    /// Optional JSON Schema URI describing the expected record data format
+    pub metadata_schema_uri: Option<&'info str>,
     /// Optional metadata about the class
     pub metadata: &'info str,
 }
 
+#[cfg(test)]
+impl core::fmt::Debug for Class<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Class")
+            .field("authority", &crate::utils::Hex(&self.authority))
+            .field("is_permissioned", &self.is_permissioned)
+            .field("is_frozen", &self.is_frozen)
+            .field("transfer_fee_bps", &self.transfer_fee_bps)
+            .field("fee_destination", &crate::utils::Hex(&self.fee_destination))
+            .field("max_record_size", &self.max_record_size)
+            .field("max_records", &self.max_records)
+            .field("bump", &self.bump)
+            .field("name", &self.name)
+            .field("metadata_schema_uri", &self.metadata_schema_uri)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
 impl<'info> Class<'info> {
     pub const DISCRIMINATOR: u8 = 1;
     pub const MAX_CLASS_NAME_LEN: usize = 0xff;
-    pub const MINIMUM_CLASS_SIZE: usize =
-        size_of::<u8>() + size_of::<Pubkey>() + size_of::<bool>() * 2 + size_of::<u8>();
+    pub const MAX_TRANSFER_FEE_BPS: u16 = 10_000;
+    pub const MINIMUM_CLASS_SIZE: usize = size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<bool>() * 2
+        + size_of::<u16>()
+        + size_of::<Pubkey>()
+        + size_of::<u32>() * 2
+        + size_of::<u8>()
+        + size_of::<u8>() * 2;
 
     /// Check if the program id and discriminator are valid
     #[inline(always)]
@@ -67,7 +114,7 @@ impl<'info> Class<'info> {
             .key()
             .ne(&data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()])
         {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::NotClassAuthority.into());
         }
 
         Ok(())
@@ -87,6 +134,24 @@ impl<'info> Class<'info> {
         }
     }
 
+    /// Check if the key is the class authority, without requiring a signature
+    pub fn check_authority_key(
+        class: &AccountInfo,
+        authority: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        if authority.ne(&data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()]) {
+            return Err(SrsError::NotClassAuthority.into());
+        }
+
+        Ok(())
+    }
+
     pub fn check_permission(
         class: &AccountInfo,
         authority: Option<&AccountInfo>,
@@ -98,12 +163,43 @@ impl<'info> Class<'info> {
         unsafe { Self::check_discriminator_unchecked(&data)? }
 
         if data[IS_PERMISSIONED_OFFSET] == 1 {
-            let authority = authority.ok_or(ProgramError::InvalidAccountData)?;
+            let authority = authority.ok_or(SrsError::NotClassAuthority)?;
             unsafe { Self::check_authority_unchecked(&data, authority) }?;
         }
 
         if data[IS_FROZEN_OFFSET] == 1 {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::ClassFrozen.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the class accepts new records, without requiring the class
+    /// authority of permissioned classes
+    pub fn check_not_frozen(class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        if data[IS_FROZEN_OFFSET] == 1 {
+            return Err(SrsError::ClassFrozen.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the class is frozen
+    pub fn check_frozen(class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        if data[IS_FROZEN_OFFSET] != 1 {
+            return Err(SrsError::ClassNotFrozen.into());
         }
 
         Ok(())
@@ -127,6 +223,146 @@ impl<'info> Class<'info> {
         Ok(())
     }
 
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_is_permissioned_unchecked(
+        class: &'info AccountInfo,
+        is_permissioned: bool,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        // Check if the class is frozen
+        if data[IS_FROZEN_OFFSET] == 1 {
+            return Err(SrsError::ClassFrozen.into());
+        }
+
+        data[IS_PERMISSIONED_OFFSET] = is_permissioned as u8;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_record_size_unchecked(
+        data: &[u8],
+        record_size: usize,
+    ) -> Result<(), ProgramError> {
+        let max_record_size = u32::from_le_bytes(
+            data[MAX_RECORD_SIZE_OFFSET..MAX_RECORD_SIZE_OFFSET + size_of::<u32>()]
+                .try_into()
+                .unwrap(),
+        );
+
+        if max_record_size != 0 && record_size > max_record_size as usize {
+            return Err(SrsError::RecordTooLarge.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_record_count_unchecked(
+        data: &[u8],
+        class_stats_data: &[u8],
+    ) -> Result<(), ProgramError> {
+        let max_records = u32::from_le_bytes(
+            data[MAX_RECORDS_OFFSET..MAX_RECORDS_OFFSET + size_of::<u32>()]
+                .try_into()
+                .unwrap(),
+        );
+
+        if max_records != 0
+            && ClassStats::get_record_count_unchecked(class_stats_data) >= max_records as u64
+        {
+            return Err(SrsError::ClassCapacityExceeded.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_bump_unchecked(data: &[u8]) -> u8 {
+        data[BUMP_OFFSET]
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_transfer_fee_bps_unchecked(data: &[u8]) -> u16 {
+        u16::from_le_bytes(
+            data[TRANSFER_FEE_BPS_OFFSET..TRANSFER_FEE_BPS_OFFSET + size_of::<u16>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_fee_destination_unchecked(data: &[u8]) -> &[u8] {
+        &data[FEE_DESTINATION_OFFSET..FEE_DESTINATION_OFFSET + size_of::<Pubkey>()]
+    }
+
+    /// Returns the name and metadata of the class
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_name_and_metadata_unchecked(
+        data: &[u8],
+    ) -> Result<(&[u8], &[u8]), ProgramError> {
+        let mut variable_data = ByteReader::new_with_offset(data, NAME_LEN_OFFSET);
+        let name = variable_data.read_bytes_with_length()?;
+        // Skip the metadata schema uri
+        variable_data.read_bytes_with_length()?;
+        let metadata = variable_data.read_bytes(variable_data.remaining_bytes())?;
+
+        Ok((name, metadata))
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_limits_unchecked(
+        class: &'info AccountInfo,
+        max_record_size: u32,
+        max_records: u32,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        ByteWriter::write_with_offset(&mut data, MAX_RECORD_SIZE_OFFSET, max_record_size)?;
+        ByteWriter::write_with_offset(&mut data, MAX_RECORDS_OFFSET, max_records)?;
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_transfer_fee_unchecked(
+        class: &'info AccountInfo,
+        transfer_fee_bps: u16,
+        fee_destination: Pubkey,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        ByteWriter::write_with_offset(&mut data, TRANSFER_FEE_BPS_OFFSET, transfer_fee_bps)?;
+        ByteWriter::write_with_offset(&mut data, FEE_DESTINATION_OFFSET, fee_destination)?;
+
+        Ok(())
+    }
+
     /// # Safety
     ///
     /// This function does not perform owner checks
@@ -141,13 +377,13 @@ impl<'info> Class<'info> {
         Ok(())
     }
 
-
     /// # Safety
     ///
     /// This function does not perform owner checks
     pub unsafe fn update_metadata_unchecked(
         class: &'info AccountInfo,
         payer: &'info AccountInfo,
+        metadata_schema_uri: Option<&'info str>,
         metadata: &'info str,
     ) -> Result<(), ProgramError> {
         let name_len = {
@@ -155,9 +391,11 @@ impl<'info> Class<'info> {
             data_ref[NAME_LEN_OFFSET] as usize
         };
 
+        let metadata_schema_uri = metadata_schema_uri.unwrap_or_default();
+
         let offset = name_len + NAME_LEN_OFFSET + size_of::<u8>();
         let current_len = class.data_len();
-        let new_len = offset + metadata.len();
+        let new_len = offset + size_of::<u8>() + metadata_schema_uri.len() + metadata.len();
 
         if new_len != current_len {
             resize_account(class, payer, new_len, new_len < current_len)?;
@@ -166,15 +404,35 @@ impl<'info> Class<'info> {
         {
             let mut data_ref = class.try_borrow_mut_data()?;
 
-            let metadata_buffer = unsafe {
-                core::slice::from_raw_parts_mut(data_ref.as_mut_ptr().add(offset), metadata.len())
-            };
-            metadata_buffer.clone_from_slice(metadata.as_bytes());
+            let mut variable_data = ByteWriter::new_with_offset(&mut data_ref, offset);
+            variable_data.write_str_with_length(metadata_schema_uri)?;
+            variable_data.write_str(metadata)?;
         }
 
         Ok(())
     }
 
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn delete_class_unchecked(
+        class: &'info AccountInfo,
+        payer: &'info AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Check if the class is frozen
+        if class.try_borrow_data()?[IS_FROZEN_OFFSET] == 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Resize to 0 bytes
+        class.realloc(0, true)?;
+        // Transfer ALL lamports back to payer to fully close the account
+        let lamports = class.lamports();
+        *payer.try_borrow_mut_lamports()? = payer.lamports().saturating_add(lamports);
+        *class.try_borrow_mut_lamports()? = 0;
+        Ok(())
+    }
+
     /// # Safety
     ///
     /// This function does not perform owner checks
@@ -182,7 +440,12 @@ impl<'info> Class<'info> {
         &self,
         account_info: &'info AccountInfo,
     ) -> Result<(), ProgramError> {
-        let required_space = Self::MINIMUM_CLASS_SIZE + self.name.len() + self.metadata.len();
+        let metadata_schema_uri = self.metadata_schema_uri.unwrap_or_default();
+
+        let required_space = Self::MINIMUM_CLASS_SIZE
+            + self.name.len()
+            + metadata_schema_uri.len()
+            + self.metadata.len();
 
         if required_space > account_info.data_len() {
             return Err(ProgramError::InvalidAccountData);
@@ -198,9 +461,15 @@ impl<'info> Class<'info> {
         ByteWriter::write_with_offset(&mut data, AUTHORITY_OFFSET, self.authority)?;
         ByteWriter::write_with_offset(&mut data, IS_PERMISSIONED_OFFSET, self.is_permissioned)?;
         ByteWriter::write_with_offset(&mut data, IS_FROZEN_OFFSET, self.is_frozen)?;
+        ByteWriter::write_with_offset(&mut data, TRANSFER_FEE_BPS_OFFSET, self.transfer_fee_bps)?;
+        ByteWriter::write_with_offset(&mut data, FEE_DESTINATION_OFFSET, self.fee_destination)?;
+        ByteWriter::write_with_offset(&mut data, MAX_RECORD_SIZE_OFFSET, self.max_record_size)?;
+        ByteWriter::write_with_offset(&mut data, MAX_RECORDS_OFFSET, self.max_records)?;
+        ByteWriter::write_with_offset(&mut data, BUMP_OFFSET, self.bump)?;
 
         let mut variable_data = ByteWriter::new_with_offset(&mut data, NAME_LEN_OFFSET);
         variable_data.write_str_with_length(self.name)?;
+        variable_data.write_str_with_length(metadata_schema_uri)?;
 
         if !self.metadata.is_empty() {
             variable_data.write_str(self.metadata)?;
@@ -0,0 +1,326 @@
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::utils::{resize_account, ByteWriter};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_COUNT_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const COUNT_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const CLASSES_OFFSET: usize = COUNT_OFFSET + size_of::<u32>();
+
+/// Index of the classes created by an authority, at the PDA `[b"registry", authority]`.
+///
+/// The class keys are stored in pages of `ClassRegistryPage::CLASSES_PER_PAGE` classes, the
+/// registry only counts the classes appended so far, which gives the page of the next class.
+#[repr(C)]
+pub struct ClassRegistry {
+    /// Number of classes appended to the registry, deleted classes included
+    pub class_count: u32,
+}
+
+impl ClassRegistry {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 8;
+
+    /// Size of a class registry account
+    pub const CLASS_REGISTRY_SIZE: usize = size_of::<u8>() + size_of::<u32>();
+
+    /// Check that the class registry is the PDA of the authority and return its bump
+    #[inline(always)]
+    pub fn check_address(
+        class_registry: &AccountInfo,
+        authority: &Pubkey,
+    ) -> Result<[u8; 1], ProgramError> {
+        let (address, bump) =
+            try_find_program_address(&[b"registry", authority.as_ref()], &crate::ID)
+                .ok_or(ProgramError::InvalidArgument)?;
+
+        if address.ne(class_registry.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok([bump])
+    }
+
+    /// Check if the class registry has already been created
+    #[inline(always)]
+    pub fn is_initialized(class_registry: &AccountInfo) -> Result<bool, ProgramError> {
+        if unsafe { class_registry.owner().ne(&crate::ID) } {
+            return Ok(false);
+        }
+
+        let data = class_registry.try_borrow_data()?;
+        if data.len() != Self::CLASS_REGISTRY_SIZE
+            || data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(true)
+    }
+
+    /// Append a class created by the authority to its registry, creating the
+    /// registry and the page of the class if needed
+    pub fn append_class(
+        class_registry: &AccountInfo,
+        class_registry_page: &AccountInfo,
+        authority: &Pubkey,
+        class: &Pubkey,
+        payer: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let bump = Self::check_address(class_registry, authority)?;
+
+        if !Self::is_initialized(class_registry)? {
+            let seeds = [
+                Seed::from(b"registry"),
+                Seed::from(authority),
+                Seed::from(&bump),
+            ];
+
+            create_account(class_registry, payer, Self::CLASS_REGISTRY_SIZE, &seeds)?;
+
+            let mut data = class_registry.try_borrow_mut_data()?;
+            ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        }
+
+        let class_count =
+            unsafe { Self::get_class_count_unchecked(&class_registry.try_borrow_data()?) };
+
+        // Classes fill the pages in order
+        let page = class_count / ClassRegistryPage::CLASSES_PER_PAGE;
+        ClassRegistryPage::initialize_if_needed(class_registry_page, authority, page, payer)?;
+
+        // Safety: The class registry page has just been validated
+        unsafe { ClassRegistryPage::push_class_unchecked(class_registry_page, class, payer)? };
+
+        let class_count = class_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        let mut data = class_registry.try_borrow_mut_data()?;
+        ByteWriter::write_with_offset(&mut data, CLASS_COUNT_OFFSET, class_count)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_class_count_unchecked(data: &[u8]) -> u32 {
+        u32::from_le_bytes(
+            data[CLASS_COUNT_OFFSET..CLASS_COUNT_OFFSET + size_of::<u32>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+/// Page of a class registry, at the PDA `[b"registry", authority, page]` with `page` as
+/// little endian `u32`.
+///
+/// Classes are only ever appended, a deleted class is left in its page as the default pubkey.
+#[repr(C)]
+pub struct ClassRegistryPage<'info> {
+    /// Keys of the classes of the page
+    pub classes: &'info [Pubkey],
+}
+
+impl ClassRegistryPage<'_> {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 9;
+
+    /// Number of classes of a full page, small enough for a full page to
+    /// stay below the resizing limit
+    pub const CLASSES_PER_PAGE: u32 = 256;
+
+    /// Size of an empty class registry page
+    pub const MINIMUM_PAGE_SIZE: usize = size_of::<u8>() + size_of::<u32>();
+
+    /// Check that the page is the page PDA of the authority and return its bump
+    #[inline(always)]
+    pub fn check_address(
+        class_registry_page: &AccountInfo,
+        authority: &Pubkey,
+        page: u32,
+    ) -> Result<[u8; 1], ProgramError> {
+        let (address, bump) = try_find_program_address(
+            &[b"registry", authority.as_ref(), &page.to_le_bytes()],
+            &crate::ID,
+        )
+        .ok_or(ProgramError::InvalidArgument)?;
+
+        if address.ne(class_registry_page.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok([bump])
+    }
+
+    /// Check if the page has already been created
+    #[inline(always)]
+    pub fn is_initialized(class_registry_page: &AccountInfo) -> Result<bool, ProgramError> {
+        if unsafe { class_registry_page.owner().ne(&crate::ID) } {
+            return Ok(false);
+        }
+
+        let data = class_registry_page.try_borrow_data()?;
+        if data.len() < Self::MINIMUM_PAGE_SIZE
+            || data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(true)
+    }
+
+    /// Create the page the first time a class is appended to it
+    pub fn initialize_if_needed(
+        class_registry_page: &AccountInfo,
+        authority: &Pubkey,
+        page: u32,
+        payer: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let bump = Self::check_address(class_registry_page, authority, page)?;
+
+        if Self::is_initialized(class_registry_page)? {
+            return Ok(());
+        }
+
+        let page = page.to_le_bytes();
+        let seeds = [
+            Seed::from(b"registry"),
+            Seed::from(authority),
+            Seed::from(&page),
+            Seed::from(&bump),
+        ];
+
+        create_account(class_registry_page, payer, Self::MINIMUM_PAGE_SIZE, &seeds)?;
+
+        let mut data = class_registry_page.try_borrow_mut_data()?;
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+
+        Ok(())
+    }
+
+    /// Remove a class that is about to be deleted from its page
+    pub fn remove_class(
+        class_registry_page: &AccountInfo,
+        class: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        // Pages are only created by the program at their PDA, so the owner
+        // and discriminator checks are enough
+        if !Self::is_initialized(class_registry_page)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut data = class_registry_page.try_borrow_mut_data()?;
+
+        let offset = data[CLASSES_OFFSET..]
+            .chunks_exact(size_of::<Pubkey>())
+            .position(|key| key == class)
+            .map(|index| CLASSES_OFFSET + index * size_of::<Pubkey>())
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        data[offset..offset + size_of::<Pubkey>()].fill(0);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_count_unchecked(data: &[u8]) -> u32 {
+        u32::from_le_bytes(
+            data[COUNT_OFFSET..COUNT_OFFSET + size_of::<u32>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn push_class_unchecked(
+        class_registry_page: &AccountInfo,
+        class: &Pubkey,
+        payer: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let count = Self::get_count_unchecked(&class_registry_page.try_borrow_data()?);
+        if count >= Self::CLASSES_PER_PAGE {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let offset = CLASSES_OFFSET + count as usize * size_of::<Pubkey>();
+        resize_account(
+            class_registry_page,
+            payer,
+            offset + size_of::<Pubkey>(),
+            false,
+        )?;
+
+        let mut data = class_registry_page.try_borrow_mut_data()?;
+        ByteWriter::write_with_offset(&mut data, offset, *class)?;
+        ByteWriter::write_with_offset(&mut data, COUNT_OFFSET, count + 1)?;
+
+        Ok(())
+    }
+}
+
+/// Create a registry account owned by the program at the PDA of `seeds`
+fn create_account(
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    space: usize,
+    seeds: &[Seed],
+) -> Result<(), ProgramError> {
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+
+    let signers = [Signer::from(seeds)];
+
+    if account.lamports() > 0 {
+        Allocate {
+            account,
+            space: space as u64,
+        }
+        .invoke_signed(&signers)?;
+
+        Assign {
+            account,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signers)?;
+
+        if lamports > 0 {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports,
+            }
+            .invoke()?;
+        }
+    } else {
+        CreateAccount {
+            from: payer,
+            to: account,
+            lamports,
+            space: space as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signers)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+use crate::{
+    state::{Record, CLASS_OFFSET},
+    utils::ByteWriter,
+};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const RECORD_COUNT_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const TOTAL_BYTES_OFFSET: usize = RECORD_COUNT_OFFSET + size_of::<u64>();
+
+/// Live record count and storage of a class, derived from `[b"stats", class]`
+///
+/// Every record is counted from its creation, records and classes created before the class
+/// stats were introduced are not supported. This is a breaking migration: existing records must
+/// be recreated under a fresh deployment.
+#[repr(C)]
+pub struct ClassStats {
+    /// Number of live records in the class
+    pub record_count: u64,
+    /// Sum of the data length of the live records in the class
+    pub total_bytes: u64,
+}
+
+impl ClassStats {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 4;
+
+    /// Size of a class stats account
+    pub const CLASS_STATS_SIZE: usize = size_of::<u8>() + size_of::<u64>() * 2;
+
+    /// Check that the class stats account is the PDA of the class and return its bump
+    #[inline(always)]
+    pub fn check_address(
+        class_stats: &AccountInfo,
+        class: &Pubkey,
+    ) -> Result<[u8; 1], ProgramError> {
+        let (address, bump) = try_find_program_address(&[b"stats", class.as_ref()], &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        if address.ne(class_stats.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok([bump])
+    }
+
+    /// Check if the class stats account has already been created
+    #[inline(always)]
+    pub fn is_initialized(class_stats: &AccountInfo) -> Result<bool, ProgramError> {
+        if unsafe { class_stats.owner().ne(&crate::ID) } {
+            return Ok(false);
+        }
+
+        let data = class_stats.try_borrow_data()?;
+        if data.len() != Self::CLASS_STATS_SIZE
+            || data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(true)
+    }
+
+    /// Check that the class does not hold any live record
+    pub fn check_no_records(class_stats: &AccountInfo, class: &Pubkey) -> Result<(), ProgramError> {
+        Self::check_address(class_stats, class)?;
+
+        if !Self::is_initialized(class_stats)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let data = class_stats.try_borrow_data()?;
+
+        // Safety: The class stats account has already been validated
+        unsafe {
+            if Self::get_record_count_unchecked(&data) != 0 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create the class stats account if it does not exist yet, either along with the class or
+    /// on its first record.
+    pub fn initialize_if_needed(
+        class_stats: &AccountInfo,
+        class: &Pubkey,
+        payer: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let bump = Self::check_address(class_stats, class)?;
+
+        if Self::is_initialized(class_stats)? {
+            return Ok(());
+        }
+
+        let space = Self::CLASS_STATS_SIZE;
+        let lamports = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(class_stats.lamports());
+
+        let seeds = [Seed::from(b"stats"), Seed::from(class), Seed::from(&bump)];
+
+        let signers = [Signer::from(&seeds)];
+
+        if class_stats.lamports() > 0 {
+            Allocate {
+                account: class_stats,
+                space: space as u64,
+            }
+            .invoke_signed(&signers)?;
+
+            Assign {
+                account: class_stats,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+
+            if lamports > 0 {
+                Transfer {
+                    from: payer,
+                    to: class_stats,
+                    lamports,
+                }
+                .invoke()?;
+            }
+        } else {
+            CreateAccount {
+                from: payer,
+                to: class_stats,
+                lamports,
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        let mut data = class_stats.try_borrow_mut_data()?;
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+
+        Ok(())
+    }
+
+    /// Check that the class stats account is the initialized stats account of the class of the
+    /// record. Every record is counted when it is created, so its class stats always exist.
+    fn check_record_class(class_stats: &AccountInfo, record_data: &[u8]) -> ProgramResult {
+        let class: &Pubkey = record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]
+            .try_into()
+            .unwrap();
+        Self::check_address(class_stats, class)?;
+
+        if !Self::is_initialized(class_stats)? {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a record that is about to be deleted from the stats of its class
+    pub fn remove_record(
+        class_stats: &AccountInfo,
+        record: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let data = record.try_borrow_data()?;
+
+        Self::check_record_class(class_stats, &data)?;
+
+        // Safety: The record and the class stats accounts have already been validated
+        unsafe {
+            let data_len = Record::get_data_len_unchecked(&data);
+            Self::remove_record_unchecked(class_stats, data_len)
+        }
+    }
+
+    /// Apply the change of the data length of a record, from `previous_data_len` to its
+    /// current data length, to the stats of its class
+    pub fn resize_record(
+        class_stats: &AccountInfo,
+        record: &AccountInfo,
+        previous_data_len: usize,
+    ) -> Result<(), ProgramError> {
+        let data = record.try_borrow_data()?;
+
+        Self::check_record_class(class_stats, &data)?;
+
+        // Safety: The record and the class stats accounts have already been validated
+        unsafe {
+            let data_len = Record::get_data_len_unchecked(&data);
+            Self::resize_record_unchecked(class_stats, previous_data_len, data_len)
+        }
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_record_count_unchecked(data: &[u8]) -> u64 {
+        u64::from_le_bytes(
+            data[RECORD_COUNT_OFFSET..RECORD_COUNT_OFFSET + size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_total_bytes_unchecked(data: &[u8]) -> u64 {
+        u64::from_le_bytes(
+            data[TOTAL_BYTES_OFFSET..TOTAL_BYTES_OFFSET + size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn add_record_unchecked(
+        class_stats: &AccountInfo,
+        data_len: usize,
+    ) -> Result<(), ProgramError> {
+        let mut data = class_stats.try_borrow_mut_data()?;
+
+        let record_count = Self::get_record_count_unchecked(&data).saturating_add(1);
+        let total_bytes = Self::get_total_bytes_unchecked(&data).saturating_add(data_len as u64);
+
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, record_count)?;
+        ByteWriter::write_with_offset(&mut data, TOTAL_BYTES_OFFSET, total_bytes)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn remove_record_unchecked(
+        class_stats: &AccountInfo,
+        data_len: usize,
+    ) -> Result<(), ProgramError> {
+        let mut data = class_stats.try_borrow_mut_data()?;
+
+        let record_count = Self::get_record_count_unchecked(&data)
+            .checked_sub(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let total_bytes = Self::get_total_bytes_unchecked(&data)
+            .checked_sub(data_len as u64)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, record_count)?;
+        ByteWriter::write_with_offset(&mut data, TOTAL_BYTES_OFFSET, total_bytes)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn resize_record_unchecked(
+        class_stats: &AccountInfo,
+        previous_data_len: usize,
+        data_len: usize,
+    ) -> Result<(), ProgramError> {
+        let mut data = class_stats.try_borrow_mut_data()?;
+
+        let total_bytes = Self::get_total_bytes_unchecked(&data)
+            .checked_sub(previous_data_len as u64)
+            .and_then(|total_bytes| total_bytes.checked_add(data_len as u64))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        ByteWriter::write_with_offset(&mut data, TOTAL_BYTES_OFFSET, total_bytes)?;
+
+        Ok(())
+    }
+}
@@ -3,3 +3,21 @@ pub use class::*;
 
 pub mod record;
 pub use record::*;
+
+pub mod record_lease;
+pub use record_lease::*;
+
+pub mod class_stats;
+pub use class_stats::*;
+
+pub mod class_registry;
+pub use class_registry::*;
+
+pub mod record_snapshot;
+pub use record_snapshot::*;
+
+pub mod royalty_config;
+pub use royalty_config::*;
+
+pub mod record_access_list;
+pub use record_access_list::*;
@@ -1,21 +1,39 @@
 use crate::{
-    token2022::{CloseAccount, Mint, Token}, utils::{resize_account, ByteWriter}
+    error::SrsError, token2022::{CloseAccount, Mint, Token}, utils::{resize_account, ByteWriter}
 };
-use core::{mem::size_of, str};
+use core::{mem::size_of, ops::Range, str};
 use pinocchio::{
-    account_info::{AccountInfo, Ref, RefMut}, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{try_find_program_address, Pubkey}
+    account_info::{AccountInfo, Ref, RefMut}, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{create_program_address, try_find_program_address, Pubkey}, sysvars::{clock::Clock, Sysvar}
 };
 
 use super::{Class, IS_PERMISSIONED_OFFSET};
 
+/// Number of mutations kept in the record audit log
+pub const MAX_RECENT_MUTATIONS: usize = 5;
+
+/// Maximum size of a record account
+///
+/// Instructions that would create or grow a record past it fail with `InvalidInstructionData`.
+/// This is independent of how much a single instruction can grow an account, which
+/// `resize_account` bounds by `MAX_PERMITTED_DATA_INCREASE`.
+pub const MAX_RECORD_SIZE: usize = 1024 * 1024;
+
 /// Offsets
 const DISCRIMINATOR_OFFSET: usize = 0;
 pub const CLASS_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
-const OWNER_TYPE_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
+pub const OWNER_TYPE_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
 pub const OWNER_OFFSET: usize = OWNER_TYPE_OFFSET + size_of::<u8>();
 pub const IS_FROZEN_OFFSET: usize = OWNER_OFFSET + size_of::<Pubkey>();
-const EXPIRY_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
-const SEED_LEN_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+pub const NON_TRANSFERABLE_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const EXPIRY_OFFSET: usize = NON_TRANSFERABLE_OFFSET + size_of::<bool>();
+const CREATED_AT_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const UPDATED_AT_OFFSET: usize = CREATED_AT_OFFSET + size_of::<i64>();
+const REVISION_OFFSET: usize = UPDATED_AT_OFFSET + size_of::<i64>();
+const MUTATION_CURSOR_OFFSET: usize = REVISION_OFFSET + size_of::<u32>();
+const RECENT_MUTATIONS_OFFSET: usize = MUTATION_CURSOR_OFFSET + size_of::<u8>();
+const BUMP_OFFSET: usize = RECENT_MUTATIONS_OFFSET + RecordMutation::SIZE * MAX_RECENT_MUTATIONS;
+const MINT_BUMP_OFFSET: usize = BUMP_OFFSET + size_of::<u8>();
+const SEED_LEN_OFFSET: usize = MINT_BUMP_OFFSET + size_of::<u8>();
 pub const SEED_OFFSET: usize = SEED_LEN_OFFSET + size_of::<u8>();
 
 #[repr(C)]
@@ -28,8 +46,26 @@ pub struct Record<'info> {
     pub owner: Pubkey,
     /// Whether the record is frozen
     pub is_frozen: bool,
-    /// Optional expiration timestamp, if not set, the expiry is [0; 8]
+    /// Whether the record can never change owner (soulbound)
+    pub non_transferable: bool,
+    /// Optional expiration timestamp, if not set, the expiry is [0; 8]. Expiries
+    /// must be in the future when set, so 0 can never be an actual expiry
     pub expiry: i64,
+    /// Unix timestamp of the record creation
+    pub created_at: i64,
+    /// Unix timestamp of the last update of the record
+    pub updated_at: i64,
+    /// Number of data updates applied to the record, used for optimistic locking
+    pub revision: u32,
+    /// Position in `recent_mutations` where the next mutation will be written
+    pub mutation_cursor: u8,
+    /// Ring buffer of the last mutations applied to this record
+    pub recent_mutations: [RecordMutation; MAX_RECENT_MUTATIONS],
+    /// The bump of the record PDA, cached to avoid deriving it again
+    pub bump: u8,
+    /// The bump of the mint PDA of the record, cached when the record is tokenized and 0
+    /// otherwise
+    pub mint_bump: u8,
     /// The record name/key
     pub seed: &'info [u8],
     /// The record's data content
@@ -37,12 +73,80 @@ pub struct Record<'info> {
 }
 
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Default)]
+pub struct RecordMutation {
+    /// The discriminator of the instruction that mutated the record, 0 if the slot is empty
+    pub instruction_type: u8,
+    /// Unix timestamp of the mutation
+    pub timestamp: i64,
+    /// The signer that performed the mutation
+    pub actor: Pubkey,
+}
+
+impl RecordMutation {
+    /// Size of a single entry of the record audit log
+    pub const SIZE: usize = size_of::<u8>() + size_of::<i64>() + size_of::<Pubkey>();
+}
+
+#[cfg(test)]
+impl core::fmt::Debug for RecordMutation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RecordMutation")
+            .field("instruction_type", &self.instruction_type)
+            .field("timestamp", &self.timestamp)
+            .field("actor", &crate::utils::Hex(&self.actor))
+            .finish()
+    }
+}
+
+#[repr(u8)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
 pub enum OwnerType {
     /// The owner is a pubkey
-    Pubkey,
+    Pubkey = 0,
     /// The owner is a token
-    Token,
+    Token = 1,
+}
+
+impl From<OwnerType> for u8 {
+    fn from(owner_type: OwnerType) -> Self {
+        owner_type as u8
+    }
+}
+
+impl TryFrom<u8> for OwnerType {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Pubkey),
+            1 => Ok(Self::Token),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+#[cfg(test)]
+impl core::fmt::Debug for Record<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Record")
+            .field("class", &crate::utils::Hex(&self.class))
+            .field("owner_type", &u8::from(self.owner_type))
+            .field("owner", &crate::utils::Hex(&self.owner))
+            .field("is_frozen", &self.is_frozen)
+            .field("non_transferable", &self.non_transferable)
+            .field("expiry", &self.expiry)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .field("revision", &self.revision)
+            .field("mutation_cursor", &self.mutation_cursor)
+            .field("recent_mutations", &self.recent_mutations)
+            .field("bump", &self.bump)
+            .field("seed", &crate::utils::Hex(self.seed))
+            .field("data", &self.data)
+            .finish()
+    }
 }
 
 impl<'info> Record<'info> {
@@ -55,21 +159,31 @@ impl<'info> Record<'info> {
         + size_of::<u8>()
         + size_of::<Pubkey>()
         + size_of::<bool>()
-        + size_of::<i64>()
+        + size_of::<bool>()
+        + size_of::<i64>() * 3
+        + size_of::<u32>()
+        + size_of::<u8>()
+        + RecordMutation::SIZE * MAX_RECENT_MUTATIONS
+        + size_of::<u8>()
+        + size_of::<u8>()
         + size_of::<u8>();
 
-    /// Check if the program id and discriminator are valid
+    /// Check if the program id is valid
     #[inline(always)]
-    pub fn check_program_id_and_discriminator(
-        account_info: &AccountInfo,
-    ) -> Result<(), ProgramError> {
+    pub fn check_program_id(account_info: &AccountInfo) -> Result<(), ProgramError> {
         // Check Program ID
         if unsafe { account_info.owner().ne(&crate::ID) } {
             return Err(ProgramError::IncorrectProgramId);
         }
 
-        // Check discriminator
-        let data = account_info.try_borrow_data()?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
         if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
             return Err(ProgramError::InvalidAccountData);
         }
@@ -77,6 +191,18 @@ impl<'info> Record<'info> {
         Ok(())
     }
 
+    /// Check if the program id and discriminator are valid
+    #[inline(always)]
+    pub fn check_program_id_and_discriminator(
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id(account_info)?;
+
+        // Check discriminator
+        let data = account_info.try_borrow_data()?;
+        unsafe { Self::check_discriminator_unchecked(&data) }
+    }
+
     #[inline(always)]
     pub fn validate_delegate(
         class: &AccountInfo,
@@ -87,7 +213,7 @@ impl<'info> Record<'info> {
         let class_data = class.try_borrow_data()?;
 
         if class_data[IS_PERMISSIONED_OFFSET].ne(&1u8) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::InvalidDelegate.into());
         }
 
         unsafe {
@@ -109,7 +235,7 @@ impl<'info> Record<'info> {
         let data = record.try_borrow_data()?;
 
         // Check if the Mint has been burned without passing through the BurnTokenizedRecord instruction
-        if data[OWNER_TYPE_OFFSET].eq(&(OwnerType::Token as u8)) {
+        if data[OWNER_TYPE_OFFSET].eq(&u8::from(OwnerType::Token)) {
             let mint = mint.ok_or(ProgramError::InvalidAccountData)?;
 
             if mint.key().ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]) {
@@ -117,15 +243,11 @@ impl<'info> Record<'info> {
             }
 
             if Mint::get_supply(mint)? != 0 {
-                return Err(ProgramError::InvalidAccountData);
+                return Err(SrsError::AlreadyTokenized.into());
             }
 
             // Close the Mint and get back the rent
-            let bump = [
-            try_find_program_address(&[b"mint", record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-            ];
+            let bump = [data[MINT_BUMP_OFFSET]];
 
             let seeds = [
                 Seed::from(b"mint"),
@@ -160,30 +282,34 @@ impl<'info> Record<'info> {
         }
 
         // Validate the delegate
-        let class = class.ok_or(ProgramError::InvalidAccountData)?;
+        let class = class.ok_or(SrsError::NotRecordOwner)?;
         if class.key().ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::InvalidDelegate.into());
         }
 
         Self::validate_delegate(class, authority)
     }
 
-    #[inline(always)]
+    #[inline]
     pub fn check_owner_or_delegate(
         record: &AccountInfo,
         class: Option<&AccountInfo>,
         authority: &AccountInfo,
     ) -> Result<(), ProgramError> {
-        // Check the program id and the discriminator
-        Self::check_program_id_and_discriminator(record)?;
+        // Check the program id
+        Self::check_program_id(record)?;
+
+        // Borrow the record data once for all the remaining checks
+        let data = record.try_borrow_data()?;
+
+        // Check the discriminator
+        unsafe { Self::check_discriminator_unchecked(&data)? };
 
         // Check if the authority is signer
         if !authority.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        let data = record.try_borrow_data()?;
-
         // Check if the authority is the owner
         if authority
             .key()
@@ -193,20 +319,91 @@ impl<'info> Record<'info> {
         }
 
         // Check if the owner type is pubkey
-        if data[OWNER_TYPE_OFFSET].ne(&(OwnerType::Pubkey as u8)) {
-            return Err(ProgramError::InvalidAccountData);
+        if data[OWNER_TYPE_OFFSET].ne(&u8::from(OwnerType::Pubkey)) {
+            return Err(SrsError::NotRecordOwner.into());
         }
 
         // Validate the delegate
-        let class = class.ok_or(ProgramError::MissingRequiredSignature)?;
+        let class = class.ok_or(SrsError::NotRecordOwner)?;
         if class.key().ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::InvalidDelegate.into());
         }
 
         Self::validate_delegate(class, authority)
     }
 
+    /// Check that `record` is the record PDA of `class` for `seed`
+    ///
+    /// The bump cached in an existing record is used, the bump of a record that does not
+    /// exist yet is searched for
+    #[inline(always)]
+    pub fn check_address(
+        record: &AccountInfo,
+        class: &Pubkey,
+        seed: &[u8],
+    ) -> Result<(), ProgramError> {
+        let address = if unsafe { record.owner().eq(&crate::ID) }
+            && record.data_len() > 0
+            && record.try_borrow_data()?[DISCRIMINATOR_OFFSET].eq(&Self::DISCRIMINATOR)
+        {
+            let bump = [unsafe { Self::get_bump_unchecked(&record.try_borrow_data()?) }];
+
+            create_program_address(&[b"record", class, seed, &bump], &crate::ID)
+                .map_err(|_| ProgramError::InvalidAccountData)?
+        } else {
+            try_find_program_address(&[b"record", class, seed], &crate::ID)
+                .ok_or(ProgramError::InvalidArgument)?
+                .0
+        };
+
+        if address.ne(record.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Check that `mint` is the mint PDA of the record, returning its bump
+    ///
+    /// The bump cached in the record when it was tokenized is used, so the address is
+    /// checked without searching for the bump again
+    #[inline(always)]
+    pub fn check_mint_address(
+        record: &AccountInfo,
+        mint: &AccountInfo,
+    ) -> Result<[u8; 1], ProgramError> {
+        let bump = [record.try_borrow_data()?[MINT_BUMP_OFFSET]];
+
+        let address = create_program_address(&[b"mint", record.key(), &bump], &crate::ID)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        if address.ne(mint.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(bump)
+    }
+
+    /// Find the mint PDA of a record that is not tokenized yet, returning its bump
+    ///
+    /// The bump is then cached in the record with `update_mint_bump_unchecked`
     #[inline(always)]
+    pub fn find_mint_address(
+        record: &AccountInfo,
+        mint: &AccountInfo,
+    ) -> Result<[u8; 1], ProgramError> {
+        let (address, bump) = try_find_program_address(&[b"mint", record.key()], &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        if address.ne(mint.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok([bump])
+    }
+
+    /// Kept out of line, so that the tokenized instructions share a single copy
+    #[inline(never)]
     pub fn check_owner_or_delegate_tokenized(
         record: &AccountInfo,
         class: Option<&AccountInfo>,
@@ -239,7 +436,7 @@ impl<'info> Record<'info> {
             .key()
             .ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
         {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::NotTokenized.into());
         }
 
         // Check if the token account is owned by the token program
@@ -261,14 +458,94 @@ impl<'info> Record<'info> {
         }
 
         // Validate the delegate
-        let class = class.ok_or(ProgramError::InvalidAccountData)?;
+        let class = class.ok_or(SrsError::NotRecordOwner)?;
         if class.key().ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::InvalidDelegate.into());
         }
 
         Self::validate_delegate(class, authority)
     }
 
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn is_expired_unchecked(data: &[u8]) -> Result<bool, ProgramError> {
+        let expiry = Self::get_expiry_unchecked(data);
+
+        // An expiry of 0 means the record never expires
+        Ok(expiry != 0 && expiry <= Clock::get()?.unix_timestamp)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_expiry_unchecked(data: &[u8]) -> i64 {
+        i64::from_le_bytes(
+            data[EXPIRY_OFFSET..EXPIRY_OFFSET + size_of::<i64>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_created_at_unchecked(data: &[u8]) -> i64 {
+        i64::from_le_bytes(
+            data[CREATED_AT_OFFSET..CREATED_AT_OFFSET + size_of::<i64>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_bump_unchecked(data: &[u8]) -> u8 {
+        data[BUMP_OFFSET]
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_mint_bump_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+        mint_bump: u8,
+    ) -> Result<(), ProgramError> {
+        data[MINT_BUMP_OFFSET] = mint_bump;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_not_expired_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if Self::is_expired_unchecked(data)? {
+            return Err(SrsError::RecordExpired.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_transferable_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if data[NON_TRANSFERABLE_OFFSET] == 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
     #[inline(always)]
     /// # Safety
     ///
@@ -278,12 +555,12 @@ impl<'info> Record<'info> {
         owner_type: OwnerType,
     ) -> Result<(), ProgramError> {
         // Check if the owner_type is the same
-        if data[OWNER_TYPE_OFFSET].eq(&(owner_type as u8)) {
+        if data[OWNER_TYPE_OFFSET].eq(&u8::from(owner_type)) {
             return Ok(());
         }
 
         // Update the owner_type
-        data[OWNER_TYPE_OFFSET] = owner_type as u8;
+        data[OWNER_TYPE_OFFSET] = owner_type.into();
 
         Ok(())
     }
@@ -317,7 +594,7 @@ impl<'info> Record<'info> {
     ) -> Result<(), ProgramError> {
         // Check if the record is frozen
         if data[IS_FROZEN_OFFSET].eq(&1u8) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::RecordFrozen.into());
         }
 
         // Check if the new_owner is the same
@@ -341,7 +618,7 @@ impl<'info> Record<'info> {
     ) -> Result<(), ProgramError> {
         // Check if the record is frozen
         if data[IS_FROZEN_OFFSET].eq(&1u8) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(SrsError::RecordFrozen.into());
         }
 
         // Update the expiry
@@ -350,33 +627,155 @@ impl<'info> Record<'info> {
         Ok(())
     }
 
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_updated_at_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+    ) -> Result<(), ProgramError> {
+        let now = Clock::get()?.unix_timestamp;
+
+        data[UPDATED_AT_OFFSET..UPDATED_AT_OFFSET + size_of::<i64>()].clone_from_slice(&now.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_revision_unchecked(data: &[u8]) -> u32 {
+        u32::from_le_bytes(
+            data[REVISION_OFFSET..REVISION_OFFSET + size_of::<u32>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
     #[inline(always)]
     /// # Safety
     ///
     /// This function does not perform owner checks
+    pub unsafe fn check_revision_unchecked(
+        data: &[u8],
+        expected_revision: u32,
+    ) -> Result<(), ProgramError> {
+        // An expected revision of 0 skips the optimistic locking check
+        if expected_revision != 0 && Self::get_revision_unchecked(data) != expected_revision {
+            return Err(SrsError::StaleRevision.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn increment_revision_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+    ) -> Result<(), ProgramError> {
+        let revision = Self::get_revision_unchecked(data).wrapping_add(1);
+
+        ByteWriter::write_with_offset(data, REVISION_OFFSET, revision)
+    }
+
+    #[inline]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn record_mutation_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+        instruction_type: u8,
+        actor: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        let cursor = data[MUTATION_CURSOR_OFFSET] as usize % MAX_RECENT_MUTATIONS;
+
+        let mutation = RecordMutation {
+            instruction_type,
+            timestamp: Clock::get()?.unix_timestamp,
+            actor: *actor,
+        };
+
+        let offset = RECENT_MUTATIONS_OFFSET + cursor * RecordMutation::SIZE;
+        let mut entry = ByteWriter::new_with_offset(data, offset);
+        entry.write(mutation.instruction_type)?;
+        entry.write(mutation.timestamp)?;
+        entry.write(mutation.actor)?;
+
+        // Advance the cursor to the next slot of the ring buffer
+        data[MUTATION_CURSOR_OFFSET] = ((cursor + 1) % MAX_RECENT_MUTATIONS) as u8;
+
+        Ok(())
+    }
+
+    /// Get the last mutations applied to the record, from the oldest to the most recent
+    pub fn get_recent_mutations(
+        record: &AccountInfo,
+    ) -> Result<[RecordMutation; MAX_RECENT_MUTATIONS], ProgramError> {
+        Self::check_program_id_and_discriminator(record)?;
+
+        let data = record.try_borrow_data()?;
+        let cursor = data[MUTATION_CURSOR_OFFSET] as usize % MAX_RECENT_MUTATIONS;
+
+        let mut mutations = [RecordMutation::default(); MAX_RECENT_MUTATIONS];
+
+        for (i, mutation) in mutations.iter_mut().enumerate() {
+            let offset = RECENT_MUTATIONS_OFFSET
+                + ((cursor + i) % MAX_RECENT_MUTATIONS) * RecordMutation::SIZE;
+
+            mutation.instruction_type = data[offset];
+            mutation.timestamp = i64::from_le_bytes(
+                data[offset + size_of::<u8>()..offset + size_of::<u8>() + size_of::<i64>()]
+                    .try_into()
+                    .unwrap(),
+            );
+            mutation.actor = data[offset + size_of::<u8>() + size_of::<i64>()..offset + RecordMutation::SIZE]
+                .try_into()
+                .unwrap();
+        }
+
+        Ok(mutations)
+    }
+
+    #[inline]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    ///
+    /// Like `initialize_unchecked`, relies on `data` being a `&str` to keep the
+    /// record data valid UTF-8
     pub unsafe fn update_data_unchecked(
         record: &'info AccountInfo,
         payer: &'info AccountInfo,
         data: &'info str,
     ) -> Result<(), ProgramError> {
-        let seed_len = {
+        // Extract everything we need from the record and release the borrow
+        // before resizing, so that no borrow is held across the reallocation
+        let (seed_len, current_len) = {
             let data_ref = record.try_borrow_data()?;
-            data_ref[SEED_LEN_OFFSET] as usize
+            if data_ref[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            (data_ref[SEED_LEN_OFFSET] as usize, data_ref.len())
         };
 
         let offset = seed_len + SEED_LEN_OFFSET + size_of::<u8>();
-        let current_len = record.data_len();
         let new_len = offset + data.len();
 
+        if new_len > MAX_RECORD_SIZE {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
         if new_len != current_len {
             resize_account(record, payer, new_len, new_len < current_len)?;
         }
 
+        // Only take the mutable borrow once the account has been resized
         {
             let mut data_ref = record.try_borrow_mut_data()?;
-            if data_ref[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
-                return Err(ProgramError::InvalidAccountData);
-            }
             let data_buffer = unsafe {
                 core::slice::from_raw_parts_mut(data_ref.as_mut_ptr().add(offset), data.len())
             };
@@ -386,6 +785,122 @@ impl<'info> Record<'info> {
         Ok(())
     }
 
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    ///
+    /// Overwrites the record data at `offset` with `patch`, without resizing
+    /// the record account
+    pub unsafe fn patch_data_unchecked(
+        data: &mut [u8],
+        offset: usize,
+        patch: &str,
+    ) -> Result<(), ProgramError> {
+        let current = Self::get_data_unchecked(data)?;
+
+        // The patch must fit in the current data and keep it valid UTF-8
+        let end = offset
+            .checked_add(patch.len())
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        if end > current.len()
+            || !current.is_char_boundary(offset)
+            || !current.is_char_boundary(end)
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let start = data.len() - current.len() + offset;
+        data[start..start + patch.len()].copy_from_slice(patch.as_bytes());
+
+        Ok(())
+    }
+
+    #[inline]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    ///
+    /// Replaces `range` of the record data with `replacement`, resizing the
+    /// record account accordingly
+    pub unsafe fn splice_data_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        range: Range<usize>,
+        replacement: &[u8],
+    ) -> Result<(), ProgramError> {
+        let (offset, current_len) = {
+            let data_ref = record.try_borrow_data()?;
+            if data_ref[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            (
+                SEED_LEN_OFFSET + size_of::<u8>() + data_ref[SEED_LEN_OFFSET] as usize,
+                data_ref.len(),
+            )
+        };
+
+        let start = offset + range.start;
+        let end = offset + range.end;
+
+        if start > end || end > current_len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let new_len = current_len - (end - start) + replacement.len();
+
+        if new_len > MAX_RECORD_SIZE {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // Grow the account before moving the trailing data
+        if new_len > current_len {
+            resize_account(record, payer, new_len, false)?;
+        }
+
+        {
+            let mut data_ref = record.try_borrow_mut_data()?;
+            data_ref.copy_within(end..current_len, start + replacement.len());
+            data_ref[start..start + replacement.len()].copy_from_slice(replacement);
+        }
+
+        // Shrink the account once the trailing data has been moved
+        if new_len < current_len {
+            resize_account(record, payer, new_len, true)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_seed_unchecked(data: &[u8]) -> &[u8] {
+        &data[SEED_OFFSET..SEED_OFFSET + data[SEED_LEN_OFFSET] as usize]
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    ///
+    /// Length of the record data, without checking that it is valid UTF-8
+    pub unsafe fn get_data_len_unchecked(data: &[u8]) -> usize {
+        data.len() - (SEED_LEN_OFFSET + size_of::<u8>() + data[SEED_LEN_OFFSET] as usize)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_data_unchecked(data: &[u8]) -> Result<&str, ProgramError> {
+        let offset = SEED_LEN_OFFSET + size_of::<u8>() + data[SEED_LEN_OFFSET] as usize;
+
+        str::from_utf8(&data[offset..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
     #[inline(always)]
     /// # Safety
     ///
@@ -404,6 +919,37 @@ impl<'info> Record<'info> {
         Ok(())
     }
 
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn copy_with_seed_unchecked(
+        source: &[u8],
+        destination: &mut RefMut<'info, [u8]>,
+        seed: &[u8],
+        bump: u8,
+    ) -> Result<(), ProgramError> {
+        let data = Self::get_data_unchecked(source)?;
+
+        if destination.len() < SEED_OFFSET + seed.len() + data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if destination[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Copy every fixed field of the record, then write the new bump and seed
+        destination[..SEED_LEN_OFFSET].copy_from_slice(&source[..SEED_LEN_OFFSET]);
+        destination[BUMP_OFFSET] = bump;
+
+        let mut variable_data = ByteWriter::new_with_offset(destination, SEED_LEN_OFFSET);
+        variable_data.write_bytes_with_length(seed)?;
+        variable_data.write_str(data)?;
+
+        Ok(())
+    }
+
     #[inline(always)]
     /// # Safety
     ///
@@ -492,10 +1038,13 @@ impl<'info> Record<'info> {
         Ok((metadata_data, additional_metadata_data))
     }
 
-    #[inline(always)]
+    #[inline]
     /// # Safety
     ///
     /// This function does not perform owner checks
+    ///
+    /// The record data is written as is: it is valid UTF-8 because `data` is
+    /// a `&str`, which instructions only build through `ByteReader::read_str`
     pub unsafe fn initialize_unchecked(
         &self,
         account_info: &'info AccountInfo,
@@ -512,10 +1061,25 @@ impl<'info> Record<'info> {
 
         ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
         ByteWriter::write_with_offset(&mut data, CLASS_OFFSET, self.class)?;
-        ByteWriter::write_with_offset(&mut data, OWNER_TYPE_OFFSET, self.owner_type)?;
+        ByteWriter::write_with_offset(&mut data, OWNER_TYPE_OFFSET, u8::from(self.owner_type))?;
         ByteWriter::write_with_offset(&mut data, OWNER_OFFSET, self.owner)?;
         ByteWriter::write_with_offset(&mut data, IS_FROZEN_OFFSET, self.is_frozen)?;
+        ByteWriter::write_with_offset(&mut data, NON_TRANSFERABLE_OFFSET, self.non_transferable)?;
         ByteWriter::write_with_offset(&mut data, EXPIRY_OFFSET, self.expiry)?;
+        ByteWriter::write_with_offset(&mut data, CREATED_AT_OFFSET, self.created_at)?;
+        ByteWriter::write_with_offset(&mut data, UPDATED_AT_OFFSET, self.updated_at)?;
+        ByteWriter::write_with_offset(&mut data, REVISION_OFFSET, self.revision)?;
+        ByteWriter::write_with_offset(&mut data, MUTATION_CURSOR_OFFSET, self.mutation_cursor)?;
+
+        let mut recent_mutations = ByteWriter::new_with_offset(&mut data, RECENT_MUTATIONS_OFFSET);
+        for mutation in self.recent_mutations.iter() {
+            recent_mutations.write(mutation.instruction_type)?;
+            recent_mutations.write(mutation.timestamp)?;
+            recent_mutations.write(mutation.actor)?;
+        }
+
+        ByteWriter::write_with_offset(&mut data, BUMP_OFFSET, self.bump)?;
+        ByteWriter::write_with_offset(&mut data, MINT_BUMP_OFFSET, self.mint_bump)?;
 
         let mut variable_data = ByteWriter::new_with_offset(&mut data, SEED_LEN_OFFSET);
         variable_data.write_bytes_with_length(self.seed)?;
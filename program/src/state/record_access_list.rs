@@ -0,0 +1,181 @@
+use crate::utils::{resize_account, ByteWriter};
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const RECORD_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
+const ENTRIES_LEN_OFFSET: usize = RECORD_OFFSET + size_of::<Pubkey>();
+const ENTRIES_OFFSET: usize = ENTRIES_LEN_OFFSET + size_of::<u8>();
+
+#[repr(C)]
+pub struct RecordAccessList<'info> {
+    /// The class of the record
+    pub class: Pubkey,
+    /// The record this access list applies to
+    pub record: Pubkey,
+    /// The accounts allowed to create the record, as concatenated pubkeys
+    pub entries: &'info [u8],
+}
+
+impl<'info> RecordAccessList<'info> {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 7;
+
+    /// Size of an empty record access list account
+    pub const MINIMUM_RECORD_ACCESS_LIST_SIZE: usize = ENTRIES_OFFSET;
+
+    /// Maximum number of accounts in a record access list
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Size of a record access list account holding `entries_len` bytes of entries
+    #[inline(always)]
+    pub const fn size(entries_len: usize) -> usize {
+        Self::MINIMUM_RECORD_ACCESS_LIST_SIZE + entries_len
+    }
+
+    /// Check if the program id is valid
+    #[inline(always)]
+    pub fn check_program_id(access_list: &AccountInfo) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { access_list.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if data.len() < Self::MINIMUM_RECORD_ACCESS_LIST_SIZE
+            || data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR)
+            || data.len() != Self::size(data[ENTRIES_LEN_OFFSET] as usize * size_of::<Pubkey>())
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the entries are a list of at most `MAX_ENTRIES` pubkeys
+    #[inline(always)]
+    pub fn check_entries(entries: &[u8]) -> Result<(), ProgramError> {
+        if !entries.len().is_multiple_of(size_of::<Pubkey>())
+            || entries.len() > Self::MAX_ENTRIES * size_of::<Pubkey>()
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(())
+    }
+
+    /// Check that the access list belongs to the class
+    pub fn check_class(access_list: &AccountInfo, class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(access_list)?;
+
+        let data = access_list.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? };
+
+        if class
+            .key()
+            .ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Check if `key` is listed in the access list of the record
+    pub fn contains(
+        access_list: &AccountInfo,
+        record: &AccountInfo,
+        key: &Pubkey,
+    ) -> Result<bool, ProgramError> {
+        Self::check_program_id(access_list)?;
+
+        let data = access_list.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? };
+
+        if record
+            .key()
+            .ne(&data[RECORD_OFFSET..RECORD_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(data[ENTRIES_OFFSET..]
+            .chunks_exact(size_of::<Pubkey>())
+            .any(|entry| entry.eq(key)))
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_entries_unchecked(
+        access_list: &AccountInfo,
+        payer: &AccountInfo,
+        entries: &[u8],
+    ) -> Result<(), ProgramError> {
+        let new_len = Self::size(entries.len());
+
+        resize_account(access_list, payer, new_len, new_len < access_list.data_len())?;
+
+        let mut data = access_list.try_borrow_mut_data()?;
+        data[ENTRIES_LEN_OFFSET] = (entries.len() / size_of::<Pubkey>()) as u8;
+        data[ENTRIES_OFFSET..].copy_from_slice(entries);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn delete_record_access_list_unchecked(
+        access_list: &AccountInfo,
+        destination: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Resize to 0 bytes
+        access_list.realloc(0, true)?;
+        // Transfer ALL lamports back to the destination to fully close the account
+        let lamports = access_list.lamports();
+        *destination.try_borrow_mut_lamports()? = destination.lamports().saturating_add(lamports);
+        *access_list.try_borrow_mut_lamports()? = 0;
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::size(self.entries.len()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, CLASS_OFFSET, self.class)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_OFFSET, self.record)?;
+        ByteWriter::write_with_offset(
+            &mut data,
+            ENTRIES_LEN_OFFSET,
+            (self.entries.len() / size_of::<Pubkey>()) as u8,
+        )?;
+        data[ENTRIES_OFFSET..ENTRIES_OFFSET + self.entries.len()].copy_from_slice(self.entries);
+
+        Ok(())
+    }
+}
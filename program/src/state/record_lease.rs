@@ -0,0 +1,302 @@
+use crate::{
+    state::{Record, CLASS_OFFSET, OWNER_OFFSET},
+    utils::ByteWriter,
+};
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const RECORD_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const LESSEE_OFFSET: usize = RECORD_OFFSET + size_of::<Pubkey>();
+const EXPIRES_AT_OFFSET: usize = LESSEE_OFFSET + size_of::<Pubkey>();
+const PERMISSIONS_OFFSET: usize = EXPIRES_AT_OFFSET + size_of::<i64>();
+const RECORD_OWNER_OFFSET: usize = PERMISSIONS_OFFSET + size_of::<u8>();
+const RECORD_CREATED_AT_OFFSET: usize = RECORD_OWNER_OFFSET + size_of::<Pubkey>();
+const RECORD_CLASS_OFFSET: usize = RECORD_CREATED_AT_OFFSET + size_of::<i64>();
+
+#[repr(C)]
+pub struct RecordLease {
+    /// The record this lease grants access to
+    pub record: Pubkey,
+    /// The account that is temporarily allowed to write to the record, each lessee of a record
+    /// holds its own lease at `[b"lease", record, lessee]`
+    pub lessee: Pubkey,
+    /// Unix timestamp after which the lease is no longer active
+    pub expires_at: i64,
+    /// Bit flags of the operations the lessee is allowed to perform
+    pub permissions: u8,
+    /// Owner of the record when the lease was granted, a transfer of the record ends the lease
+    pub record_owner: Pubkey,
+    /// Creation timestamp of the record when the lease was granted, a record recreated at the
+    /// same address does not inherit the lease
+    pub record_created_at: i64,
+    /// Class of the record when the lease was granted, its authority revokes and collects the
+    /// rent of the lease even once the record is closed, a move of the record ends the lease
+    pub record_class: Pubkey,
+}
+
+impl RecordLease {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 3;
+
+    /// Size of a record lease account
+    pub const RECORD_LEASE_SIZE: usize = size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<Pubkey>()
+        + size_of::<i64>()
+        + size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<i64>()
+        + size_of::<Pubkey>();
+
+    /// The lessee can update the record data
+    pub const PERMISSION_UPDATE_DATA: u8 = 1 << 0;
+    /// The lessee can update the record expiry
+    pub const PERMISSION_UPDATE_EXPIRY: u8 = 1 << 1;
+    /// The lessee can freeze and unfreeze the record
+    pub const PERMISSION_FREEZE: u8 = 1 << 2;
+    /// Every operation a lease can grant
+    pub const PERMISSION_ALL: u8 =
+        Self::PERMISSION_UPDATE_DATA | Self::PERMISSION_UPDATE_EXPIRY | Self::PERMISSION_FREEZE;
+
+    /// Check if the program id is valid
+    #[inline(always)]
+    pub fn check_program_id(lease: &AccountInfo) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { lease.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if data.len() != Self::RECORD_LEASE_SIZE
+            || data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_record_unchecked(
+        data: &[u8],
+        record: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if record
+            .key()
+            .ne(&data[RECORD_OFFSET..RECORD_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_class_unchecked(
+        data: &[u8],
+        class: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if class
+            .key()
+            .ne(&data[RECORD_CLASS_OFFSET..RECORD_CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_expires_at_unchecked(data: &[u8]) -> i64 {
+        i64::from_le_bytes(
+            data[EXPIRES_AT_OFFSET..EXPIRES_AT_OFFSET + size_of::<i64>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_expires_at_unchecked(
+        data: &mut [u8],
+        expires_at: i64,
+    ) -> Result<(), ProgramError> {
+        data[EXPIRES_AT_OFFSET..EXPIRES_AT_OFFSET + size_of::<i64>()]
+            .copy_from_slice(&expires_at.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks on the lease nor the record
+    pub unsafe fn is_stale_unchecked(data: &[u8], record_data: &[u8]) -> bool {
+        data[RECORD_OWNER_OFFSET..RECORD_OWNER_OFFSET + size_of::<Pubkey>()]
+            .ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+            || data[RECORD_CREATED_AT_OFFSET..RECORD_CREATED_AT_OFFSET + size_of::<i64>()]
+                .ne(&Record::get_created_at_unchecked(record_data).to_le_bytes())
+            || data[RECORD_CLASS_OFFSET..RECORD_CLASS_OFFSET + size_of::<Pubkey>()]
+                .ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+    }
+
+    /// Check that the lease belongs to the record
+    pub fn check_record(lease: &AccountInfo, record: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(lease)?;
+
+        let data = lease.try_borrow_data()?;
+
+        unsafe {
+            Self::check_discriminator_unchecked(&data)?;
+            Self::check_record_unchecked(&data, record)
+        }
+    }
+
+    /// Check that the lease belongs to the record and was granted under the class
+    pub fn check_record_and_class(
+        lease: &AccountInfo,
+        record: &AccountInfo,
+        class: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        Self::check_record(lease, record)?;
+
+        unsafe { Self::check_class_unchecked(&lease.try_borrow_data()?, class) }
+    }
+
+    /// Check if the lease can be closed by anyone: it has expired, the record was closed, or the
+    /// lease was granted over a previous owner, creation or class of the record
+    ///
+    /// A closed record is an account no longer owned by the program, without data, or drained
+    /// earlier in the same transaction, so the lease of a deleted, reclaimed, renamed or moved
+    /// record can always be closed. The lease must already have been checked to belong to the
+    /// record.
+    pub fn is_closable(lease: &AccountInfo, record: &AccountInfo) -> Result<bool, ProgramError> {
+        if unsafe { record.owner().ne(&crate::ID) }
+            || record.data_len() == 0
+            || record.lamports() == 0
+        {
+            return Ok(true);
+        }
+
+        Record::check_program_id_and_discriminator(record)?;
+
+        let data = lease.try_borrow_data()?;
+
+        Ok(
+            unsafe { Self::get_expires_at_unchecked(&data) } <= Clock::get()?.unix_timestamp
+                || unsafe { Self::is_stale_unchecked(&data, &record.try_borrow_data()?) },
+        )
+    }
+
+    /// Check that the authority is the lessee of an active lease over the record
+    /// and return the permissions granted by the lease along with its expiry
+    pub fn check_lessee(
+        lease: &AccountInfo,
+        record: &AccountInfo,
+        authority: &AccountInfo,
+    ) -> Result<(u8, i64), ProgramError> {
+        Self::check_program_id(lease)?;
+
+        let data = lease.try_borrow_data()?;
+
+        unsafe {
+            Self::check_discriminator_unchecked(&data)?;
+            Self::check_record_unchecked(&data, record)?;
+        }
+
+        // Check if the lease was granted over the current record, owner and class at this address
+        Record::check_program_id_and_discriminator(record)?;
+        if unsafe { Self::is_stale_unchecked(&data, &record.try_borrow_data()?) } {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the authority is signer
+        if !authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check if the authority is the lessee
+        if authority
+            .key()
+            .ne(&data[LESSEE_OFFSET..LESSEE_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Check if the lease is still active
+        let expires_at = unsafe { Self::get_expires_at_unchecked(&data) };
+        if expires_at <= Clock::get()?.unix_timestamp {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok((data[PERMISSIONS_OFFSET], expires_at))
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn delete_record_lease_unchecked(
+        lease: &AccountInfo,
+        destination: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Resize to 0 bytes
+        lease.realloc(0, true)?;
+        // Transfer ALL lamports back to the destination to fully close the account
+        let lamports = lease.lamports();
+        *destination.try_borrow_mut_lamports()? = destination.lamports().saturating_add(lamports);
+        *lease.try_borrow_mut_lamports()? = 0;
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::RECORD_LEASE_SIZE {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_OFFSET, self.record)?;
+        ByteWriter::write_with_offset(&mut data, LESSEE_OFFSET, self.lessee)?;
+        ByteWriter::write_with_offset(&mut data, EXPIRES_AT_OFFSET, self.expires_at)?;
+        ByteWriter::write_with_offset(&mut data, PERMISSIONS_OFFSET, self.permissions)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_OWNER_OFFSET, self.record_owner)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_CREATED_AT_OFFSET, self.record_created_at)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_CLASS_OFFSET, self.record_class)?;
+
+        Ok(())
+    }
+}
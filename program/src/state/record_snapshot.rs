@@ -0,0 +1,65 @@
+use crate::utils::ByteWriter;
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const RECORD_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const REVISION_OFFSET: usize = RECORD_OFFSET + size_of::<Pubkey>();
+const SLOT_OFFSET: usize = REVISION_OFFSET + size_of::<u32>();
+const UNIX_TIMESTAMP_OFFSET: usize = SLOT_OFFSET + size_of::<u64>();
+const DATA_HASH_OFFSET: usize = UNIX_TIMESTAMP_OFFSET + size_of::<i64>();
+
+#[repr(C)]
+pub struct RecordSnapshot {
+    /// The record this snapshot was taken from
+    pub record: Pubkey,
+    /// The revision of the record at the time of the snapshot
+    pub revision: u32,
+    /// The slot in which the snapshot was taken
+    pub slot: u64,
+    /// Unix timestamp of the snapshot
+    pub unix_timestamp: i64,
+    /// sha256 hash of the record data at the time of the snapshot
+    pub data_hash: [u8; 32],
+}
+
+impl RecordSnapshot {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 5;
+
+    /// Size of a record snapshot account
+    pub const RECORD_SNAPSHOT_SIZE: usize = size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<u32>()
+        + size_of::<u64>()
+        + size_of::<i64>()
+        + size_of::<[u8; 32]>();
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(
+        &self,
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::RECORD_SNAPSHOT_SIZE {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_OFFSET, self.record)?;
+        ByteWriter::write_with_offset(&mut data, REVISION_OFFSET, self.revision)?;
+        ByteWriter::write_with_offset(&mut data, SLOT_OFFSET, self.slot)?;
+        ByteWriter::write_with_offset(&mut data, UNIX_TIMESTAMP_OFFSET, self.unix_timestamp)?;
+        ByteWriter::write_with_offset(&mut data, DATA_HASH_OFFSET, self.data_hash)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::utils::ByteWriter;
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const BASIS_POINTS_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
+const DESTINATION_OFFSET: usize = BASIS_POINTS_OFFSET + size_of::<u16>();
+
+#[repr(C)]
+pub struct RoyaltyConfig {
+    /// The class this royalty config applies to
+    pub class: Pubkey,
+    /// Royalty charged on record transfers, in basis points
+    pub basis_points: u16,
+    /// The account receiving the royalties
+    pub destination: Pubkey,
+}
+
+impl RoyaltyConfig {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 6;
+
+    /// Size of a royalty config account
+    pub const ROYALTY_CONFIG_SIZE: usize =
+        size_of::<u8>() + size_of::<Pubkey>() + size_of::<u16>() + size_of::<Pubkey>();
+
+    /// Royalties cannot exceed the full transfer value
+    pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+    /// Check that the royalty config account is the PDA of the class
+    #[inline(always)]
+    pub fn check_address(royalty_config: &AccountInfo, class: &[u8]) -> Result<(), ProgramError> {
+        let (address, _) = try_find_program_address(&[b"royalty", class], &crate::ID)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        if address.ne(royalty_config.key()) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Check if the royalty config account has been created
+    #[inline(always)]
+    pub fn is_initialized(royalty_config: &AccountInfo) -> Result<bool, ProgramError> {
+        if unsafe { royalty_config.owner().ne(&crate::ID) } {
+            return Ok(false);
+        }
+
+        unsafe { Self::check_discriminator_unchecked(&royalty_config.try_borrow_data()?)? };
+
+        Ok(true)
+    }
+
+    /// Check if the program id is valid
+    #[inline(always)]
+    pub fn check_program_id(royalty_config: &AccountInfo) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { royalty_config.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if data.len() != Self::ROYALTY_CONFIG_SIZE
+            || data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_class_unchecked(data: &[u8], class: &[u8]) -> Result<(), ProgramError> {
+        if class.ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_basis_points_unchecked(data: &[u8]) -> u16 {
+        u16::from_le_bytes(
+            data[BASIS_POINTS_OFFSET..BASIS_POINTS_OFFSET + size_of::<u16>()]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_destination_unchecked(data: &[u8]) -> &[u8] {
+        &data[DESTINATION_OFFSET..DESTINATION_OFFSET + size_of::<Pubkey>()]
+    }
+
+    /// Check that the royalty config belongs to the class
+    pub fn check_class(royalty_config: &AccountInfo, class: &[u8]) -> Result<(), ProgramError> {
+        Self::check_program_id(royalty_config)?;
+
+        let data = royalty_config.try_borrow_data()?;
+
+        unsafe {
+            Self::check_discriminator_unchecked(&data)?;
+            Self::check_class_unchecked(&data, class)
+        }
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_unchecked(
+        data: &mut [u8],
+        basis_points: u16,
+        destination: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        data[BASIS_POINTS_OFFSET..BASIS_POINTS_OFFSET + size_of::<u16>()]
+            .copy_from_slice(&basis_points.to_le_bytes());
+        data[DESTINATION_OFFSET..DESTINATION_OFFSET + size_of::<Pubkey>()]
+            .copy_from_slice(destination);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(
+        &self,
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::ROYALTY_CONFIG_SIZE {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, CLASS_OFFSET, self.class)?;
+        ByteWriter::write_with_offset(&mut data, BASIS_POINTS_OFFSET, self.basis_points)?;
+        ByteWriter::write_with_offset(&mut data, DESTINATION_OFFSET, self.destination)?;
+
+        Ok(())
+    }
+}
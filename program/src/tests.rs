@@ -2,17 +2,22 @@ use borsh::de::BorshDeserialize;
 use borsh::ser::BorshSerialize;
 use core::str::FromStr;
 use solana_account::{Account, WritableAccount};
-use solana_program::program_error::ProgramError;
+use solana_program::{instruction::AccountMeta, program_error::ProgramError};
 
 use kaigan::types::{RemainderStr, RemainderVec, U8PrefixString, U8PrefixVec};
 use mollusk_svm::{program::keyed_account_for_system_program, result::Check, Mollusk};
 use solana_pubkey::Pubkey;
 
+use crate::error::SrsError;
+use crate::utils::{ByteReader, ByteWriter};
+use pinocchio::program_error::ProgramError as PinocchioError;
+use proptest::prelude::*;
+
 use solana_record_service_client::{
     accounts::*,
     instructions::*,
     programs::SOLANA_RECORD_SERVICE_ID,
-    types::{Metadata, AdditionalMetadata},
+    types::{AdditionalMetadata, BatchRecord, Metadata, RecordMutation},
 };
 
 pub const AUTHORITY: Pubkey = Pubkey::new_from_array([0xaa; 32]);
@@ -20,6 +25,15 @@ pub const OWNER: Pubkey = Pubkey::new_from_array([0xbb; 32]);
 pub const NEW_OWNER: Pubkey = Pubkey::new_from_array([0xcc; 32]);
 pub const RANDOM_PUBKEY: Pubkey = Pubkey::new_from_array([0xdd; 32]);
 
+// Compute unit ceilings, regression guards for the hot instructions
+pub const CREATE_CLASS_MAX_CU: u64 = 30_000;
+pub const CREATE_RECORD_MAX_CU: u64 = 20_000;
+pub const UPDATE_RECORD_MAX_CU: u64 = 10_000;
+pub const MINT_TOKENIZED_RECORD_MAX_CU: u64 = 60_000;
+pub const BURN_TOKENIZED_RECORD_MAX_CU: u64 = 20_000;
+pub const TRANSFER_TOKENIZED_RECORD_MAX_CU: u64 = 15_000;
+pub const CLONE_RECORD_MAX_CU: u64 = 25_000;
+
 // TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
 pub const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
     0x06, 0xdd, 0xf6, 0xe1, 0xee, 0x75, 0x8f, 0xde, 0x18, 0x42, 0x5d, 0xbc, 0xe4, 0x6c, 0xcd, 0xda,
@@ -75,6 +89,22 @@ fn make_u8prefix_vec_u8(s: &[u8]) -> U8PrefixVec<u8> {
     U8PrefixVec::try_from_slice(&[&[s.len() as u8], s].concat()).expect("Invalid seed")
 }
 
+fn make_u8prefix_vec<T: BorshSerialize + BorshDeserialize>(items: &[T]) -> U8PrefixVec<T> {
+    let mut data = vec![items.len() as u8];
+    for item in items {
+        item.serialize(&mut data).expect("Invalid item");
+    }
+    U8PrefixVec::try_from_slice(&data).expect("Invalid items")
+}
+
+fn make_batch_records(records: &[BatchRecord]) -> RemainderVec<u8> {
+    let mut data = Vec::new();
+    for record in records {
+        record.serialize(&mut data).expect("Invalid batch record");
+    }
+    make_remainder_vec(&data)
+}
+
 fn make_u32prefix_string(s: &str) -> String {
     let len = s.len() as u32;
     let len_bytes = len.to_le_bytes();
@@ -125,7 +155,18 @@ fn keyed_account_for_class(
     name: &str,
     metadata: &str,
 ) -> (Pubkey, Account) {
-    let (address, _bump) = Pubkey::find_program_address(
+    keyed_account_for_class_with_schema(authority, is_permissioned, is_frozen, name, "", metadata)
+}
+
+fn keyed_account_for_class_with_schema(
+    authority: Pubkey,
+    is_permissioned: bool,
+    is_frozen: bool,
+    name: &str,
+    metadata_schema_uri: &str,
+    metadata: &str,
+) -> (Pubkey, Account) {
+    let (address, bump) = Pubkey::find_program_address(
         &[b"class", &authority.as_ref(), name.as_ref()],
         &SOLANA_RECORD_SERVICE_ID,
     );
@@ -135,7 +176,13 @@ fn keyed_account_for_class(
         authority,
         is_permissioned,
         is_frozen,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        max_record_size: 0,
+        max_records: 0,
+        bump,
         name: make_u8prefix_string(name),
+        metadata_schema_uri: make_u8prefix_string(metadata_schema_uri),
         metadata: make_remainder_str(metadata),
     }
     .try_to_vec()
@@ -152,6 +199,85 @@ fn keyed_account_for_class(
     (address, class_account)
 }
 
+fn class_with_limits(mut class: Account, max_record_size: u32, max_records: u32) -> Account {
+    let mut class_data = Class::from_bytes(&class.data).expect("Invalid class");
+    class_data.max_record_size = max_record_size;
+    class_data.max_records = max_records;
+
+    class.data = class_data.try_to_vec().expect("Invalid class");
+    class
+}
+
+fn class_with_transfer_fee(
+    mut class: Account,
+    transfer_fee_bps: u16,
+    fee_destination: Pubkey,
+) -> Account {
+    let mut class_data = Class::from_bytes(&class.data).expect("Invalid class");
+    class_data.transfer_fee_bps = transfer_fee_bps;
+    class_data.fee_destination = fee_destination;
+
+    class.data = class_data.try_to_vec().expect("Invalid class");
+    class
+}
+
+fn empty_recent_mutations() -> [RecordMutation; 5] {
+    core::array::from_fn(|_| RecordMutation {
+        instruction_type: 0,
+        timestamp: 0,
+        actor: Pubkey::default(),
+    })
+}
+
+/// Append a mutation to the audit log of a record account, the way the program does
+fn record_with_mutation(mut record: Account, instruction_type: u8, actor: Pubkey) -> Account {
+    let mut record_data = Record::from_bytes(&record.data).expect("Invalid record");
+    let cursor = record_data.mutation_cursor as usize;
+
+    record_data.recent_mutations[cursor] = RecordMutation {
+        instruction_type,
+        timestamp: 0,
+        actor,
+    };
+    record_data.mutation_cursor = ((cursor + 1) % 5) as u8;
+
+    record.data = record_data.try_to_vec().expect("Invalid record");
+    record
+}
+
+fn record_with_revision(mut record: Account, revision: u32) -> Account {
+    let mut record_data = Record::from_bytes(&record.data).expect("Invalid record");
+    record_data.revision = revision;
+
+    record.data = record_data.try_to_vec().expect("Invalid record");
+    record
+}
+
+fn record_with_created_at(mut record: Account, created_at: i64) -> Account {
+    let mut record_data = Record::from_bytes(&record.data).expect("Invalid record");
+    record_data.created_at = created_at;
+
+    record.data = record_data.try_to_vec().expect("Invalid record");
+    record
+}
+
+fn record_non_transferable(mut record: Account) -> Account {
+    let mut record_data = Record::from_bytes(&record.data).expect("Invalid record");
+    record_data.non_transferable = true;
+
+    record.data = record_data.try_to_vec().expect("Invalid record");
+    record
+}
+
+/// The mint bump cached in tokenized records, 0 for the other records
+fn mint_bump_for_record(record: &Pubkey, owner_type: u8) -> u8 {
+    if owner_type == 1 {
+        Pubkey::find_program_address(&[b"mint", record.as_ref()], &SOLANA_RECORD_SERVICE_ID).1
+    } else {
+        0
+    }
+}
+
 fn keyed_account_for_record(
     class: Pubkey,
     owner_type: u8,
@@ -161,7 +287,7 @@ fn keyed_account_for_record(
     seed: &[u8],
     data: &[u8],
 ) -> (Pubkey, Account) {
-    let (address, _bump) = Pubkey::find_program_address(
+    let (address, bump) = Pubkey::find_program_address(
         &[b"record", &class.as_ref(), seed.as_ref()],
         &SOLANA_RECORD_SERVICE_ID,
     );
@@ -171,7 +297,15 @@ fn keyed_account_for_record(
         owner_type,
         owner,
         is_frozen,
+        non_transferable: false,
         expiry,
+        created_at: 0,
+        updated_at: 0,
+        revision: 0,
+        mutation_cursor: 0,
+        recent_mutations: empty_recent_mutations(),
+        bump,
+        mint_bump: mint_bump_for_record(&address, owner_type),
         seed: make_u8prefix_vec_u8(seed),
         data: RemainderVec::<u8>::try_from_slice(data).unwrap(),
     }
@@ -190,6 +324,185 @@ fn keyed_account_for_record(
     (address, record_account)
 }
 
+fn keyed_account_for_class_stats(
+    class: Pubkey,
+    record_count: u64,
+    total_bytes: u64,
+) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"stats", &class.as_ref()], &SOLANA_RECORD_SERVICE_ID);
+    let class_stats_account_data = ClassStats {
+        discriminator: 4,
+        record_count,
+        total_bytes,
+    }
+    .try_to_vec()
+    .expect("Invalid class stats");
+
+    let mut class_stats_account = Account::new(
+        100_000_000u64,
+        class_stats_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    class_stats_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class_stats_account_data);
+
+    (address, class_stats_account)
+}
+
+fn keyed_account_for_class_registry(authority: Pubkey, class_count: u32) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"registry", &authority.as_ref()],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let class_registry_account_data = ClassRegistry {
+        discriminator: 8,
+        class_count,
+    }
+    .try_to_vec()
+    .expect("Invalid class registry");
+
+    let mut class_registry_account = Account::new(
+        100_000_000u64,
+        class_registry_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    class_registry_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class_registry_account_data);
+
+    (address, class_registry_account)
+}
+
+fn keyed_account_for_class_registry_page(
+    authority: Pubkey,
+    page: u32,
+    classes: &[Pubkey],
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"registry", &authority.as_ref(), &page.to_le_bytes()],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let class_registry_page_account_data = ClassRegistryPage {
+        discriminator: 9,
+        classes: classes.to_vec(),
+    }
+    .try_to_vec()
+    .expect("Invalid class registry page");
+
+    let mut class_registry_page_account = Account::new(
+        100_000_000u64,
+        class_registry_page_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    class_registry_page_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class_registry_page_account_data);
+
+    (address, class_registry_page_account)
+}
+
+fn keyed_account_for_record_lease(
+    class: Pubkey,
+    record: Pubkey,
+    lessee: Pubkey,
+    expires_at: i64,
+    permissions: u8,
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"lease", &record.as_ref(), lessee.as_ref()],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let lease_account_data = RecordLease {
+        discriminator: 3,
+        record,
+        lessee,
+        expires_at,
+        permissions,
+        // Bound to the records of `keyed_account_for_record` owned by OWNER
+        record_owner: OWNER,
+        record_created_at: 0,
+        record_class: class,
+    }
+    .try_to_vec()
+    .expect("Invalid record lease");
+
+    let mut lease_account = Account::new(
+        100_000_000u64,
+        lease_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    lease_account
+        .data_as_mut_slice()
+        .clone_from_slice(&lease_account_data);
+
+    (address, lease_account)
+}
+
+fn keyed_account_for_record_access_list(
+    class: Pubkey,
+    record: Pubkey,
+    entries: &[Pubkey],
+) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"acl", &record.as_ref()], &SOLANA_RECORD_SERVICE_ID);
+    let access_list_account_data = RecordAccessList {
+        discriminator: 7,
+        class,
+        record,
+        entries: make_u8prefix_vec(entries),
+    }
+    .try_to_vec()
+    .expect("Invalid record access list");
+
+    let mut access_list_account = Account::new(
+        100_000_000u64,
+        access_list_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    access_list_account
+        .data_as_mut_slice()
+        .clone_from_slice(&access_list_account_data);
+
+    (address, access_list_account)
+}
+
+fn keyed_account_for_royalty_config(
+    class: Pubkey,
+    basis_points: u16,
+    destination: Pubkey,
+) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"royalty", &class.as_ref()], &SOLANA_RECORD_SERVICE_ID);
+    let royalty_config_account_data = RoyaltyConfig {
+        discriminator: 6,
+        class,
+        basis_points,
+        destination,
+    }
+    .try_to_vec()
+    .expect("Invalid royalty config");
+
+    let mut royalty_config_account = Account::new(
+        100_000_000u64,
+        royalty_config_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    royalty_config_account
+        .data_as_mut_slice()
+        .clone_from_slice(&royalty_config_account_data);
+
+    (address, royalty_config_account)
+}
+
+fn keyed_account_for_uninitialized_royalty_config(class: Pubkey) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"royalty", &class.as_ref()], &SOLANA_RECORD_SERVICE_ID);
+
+    (address, Account::default())
+}
+
 /// Fake Metadata that has
 /// - name: "test"
 /// - symbol: "SRS"
@@ -209,7 +522,7 @@ fn keyed_account_for_record_with_metadata(
     name: &str,
     metadata: Option<&[u8]>,
 ) -> (Pubkey, Account) {
-    let (address, _bump) = Pubkey::find_program_address(
+    let (address, bump) = Pubkey::find_program_address(
         &[b"record", &class.as_ref(), name.as_ref()],
         &SOLANA_RECORD_SERVICE_ID,
     );
@@ -219,7 +532,15 @@ fn keyed_account_for_record_with_metadata(
         owner_type,
         owner,
         is_frozen,
+        non_transferable: false,
         expiry,
+        created_at: 0,
+        updated_at: 0,
+        revision: 0,
+        mutation_cursor: 0,
+        recent_mutations: empty_recent_mutations(),
+        bump,
+        mint_bump: mint_bump_for_record(&address, owner_type),
         seed: make_u8prefix_vec_u8(name.as_bytes()),
         data: RemainderVec::<u8>::try_from_slice(metadata.unwrap_or(METADATA)).unwrap(),
     }
@@ -258,7 +579,7 @@ fn keyed_account_for_record_with_metadata_and_additional_metadata(
     expiry: i64,
     name: &str,
 ) -> (Pubkey, Account) {
-    let (address, _bump) = Pubkey::find_program_address(
+    let (address, bump) = Pubkey::find_program_address(
         &[b"record", &class.as_ref(), name.as_ref()],
         &SOLANA_RECORD_SERVICE_ID,
     );
@@ -268,7 +589,15 @@ fn keyed_account_for_record_with_metadata_and_additional_metadata(
         owner_type,
         owner,
         is_frozen,
+        non_transferable: false,
         expiry,
+        created_at: 0,
+        updated_at: 0,
+        revision: 0,
+        mutation_cursor: 0,
+        recent_mutations: empty_recent_mutations(),
+        bump,
+        mint_bump: mint_bump_for_record(&address, owner_type),
         seed: make_u8prefix_vec_u8(name.as_bytes()),
         data: RemainderVec::<u8>::try_from_slice(METADATA_WITH_ADDITIONAL_METADATA).unwrap(),
     }
@@ -302,7 +631,7 @@ fn keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
     expiry: i64,
     name: &str,
 ) -> (Pubkey, Account) {
-    let (address, _bump) = Pubkey::find_program_address(
+    let (address, bump) = Pubkey::find_program_address(
         &[b"record", &class.as_ref(), name.as_ref()],
         &SOLANA_RECORD_SERVICE_ID,
     );
@@ -312,7 +641,15 @@ fn keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
         owner_type,
         owner,
         is_frozen,
+        non_transferable: false,
         expiry,
+        created_at: 0,
+        updated_at: 0,
+        revision: 0,
+        mutation_cursor: 0,
+        recent_mutations: empty_recent_mutations(),
+        bump,
+        mint_bump: mint_bump_for_record(&address, owner_type),
         seed: make_u8prefix_vec_u8(name.as_bytes()),
         data: RemainderVec::<u8>::try_from_slice(METADATA_WITH_MULTIPLE_ADDITIONAL_METADATA)
             .unwrap(),
@@ -369,6 +706,8 @@ const MINT_GROUP_MEMBER_POINTER_EXTENSION: &[u8] = &[
     5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4,
     96, 81, 27, 127,
 ];
+/// NonTransferable extension: type 9, no data
+const MINT_NON_TRANSFERABLE_EXTENSION: &[u8] = &[9, 0, 0, 0];
 const MINT_METADATA_EXTENSION: &[u8] = &[
     19, 0, 91, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
     142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
@@ -660,13 +999,30 @@ const MINT_GROUP_EXTENSION: &[u8] = &[
     23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255,
 ];
 
+const MINT_GROUP_METADATA_POINTER_EXTENSION: &[u8] = &[
+    18, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87,
+    188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59,
+    205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211,
+    23, 123, 152, 136, 141, 87, 92,
+];
+/// Metadata extension of the group: class name "test", symbol "GRP" and class metadata "test"
+const MINT_GROUP_METADATA_EXTENSION: &[u8] = &[
+    19, 0, 91, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87,
+    188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59,
+    205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211,
+    23, 123, 152, 136, 141, 87, 92, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 71, 82, 80, 4, 0,
+    0, 0, 116, 101, 115, 116, 0, 0, 0, 0,
+];
+
 fn keyed_account_for_group(class: Pubkey) -> (Pubkey, Account) {
     let (address, _bump) =
         Pubkey::find_program_address(&[b"group", &class.as_ref()], &SOLANA_RECORD_SERVICE_ID);
 
     let total_size = GROUP_MINT_DATA_WITH_EXTENSIONS.len()
         + MINT_GROUP_POINTER_EXTENSION.len()
-        + MINT_GROUP_EXTENSION.len();
+        + MINT_GROUP_METADATA_POINTER_EXTENSION.len()
+        + MINT_GROUP_EXTENSION.len()
+        + MINT_GROUP_METADATA_EXTENSION.len();
 
     let mut group_account_data = vec![0u8; total_size];
 
@@ -678,9 +1034,17 @@ fn keyed_account_for_group(class: Pubkey) -> (Pubkey, Account) {
     group_account_data[offset..offset + MINT_GROUP_POINTER_EXTENSION.len()]
         .copy_from_slice(MINT_GROUP_POINTER_EXTENSION);
     offset += MINT_GROUP_POINTER_EXTENSION.len();
+    // Metadata Pointer Extension
+    group_account_data[offset..offset + MINT_GROUP_METADATA_POINTER_EXTENSION.len()]
+        .copy_from_slice(MINT_GROUP_METADATA_POINTER_EXTENSION);
+    offset += MINT_GROUP_METADATA_POINTER_EXTENSION.len();
     // Group Extension
     group_account_data[offset..offset + MINT_GROUP_EXTENSION.len()]
         .copy_from_slice(MINT_GROUP_EXTENSION);
+    offset += MINT_GROUP_EXTENSION.len();
+    // Metadata Extension
+    group_account_data[offset..offset + MINT_GROUP_METADATA_EXTENSION.len()]
+        .copy_from_slice(MINT_GROUP_METADATA_EXTENSION);
 
     let mut group_account = Account::new(
         100_000_000u64,
@@ -746,6 +1110,14 @@ fn create_class() {
     // Class
     let (class, class_data) = keyed_account_for_class_default();
 
+    // Class registry
+    let (class_registry, class_registry_data) = keyed_account_for_class_registry(authority, 1);
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(authority, 0, &[class]);
+
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
@@ -753,12 +1125,18 @@ fn create_class() {
         authority,
         payer: authority,
         class,
+        class_registry,
+        class_registry_page,
         system_program,
+        class_stats,
     }
     .instruction(CreateClassInstructionArgs {
         is_permissioned: false,
         is_frozen: false,
+        max_record_size: 0,
+        max_records: 0,
         name: make_u8prefix_string("test"),
+        metadata_schema_uri: make_u8prefix_string(""),
         metadata: make_remainder_str("test"),
     });
 
@@ -767,43 +1145,72 @@ fn create_class() {
         "../target/deploy/solana_record_service",
     );
 
-    mollusk.process_and_validate_instruction(
+    let result = mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
             (class, Account::default()),
+            (class_registry, Account::default()),
+            (class_registry_page, Account::default()),
+            (class_stats, Account::default()),
             (system_program, system_program_data),
         ],
         &[
             Check::success(),
             Check::account(&class).data(&class_data.data).build(),
+            Check::account(&class_stats)
+                .data(&class_stats_data.data)
+                .build(),
+            Check::account(&class_registry)
+                .data(&class_registry_data.data)
+                .build(),
+            Check::account(&class_registry_page)
+                .data(&class_registry_page_data.data)
+                .build(),
         ],
     );
+
+    assert!(result.compute_units_consumed <= CREATE_CLASS_MAX_CU);
 }
 
 #[test]
-fn update_class_metadata() {
+/// Fails because the class is a signer keypair instead of the class PDA
+fn fail_create_class_incorrect_class() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let class = Pubkey::new_unique();
+
+    // Class registry
+    let (class_registry, _) = keyed_account_for_class_registry(authority, 0);
+    let (class_registry_page, _) = keyed_account_for_class_registry_page(authority, 0, &[]);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
 
-    // Class Updated
-    let (_, class_data_updated) = keyed_account_for_class(authority, false, false, "test", "test2");
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateClassMetadata {
+    let mut instruction = CreateClass {
         authority,
-        payer,
+        payer: authority,
         class,
+        class_registry,
+        class_registry_page,
         system_program,
+        class_stats,
     }
-    .instruction(UpdateClassMetadataInstructionArgs {
-        metadata: RemainderStr::from_str("test2").unwrap(),
+    .instruction(CreateClassInstructionArgs {
+        is_permissioned: false,
+        is_frozen: false,
+        max_record_size: 0,
+        max_records: 0,
+        name: make_u8prefix_string("test"),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: make_remainder_str("test"),
     });
+    // Sign with the class, so that it could be created without the PDA
+    instruction.accounts[2].is_signer = true;
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
@@ -814,37 +1221,52 @@ fn update_class_metadata() {
         &instruction,
         &[
             (authority, authority_data),
-            (payer, payer_data),
-            (class, class_data),
+            (class, Account::default()),
+            (class_registry, Account::default()),
+            (class_registry_page, Account::default()),
+            (class_stats, Account::default()),
             (system_program, system_program_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&class)
-                .data(&class_data_updated.data)
-                .build(),
-        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-/// Fails because the class_authority != authority of the instruction
-fn update_class_metadata_incorrect_authority() {
+/// Fails because the authority is the zero address
+fn fail_create_class_zero_authority() {
     // Authority
-    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
+    let authority = Pubkey::default();
+    let authority_data = Account::new(100_000_000_000u64, 0, &Pubkey::default());
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, _) = keyed_account_for_class_default();
+
+    // Class registry
+    let (class_registry, _) = keyed_account_for_class_registry(authority, 0);
+    let (class_registry_page, _) = keyed_account_for_class_registry_page(authority, 0, &[]);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateClassMetadata {
-        authority: random_authority,
-        payer: random_authority,
+    let instruction = CreateClass {
+        authority,
+        payer: authority,
         class,
+        class_registry,
+        class_registry_page,
         system_program,
+        class_stats,
     }
-    .instruction(UpdateClassMetadataInstructionArgs {
-        metadata: RemainderStr::from_str("test2").unwrap(),
+    .instruction(CreateClassInstructionArgs {
+        is_permissioned: false,
+        is_frozen: false,
+        max_record_size: 0,
+        max_records: 0,
+        name: make_u8prefix_string("test"),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: make_remainder_str("test"),
     });
 
     let mollusk = Mollusk::new(
@@ -855,92 +1277,113 @@ fn update_class_metadata_incorrect_authority() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (random_authority, random_authority_data),
-            (class, class_data),
+            (authority, authority_data),
+            (class, Account::default()),
+            (class_registry, Account::default()),
+            (class_registry_page, Account::default()),
+            (class_stats, Account::default()),
             (system_program, system_program_data),
         ],
-        &[Check::err(ProgramError::InvalidAccountData)],
+        &[Check::err(ProgramError::InvalidArgument)],
     );
 }
 
 #[test]
-fn update_class_authority() {
+fn create_class_with_metadata_schema_uri() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // New Authority
-    let (new_authority, _) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_class_with_schema(
+        AUTHORITY,
+        false,
+        false,
+        "test",
+        "https://example.com/schema.json",
+        "test",
+    );
+
+    // Class registry
+    let (class_registry, _) = keyed_account_for_class_registry(authority, 0);
+    let (class_registry_page, _) = keyed_account_for_class_registry_page(authority, 0, &[]);
+
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateClassAuthority { 
-        authority: authority,
+    let instruction = CreateClass {
+        authority,
         payer: authority,
         class,
-        system_program, 
-    }.instruction(UpdateClassAuthorityInstructionArgs { new_authority });
+        class_registry,
+        class_registry_page,
+        system_program,
+        class_stats,
+    }
+    .instruction(CreateClassInstructionArgs {
+        is_permissioned: false,
+        is_frozen: false,
+        max_record_size: 0,
+        max_records: 0,
+        name: make_u8prefix_string("test"),
+        metadata_schema_uri: make_u8prefix_string("https://example.com/schema.json"),
+        metadata: make_remainder_str("test"),
+    });
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    // Class Updated
-    let (_, class_data_updated) = keyed_account_for_class(new_authority, false, false, "test", "test");
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (class, class_data),
+            (class, Account::default()),
+            (class_registry, Account::default()),
+            (class_registry_page, Account::default()),
+            (class_stats, Account::default()),
             (system_program, system_program_data),
         ],
         &[
             Check::success(),
-            Check::account(&class).data(&class_data_updated.data).build(),
+            Check::account(&class).data(&class_data.data).build(),
+            Check::account(&class_stats)
+                .data(&class_stats_data.data)
+                .build(),
         ],
     );
 }
 
 #[test]
-fn update_class_frozen() {
+fn update_class_metadata() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Class frozen
-    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
-
-    let instruction = FreezeClass { authority, class }
-        .instruction(FreezeClassInstructionArgs { is_frozen: true });
 
-    let mollusk = Mollusk::new(
-        &SOLANA_RECORD_SERVICE_ID,
-        "../target/deploy/solana_record_service",
-    );
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class(authority, false, false, "test", "test2");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    mollusk.process_and_validate_instruction(
-        &instruction,
-        &[(authority, authority_data), (class, class_data)],
-        &[
-            Check::success(),
-            Check::account(&class).data(&class_data_frozen.data).build(),
-        ],
-    );
-}
-
-#[test]
-fn update_class_frozen_already_frozen() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
-    // Class
-    let (class, class_data) = keyed_account_for_class(authority, false, true, "test", "test");
-    // Class frozen
-    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
-
-    let instruction = FreezeClass { authority, class }
-        .instruction(FreezeClassInstructionArgs { is_frozen: true });
+    let instruction = UpdateClassMetadata {
+        authority,
+        payer,
+        class,
+        system_program,
+    }
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test2").unwrap(),
+    });
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
@@ -949,38 +1392,48 @@ fn update_class_frozen_already_frozen() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(authority, authority_data), (class, class_data)],
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
         &[
             Check::success(),
-            Check::account(&class).data(&class_data_frozen.data).build(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
         ],
     );
 }
 
 #[test]
-fn create_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn update_class_metadata_shrink_refunds_payer() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let (class, class_data) =
+        keyed_account_for_class(authority, false, false, "test", "this is long metadata");
+    // Class updated with a shorter metadata
+    let (_, class_data_updated) = keyed_account_for_class(authority, false, false, "test", "short");
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecord {
-        owner,
-        payer: owner,
+    let instruction = UpdateClassMetadata {
+        authority,
+        payer,
         class,
-        record,
         system_program,
-        authority: None,
     }
-    .instruction(CreateRecordInstructionArgs {
-        expiration: 0,
-        seed: make_u8prefix_vec_u8(b"test"),
-        data: make_remainder_vec(b"test"),
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("short").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -988,153 +1441,194 @@ fn create_record() {
         "../target/deploy/solana_record_service",
     );
 
+    // The class keeps the rent of its new size, the excess goes to the payer
+    let class_lamports = mollusk
+        .sysvars
+        .rent
+        .minimum_balance(class_data_updated.data.len());
+    let payer_lamports = payer_data.lamports + class_data.lamports - class_lamports;
+
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
+            (authority, authority_data),
+            (payer, payer_data),
             (class, class_data),
-            (record, Account::default()),
             (system_program, system_program_data),
         ],
         &[
             Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .lamports(class_lamports)
+                .build(),
+            Check::account(&payer).lamports(payer_lamports).build(),
         ],
     );
 }
 
 #[test]
-fn create_record_with_metadata() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn update_class_metadata_and_group_metadata() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    //System Program
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class(authority, false, false, "test", "test2");
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // Group Updated, with the new class metadata as its uri
+    let mut group_metadata_updated =
+        MINT_GROUP_METADATA_EXTENSION[..MINT_GROUP_METADATA_EXTENSION.len() - 12].to_vec();
+    group_metadata_updated.extend_from_slice(&5u32.to_le_bytes());
+    group_metadata_updated.extend_from_slice(b"test2");
+    group_metadata_updated.extend_from_slice(&0u32.to_le_bytes());
+    let metadata_len = (group_metadata_updated.len() - 4) as u16;
+    group_metadata_updated[2..4].copy_from_slice(&metadata_len.to_le_bytes());
+
+    let mut group_data_updated =
+        group_data.data[..group_data.data.len() - MINT_GROUP_METADATA_EXTENSION.len()].to_vec();
+    group_data_updated.extend_from_slice(&group_metadata_updated);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecordTokenizable {
-        owner,
-        payer: owner,
+    let update_class_instruction = UpdateClassMetadata {
+        authority,
+        payer,
         class,
-        record,
         system_program,
-        authority: None,
     }
-    .instruction(CreateRecordTokenizableInstructionArgs {
-        expiration: 0,
-        seed: make_u8prefix_vec_u8(b"test"),
-        metadata: Metadata {
-            name: make_u32prefix_string("test"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![],
-        },
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test2").unwrap(),
     });
 
-    let mollusk = Mollusk::new(
+    let update_group_instruction = UpdateGroupMetadata {
+        authority,
+        payer,
+        class,
+        group,
+        token2022,
+        system_program,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk.process_and_validate_instruction(
-        &instruction,
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
         &[
-            (owner, owner_data),
-            (class, class_data),
-            (record, Account::default()),
-            (system_program, system_program_data),
+            (
+                &update_class_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&class)
+                        .data(&class_data_updated.data)
+                        .build(),
+                ],
+            ),
+            (
+                &update_group_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&group).data(&group_data_updated).build(),
+                    Check::account(&group).rent_exempt().build(),
+                ],
+            ),
         ],
         &[
-            Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (group, group_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
         ],
     );
 }
 
 #[test]
-fn create_record_with_metadata_and_additional_metadata() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn fail_update_group_metadata_not_class_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
-        class, 0, owner, false, 0, "test",
-    );
-    //System Program
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecordTokenizable {
-        owner,
-        payer: owner,
+    let instruction = UpdateGroupMetadata {
+        authority,
+        payer: authority,
         class,
-        record,
+        group,
+        token2022,
         system_program,
-        authority: None,
     }
-    .instruction(CreateRecordTokenizableInstructionArgs {
-        expiration: 0,
-        seed: make_u8prefix_vec_u8(b"test"),
-        metadata: Metadata {
-            name: make_u32prefix_string("test"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![AdditionalMetadata {
-                label: make_u32prefix_string("test"),
-                value: make_u32prefix_string("test"),
-            }],
-        },
-    });
+    .instruction();
 
-    let mollusk = Mollusk::new(
+    let mut mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
+            (authority, authority_data),
             (class, class_data),
-            (record, Account::default()),
+            (group, group_data),
+            (token2022, token2022_data),
             (system_program, system_program_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
-        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
     );
 }
 
 #[test]
-fn create_permissioned_record() {
+fn fail_update_class_metadata_too_long() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecord {
-        owner,
-        payer: authority,
+    // One byte over MAX_METADATA_LEN
+    let metadata = "a".repeat(crate::constants::MAX_METADATA_LEN + 1);
+
+    let instruction = UpdateClassMetadata {
+        authority,
+        payer,
         class,
-        record,
         system_program,
-        authority: Some(authority),
     }
-    .instruction(CreateRecordInstructionArgs {
-        expiration: 0,
-        seed: make_u8prefix_vec_u8(b"test"),
-        data: make_remainder_vec(b"test"),
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str(&metadata).unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1145,46 +1639,49 @@ fn create_permissioned_record() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
+            (authority, authority_data),
+            (payer, payer_data),
             (class, class_data),
-            (record, Account::default()),
             (system_program, system_program_data),
-            (authority, authority_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
         ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
     );
 }
 
 #[test]
-fn update_record() {
+fn update_class_metadata_schema_uri() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    // Record updated
-    let (_, record_data_updated) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
 
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class_with_schema(
+        authority,
+        false,
+        false,
+        "test",
+        "https://example.com/schema.json",
+        "test",
+    );
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateRecord {
+    let instruction = UpdateClassMetadata {
         authority,
         payer,
-        record,
         class,
         system_program,
     }
-    .instruction(UpdateRecordInstructionArgs {
-        data: make_remainder_vec(b"test2"),
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string("https://example.com/schema.json"),
+        metadata: RemainderStr::from_str("test").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1197,66 +1694,46 @@ fn update_record() {
         &[
             (authority, authority_data),
             (payer, payer_data),
-            (record, record_data),
             (class, class_data),
             (system_program, system_program_data),
         ],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
+            Check::account(&class)
+                .data(&class_data_updated.data)
                 .build(),
         ],
     );
 }
 
 #[test]
-fn update_record_with_metadata() {
+fn update_class_metadata_limits() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-
-    // New metadata
-    let new_metadata = Metadata {
-        name: make_u32prefix_string("test2"),
-        symbol: make_u32prefix_string("SRS"),
-        uri: make_u32prefix_string("test"),
-        additional_metadata: vec![],
-    };
-    // Record updated
-    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
-        class,
-        0,
-        OWNER,
-        false,
-        0,
-        "test",
-        Some(&new_metadata.try_to_vec().unwrap()),
-    );
 
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class_default();
+    let class_data_updated = class_with_limits(class_data_updated, 64, 10);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateRecordTokenizable {
+    let instruction = UpdateClassMetadata {
         authority,
         payer,
-        record,
         class,
         system_program,
     }
-    .instruction(UpdateRecordTokenizableInstructionArgs {
-        metadata: Metadata {
-            name: make_u32prefix_string("test2"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![],
-        },
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 64,
+        max_records: 10,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1269,44 +1746,46 @@ fn update_record_with_metadata() {
         &[
             (authority, authority_data),
             (payer, payer_data),
-            (record, record_data),
             (class, class_data),
             (system_program, system_program_data),
         ],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
+            Check::account(&class)
+                .data(&class_data_updated.data)
                 .build(),
         ],
     );
 }
 
 #[test]
-/// Fails because class authority != from authority of the instruction
-fn update_record_with_delegate_incorrect_authority() {
+fn update_class_metadata_transfer_fee() {
     // Authority
-    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
+    let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
 
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class_default();
+    let class_data_updated = class_with_transfer_fee(class_data_updated, 500, AUTHORITY);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateRecord {
-        authority: random_authority,
+    let instruction = UpdateClassMetadata {
+        authority,
         payer,
-        record,
         class,
         system_program,
     }
-    .instruction(UpdateRecordInstructionArgs {
-        data: make_remainder_vec(b"test2"),
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 500,
+        fee_destination: AUTHORITY,
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1317,39 +1796,45 @@ fn update_record_with_delegate_incorrect_authority() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (random_authority, random_authority_data),
+            (authority, authority_data),
             (payer, payer_data),
-            (record, record_data),
-            (system_program, system_program_data),
             (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
         ],
-        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn update_class_expiry() {
+/// Fails because the transfer fee exceeds 10000 basis points
+fn fail_update_class_metadata_transfer_fee_too_high() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateRecordExpiry {
+    let instruction = UpdateClassMetadata {
         authority,
         payer,
-        record,
         class,
         system_program,
     }
-    .instruction(UpdateRecordExpiryInstructionArgs {
-        expiry: 1000,
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 10_001,
+        fee_destination: AUTHORITY,
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1357,47 +1842,43 @@ fn update_class_expiry() {
         "../target/deploy/solana_record_service",
     );
 
-    // Record updated
-    let (_, record_data_updated) = keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
             (payer, payer_data),
-            (record, record_data),
-            (system_program, system_program_data),
             (class, class_data),
+            (system_program, system_program_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
-        ],
+        &[Check::err(ProgramError::InvalidArgument)],
     );
 }
 
 #[test]
-fn transfer_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+/// Fails because a class charging a transfer fee has no fee destination
+fn fail_update_class_metadata_transfer_fee_without_destination() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
-    // Record updated
-    let (_, record_data_updated) =
-        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = TransferRecord {
-        authority: owner,
-        record,
-        class: None,
+    let instruction = UpdateClassMetadata {
+        authority,
+        payer,
+        class,
+        system_program,
     }
-    .instruction(TransferRecordInstructionArgs {
-        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 500,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1407,36 +1888,39 @@ fn transfer_record() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(owner, owner_data), (record, record_data)],
         &[
-            Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::InvalidArgument)],
     );
 }
 
 #[test]
-fn transfer_record_with_delegate() {
+/// Fails because the class_authority != authority of the instruction
+fn update_class_metadata_incorrect_authority() {
     // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    // Record updated
-    let (_, record_data_updated) =
-        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = TransferRecord {
-        authority,
-        record,
-        class: Some(class),
+    let instruction = UpdateClassMetadata {
+        authority: random_authority,
+        payer: random_authority,
+        class,
+        system_program,
     }
-    .instruction(TransferRecordInstructionArgs {
-        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    .instruction(UpdateClassMetadataInstructionArgs {
+        max_record_size: 0,
+        max_records: 0,
+        transfer_fee_bps: 0,
+        fee_destination: Pubkey::default(),
+        metadata_schema_uri: make_u8prefix_string(""),
+        metadata: RemainderStr::from_str("test2").unwrap(),
     });
 
     let mollusk = Mollusk::new(
@@ -1447,72 +1931,79 @@ fn transfer_record_with_delegate() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
-            (record, record_data),
+            (random_authority, random_authority_data),
             (class, class_data),
+            (system_program, system_program_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
-        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
     );
 }
 
 #[test]
-/// Fails because the record is frozen
-fn fail_transfer_record_frozen() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn update_class_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, _) = keyed_account_for_random_authority();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = TransferRecord {
-        authority: owner,
-        record,
-        class: None,
+    let instruction = UpdateClassAuthority {
+        authority: authority,
+        payer: authority,
+        class,
+        system_program,
     }
-    .instruction(TransferRecordInstructionArgs {
-        new_owner: Pubkey::new_from_array([0xcc; 32]),
-    });
+    .instruction(UpdateClassAuthorityInstructionArgs { new_authority });
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
+    // Class Updated
+    let (_, class_data_updated) =
+        keyed_account_for_class(new_authority, false, false, "test", "test");
+
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(owner, owner_data), (record, record_data)],
-        &[Check::err(ProgramError::InvalidAccountData)],
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
+        ],
     );
 }
 
 #[test]
-fn delete_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
+/// Fails because the new authority is the zero address
+fn fail_update_class_authority_zero_address() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = DeleteRecord {
-        authority: owner,
-        payer,
-        record,
-        class: None,
-        token2022_program: None,
-        mint: None,
+    let instruction = UpdateClassAuthority {
+        authority,
+        payer: authority,
+        class,
+        system_program,
     }
-    .instruction();
+    .instruction(UpdateClassAuthorityInstructionArgs {
+        new_authority: Pubkey::default(),
+    });
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
@@ -1522,38 +2013,38 @@ fn delete_record() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
-            (payer, payer_data),
-            (record, record_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&record).data(&[0xff]).build(),
+            (authority, authority_data),
+            (class, class_data),
+            (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::InvalidArgument)],
     );
 }
 
 #[test]
-fn delete_record_with_delegate() {
+/// Fails because the class authority did not sign the instruction
+fn fail_update_class_authority_missing_signature() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
+    // New Authority
+    let new_authority = NEW_OWNER;
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = DeleteRecord {
+    let mut instruction = UpdateClassAuthority {
         authority,
         payer,
-        record,
-        class: Some(class),
-        token2022_program: None,
-        mint: None,
+        class,
+        system_program,
     }
-    .instruction();
+    .instruction(UpdateClassAuthorityInstructionArgs { new_authority });
+
+    // Remove the authority signature
+    instruction.accounts[0].is_signer = false;
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
@@ -1565,87 +2056,102 @@ fn delete_record_with_delegate() {
         &[
             (authority, authority_data),
             (payer, payer_data),
-            (record, record_data),
             (class, class_data),
+            (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn update_class_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class frozen
+    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
+
+    let instruction = FreezeClass { authority, class }.instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
         &[
             Check::success(),
-            Check::account(&record).data(&[0xff]).build(),
+            Check::account(&class).data(&class_data_frozen.data).build(),
         ],
     );
 }
 
 #[test]
-fn delete_tokenized_record_with_no_supply() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn fail_update_class_frozen_already_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Class
-    let (class, _) = keyed_account_for_class_default();
-    // Mint
-    let (record, _bump) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
+    let (class, class_data) = keyed_account_for_class(authority, false, true, "test", "test");
+
+    let instruction = FreezeClass { authority, class }.instruction();
+
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
     );
-    let (mint, mut mint_data) = keyed_account_for_mint(record);
-    mint_data.data_as_mut_slice()[..MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY.len()].copy_from_slice(MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY);
-    // Record
-    let (_, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // Token2022 Program
-    let (token2022_program, token2022_program_data) = mollusk_svm_programs_token::token2022::keyed_account();
-   
-    let instruction = DeleteRecord {
-        authority: owner,
-        payer: owner,
-        record,
-        class: None,
-        token2022_program: Some(token2022_program),
-        mint: Some(mint),
-    }
-    .instruction();
 
-    let mut mollusk = Mollusk::new(
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn update_class_frozen_deprecated_is_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class frozen
+    let (class, class_data) = keyed_account_for_class(authority, false, true, "test", "test");
+    // Class
+    let (_, class_data_unfrozen) = keyed_account_for_class_default();
+
+    // The combined form appends the `is_frozen` flag to the instruction data
+    let mut instruction = FreezeClass { authority, class }.instruction();
+    instruction.data.push(false as u8);
+
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token2022_program, token2022_program_data),
-        ],
+        &[(authority, authority_data), (class, class_data)],
         &[
             Check::success(),
-            Check::account(&record).data(&[0xff]).build(),
-            Check::account(&mint).data(&[]).build(),
+            Check::account(&class)
+                .data(&class_data_unfrozen.data)
+                .build(),
         ],
     );
 }
 
 #[test]
-fn freeze_record() {
+fn unfreeze_class() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
+    // Class frozen
+    let (class, class_data) = keyed_account_for_class(authority, false, true, "test", "test");
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    // Record frozen
-    let (_, record_data_frozen) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let (_, class_data_unfrozen) = keyed_account_for_class_default();
 
-    let instruction = FreezeRecord {
-        authority,
-        record,
-        class,
-    }
-    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+    let instruction = UnfreezeClass { authority, class }.instruction();
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
@@ -1654,35 +2160,24 @@ fn freeze_record() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(authority, authority_data), (record, record_data), (class, class_data)],
+        &[(authority, authority_data), (class, class_data)],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_frozen.data)
+            Check::account(&class)
+                .data(&class_data_unfrozen.data)
                 .build(),
         ],
     );
 }
 
 #[test]
-fn freeze_record_already_frozen() {
+fn fail_unfreeze_class_not_frozen() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
-    // Record frozen
-    let (_, record_data_frozen) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
 
-    let instruction = FreezeRecord {
-        authority,
-        record,
-        class,
-    }
-    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+    let instruction = UnfreezeClass { authority, class }.instruction();
 
     let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
@@ -1691,801 +2186,8690 @@ fn freeze_record_already_frozen() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (authority, authority_data),
-            (record, record_data),
-            (class, class_data),
-        ],
+        &[(authority, authority_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassNotFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn update_class_permission() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class permissioned
+    let (_, class_data_permissioned) =
+        keyed_account_for_class(authority, true, false, "test", "test");
+
+    let instruction = UpdateClassPermission { authority, class }.instruction(
+        UpdateClassPermissionInstructionArgs {
+            is_permissioned: true,
+        },
+    );
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_frozen.data)
+            Check::account(&class)
+                .data(&class_data_permissioned.data)
                 .build(),
         ],
     );
 }
 
-// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0]`,
-// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255]`
+#[test]
+/// Fails because the class is frozen
+fn fail_update_class_permission_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, true, "test", "test");
+
+    let instruction = UpdateClassPermission { authority, class }.instruction(
+        UpdateClassPermissionInstructionArgs {
+            is_permissioned: false,
+        },
+    );
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassFrozen as u32,
+        ))],
+    );
+}
 
 #[test]
-fn mint_record_token() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn delete_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, mint_data) = keyed_account_for_mint(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
 
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[class]);
+    let (_, deleted_class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[Pubkey::default()]);
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: owner,
-        authority: owner,
-        record,
-        mint,
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteClass {
+        authority,
+        payer,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
+        class_registry_page,
+        class_stats,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, Account::default()),
+            (authority, authority_data),
+            (payer, payer_data),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
-            (system_program, system_program_data),
+            (class_registry_page, class_registry_page_data),
+            (class_stats, class_stats_data),
         ],
         &[
             Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
+            Check::account(&class).lamports(0).build(),
+            Check::account(&class_registry_page)
+                .data(&deleted_class_registry_page_data.data)
                 .build(),
-            Check::account(&group).rent_exempt().build(),
         ],
     );
 }
 
 #[test]
-fn mint_record_token_with_additional_metadata() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+/// Fails because a frozen class cannot be deleted
+fn fail_delete_class_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
-        class, 0, owner, false, 0, "test",
-    );
-    // Mint
-    let (mint, mint_data) = keyed_account_for_mint_with_additional_metadata(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, false, true, "test", "test");
 
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[class]);
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: owner,
-        authority: owner,
-        record,
-        mint,
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteClass {
+        authority,
+        payer,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
+        class_registry_page,
+        class_stats,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, Account::default()),
+            (authority, authority_data),
+            (payer, payer_data),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
-            (system_program, system_program_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
-                .build(),
-            Check::account(&mint).rent_exempt().build(),
+            (class_registry_page, class_registry_page_data),
+            (class_stats, class_stats_data),
         ],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn mint_record_token_with_multiple_additional_metadata() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+/// Fails because the class_authority != authority of the instruction
+fn fail_delete_class_incorrect_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_random_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
-            class, 0, owner, false, 0, "test",
-        );
-    // Mint
-    let (mint, mint_data) = keyed_account_for_mint_with_multiple_additional_metadata(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
 
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[class]);
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: owner,
-        authority: owner,
-        record,
-        mint,
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteClass {
+        authority,
+        payer,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
+        class_registry_page,
+        class_stats,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, Account::default()),
+            (authority, authority_data),
+            (payer, payer_data),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
-            (system_program, system_program_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
-                .build(),
-            Check::account(&mint).rent_exempt().build(),
+            (class_registry_page, class_registry_page_data),
+            (class_stats, class_stats_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
     );
 }
 
 #[test]
-fn mint_record_token_with_delegate() {
+/// Fails because the class is not listed in the class registry page
+fn fail_delete_class_not_in_registry_page() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, mint_data) = keyed_account_for_mint(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    let (class, class_data) = keyed_account_for_class_default();
 
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[RANDOM_PUBKEY]);
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: authority,
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteClass {
         authority,
-        record,
-        mint,
+        payer,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
+        class_registry_page,
+        class_stats,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
             (authority, authority_data),
-            (record, record_data),
-            (mint, Account::default()),
+            (payer, payer_data),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
-            (system_program, system_program_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
-                .build(),
+            (class_registry_page, class_registry_page_data),
+            (class_stats, class_stats_data),
         ],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn freeze_tokenized_record() {
+/// Fails because the class has no stats account
+fn fail_delete_class_without_class_stats() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &SOLANA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    // ATA updated
-    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
 
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[class]);
 
-    let instruction = FreezeTokenizedRecord {
+    // Class Stats, never created
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteClass {
         authority,
-        record,
-        mint,
-        token_account,
+        payer,
         class,
-        token2022,
+        class_registry_page,
+        class_stats,
     }
-    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+    .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
+            (payer, payer_data),
             (class, class_data),
-            (token2022, token2022_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&token_account)
-                .data(&token_account_data_updated.data)
-                .build(),
+            (class_registry_page, class_registry_page_data),
+            (class_stats, Account::default()),
         ],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn freeze_tokenized_record_delegate() {
+/// Fails because the class still holds a record
+fn fail_delete_class_with_records() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &SOLANA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    // ATA updated
-    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+    let (class, class_data) = keyed_account_for_class_default();
 
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[class]);
 
-    let instruction = FreezeTokenizedRecord {
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+
+    let instruction = DeleteClass {
         authority,
-        record,
-        mint,
-        token_account,
+        payer,
         class,
-        token2022,
+        class_registry_page,
+        class_stats,
     }
-    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+    .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (token2022, token2022_data),
+            (payer, payer_data),
             (class, class_data),
+            (class_registry_page, class_registry_page_data),
+            (class_stats, class_stats_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&token_account)
-                .data(&token_account_data_updated.data)
-                .build(),
-        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn transfer_tokenized_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+/// Fails because the class stats account is not the stats PDA of the class
+fn fail_delete_class_incorrect_class_stats() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_owner();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &SOLANA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
-    // New ATA
-    let (new_token_account, new_token_account_data) =
-        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+    let (class, class_data) = keyed_account_for_class_default();
 
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class registry page
+    let (class_registry_page, class_registry_page_data) =
+        keyed_account_for_class_registry_page(AUTHORITY, 0, &[class]);
 
-    let instruction = TransferTokenizedRecord {
-        authority: owner,
-        record,
-        mint,
-        token_account,
-        new_token_account,
-        token2022,
-        class: None,
+    // Class Stats
+    let (_, class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteClass {
+        authority,
+        payer,
+        class,
+        class_registry_page,
+        class_stats: RANDOM_PUBKEY,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (new_token_account, new_token_account_data),
-            (token2022, token2022_data),
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (class_registry_page, class_registry_page_data),
+            (RANDOM_PUBKEY, class_stats_data),
         ],
-        &[Check::success()],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn transfer_tokenized_record_delegate() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+fn create_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &SOLANA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    let (class, class_data) = keyed_account_for_class_default();
     // Record
     let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    // New ATA
-    let (new_token_account, new_token_account_data) =
-        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = TransferTokenizedRecord {
-        authority,
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
         record,
-        mint,
-        token_account,
-        new_token_account,
-        token2022,
-        class: Some(class),
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
     }
-    .instruction();
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
-    mollusk.process_and_validate_instruction(
+    let result = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (new_token_account, new_token_account_data),
-            (token2022, token2022_data),
+            (owner, owner_data),
             (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&class_stats)
+                .data(&class_stats_data.data)
+                .build(),
         ],
-        &[Check::success()],
     );
+
+    assert!(result.compute_units_consumed <= CREATE_RECORD_MAX_CU);
 }
 
 #[test]
-fn burn_tokenized_record() {
+/// Fails because the class was frozen, even though it is not permissioned
+fn fail_create_record_frozen_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &SOLANA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class frozen
+    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
     // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let freeze_instruction = FreezeClass { authority, class }.instruction();
 
-    let instruction = BurnTokenizedRecord {
-        authority: owner,
-        payer,
+    let create_instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
         record,
-        mint,
-        token_account,
-        token2022,
-        class: None,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
     }
-    .instruction();
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
-    mollusk.process_and_validate_instruction(
-        &instruction,
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (
+                &freeze_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&class).data(&class_data_frozen.data).build(),
+                ],
+            ),
+            (
+                &create_instruction,
+                &[Check::err(ProgramError::Custom(
+                    SrsError::ClassFrozen as u32,
+                ))],
+            ),
+        ],
         &[
+            (authority, authority_data),
             (owner, owner_data),
-            (payer, payer_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (token2022, token2022_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
         ],
-        &[Check::success()],
     );
 }
 
 #[test]
-fn burn_tokenized_record_delegate() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
+/// Fails because the record is a signer keypair instead of the record PDA
+fn fail_create_record_incorrect_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &SOLANA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    let (class, class_data) = keyed_account_for_class_default();
     // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let record = Pubkey::new_unique();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = BurnTokenizedRecord {
-        authority,
-        payer,
+    let mut instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
         record,
-        mint,
-        token_account,
-        token2022,
-        class: Some(class),
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
     }
-    .instruction();
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+    // Sign with the record, so that it could be created without the PDA
+    instruction.accounts[3].is_signer = true;
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
-            (payer, payer_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (token2022, token2022_data),
+            (owner, owner_data),
             (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
         ],
-        &[Check::success()],
+        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn mint_and_burn_tokenized_record() {
+/// Fails because the class stats account, a required account of CreateRecord, is missing
+fn fail_create_record_missing_class_stats() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
     // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, _) = keyed_account_for_mint(record);
-    // Group
-    let (group, _) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, _) = keyed_account_for_token(owner, mint, false);
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let mint_instruction = MintTokenizedRecord {
+    let mut instruction = CreateRecord {
         owner,
         payer: owner,
-        authority: owner,
-        record,
-        mint,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
-    }
-    .instruction();
-
-    let burn_instruction = BurnTokenizedRecord {
-        authority: owner,
-        payer: owner,
         record,
-        mint,
-        token_account,
-        token2022,
-        class: None,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
     }
-    .instruction();
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+    // Only pass the accounts that preceded the class stats account
+    instruction.accounts.truncate(5);
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
-    mollusk.process_and_validate_instruction_chain(
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (&mint_instruction, &[Check::success()]),
-            (&burn_instruction, &[Check::success()]),
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+/// Fails because the record data exceeds the class max record size
+fn fail_create_record_exceeds_max_record_size() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_limits(class_data, 2, 0);
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordTooLarge as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the record account exceeds the maximum record size
+fn fail_create_record_exceeds_max_size() {
+    use crate::state::MAX_RECORD_SIZE;
+
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(&vec![b'a'; MAX_RECORD_SIZE]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+/// Fails because the record expiry is already in the past
+fn fail_create_record_expired() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 1, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 1,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 1000;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+/// Fails because the class already holds its max number of records
+fn fail_create_record_exceeds_max_records() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_limits(class_data, 0, 1);
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, class_stats_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassCapacityExceeded as u32,
+        ))],
+    );
+}
+
+#[test]
+fn batch_create_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Records
+    let seeds: Vec<Vec<u8>> = (0..8u8)
+        .map(|i| [b"test".as_ref(), &[i]].concat())
+        .collect();
+    let records: Vec<(Pubkey, Account)> = seeds
+        .iter()
+        .map(|seed| keyed_account_for_record(class, 0, owner, false, 0, seed, b"test"))
+        .collect();
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 8, 32);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = BatchCreateRecord {
+        authority: owner,
+        payer: owner,
+        class,
+        class_stats,
+        system_program,
+    }
+    .instruction_with_remaining_accounts(
+        BatchCreateRecordInstructionArgs {
+            count: seeds.len() as u8,
+            records: make_batch_records(
+                &seeds
+                    .iter()
+                    .map(|seed| BatchRecord {
+                        seed: make_u8prefix_vec_u8(seed),
+                        data: "test".to_string(),
+                        expiry: 0,
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        },
+        &records
+            .iter()
+            .map(|(record, _)| AccountMeta::new(*record, false))
+            .collect::<Vec<_>>(),
+    );
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    let mut accounts = vec![
+        (owner, owner_data),
+        (class, class_data),
+        (class_stats, Account::default()),
+        (system_program, system_program_data),
+    ];
+    accounts.extend(
+        records
+            .iter()
+            .map(|(record, _)| (*record, Account::default())),
+    );
+
+    let mut checks = vec![
+        Check::success(),
+        Check::account(&class_stats)
+            .data(&class_stats_data.data)
+            .build(),
+    ];
+    checks.extend(
+        records
+            .iter()
+            .map(|(record, record_data)| Check::account(record).data(&record_data.data).build()),
+    );
+
+    mollusk.process_and_validate_instruction_chain(&[(&instruction, checks.as_slice())], &accounts);
+}
+
+#[test]
+/// Fails because the batch holds more than 8 records
+fn fail_batch_create_record_too_large() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = BatchCreateRecord {
+        authority: owner,
+        payer: owner,
+        class,
+        class_stats,
+        system_program,
+    }
+    .instruction(BatchCreateRecordInstructionArgs {
+        count: 9,
+        records: make_batch_records(
+            &(0..9u8)
+                .map(|i| BatchRecord {
+                    seed: make_u8prefix_vec_u8(&[i]),
+                    data: "test".to_string(),
+                    expiry: 0,
+                })
+                .collect::<Vec<_>>(),
+        ),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::BatchTooLarge as u32,
+        ))],
+    );
+}
+
+#[test]
+fn create_record_with_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_record_with_metadata_and_additional_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
+        class, 0, owner, false, 0, "test",
+    );
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![AdditionalMetadata {
+                label: make_u32prefix_string("test"),
+                value: make_u32prefix_string("test"),
+            }],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_permissioned_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: authority,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: Some(authority),
+        access_list: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+            (authority, authority_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_permissioned_record_with_metadata() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: Some(authority),
+        access_list: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+            (authority, authority_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class authority of the permissioned class did not sign
+fn fail_create_permissioned_record_with_metadata_missing_signature() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, _) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let mut instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: Some(authority),
+        access_list: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    // Remove the authority signature
+    instruction.accounts[6].is_signer = false;
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+            (authority, authority_data),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+/// Fails because the class is frozen
+fn fail_create_record_with_metadata_frozen_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, true, "test", "test");
+    // Record
+    let (record, _) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: Some(authority),
+        access_list: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+            (authority, authority_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn create_record_access_list() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Access List
+    let (access_list, access_list_data) =
+        keyed_account_for_record_access_list(class, record, &[OWNER]);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordAccessList {
+        authority,
+        payer: authority,
+        class,
+        record,
+        access_list,
+        system_program,
+    }
+    .instruction(CreateRecordAccessListInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"test"),
+        entries: make_u8prefix_vec(&[OWNER]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (record, Account::default()),
+            (access_list, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&access_list)
+                .data(&access_list_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the access list is not the access list PDA of the record
+fn fail_create_record_access_list_incorrect_access_list() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordAccessList {
+        authority,
+        payer: authority,
+        class,
+        record,
+        access_list: RANDOM_PUBKEY,
+        system_program,
+    }
+    .instruction(CreateRecordAccessListInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"test"),
+        entries: make_u8prefix_vec(&[OWNER]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (record, Account::default()),
+            (RANDOM_PUBKEY, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn update_record_access_list() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Access List
+    let (access_list, access_list_data) =
+        keyed_account_for_record_access_list(class, record, &[OWNER]);
+    // Access List updated
+    let (_, access_list_data_updated) =
+        keyed_account_for_record_access_list(class, record, &[OWNER, NEW_OWNER]);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordAccessList {
+        authority,
+        payer: authority,
+        class,
+        access_list,
+        system_program,
+    }
+    .instruction(UpdateRecordAccessListInstructionArgs {
+        entries: make_u8prefix_vec(&[OWNER, NEW_OWNER]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (access_list, access_list_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&access_list)
+                .data(&access_list_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn delete_record_access_list() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Access List
+    let (access_list, access_list_data) =
+        keyed_account_for_record_access_list(class, record, &[OWNER]);
+
+    let instruction = DeleteRecordAccessList {
+        authority,
+        payer: authority,
+        class,
+        access_list,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (access_list, access_list_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&access_list).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_permissioned_record_with_access_list() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Access List
+    let (access_list, access_list_data) =
+        keyed_account_for_record_access_list(class, record, &[owner]);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: Some(access_list),
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+            (access_list, access_list_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the owner is not listed in the record access list
+fn fail_create_permissioned_record_not_in_access_list() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Access List
+    let (access_list, access_list_data) =
+        keyed_account_for_record_access_list(class, record, &[NEW_OWNER]);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: Some(access_list),
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+            (access_list, access_list_data),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn update_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+
+    assert!(result.compute_units_consumed <= UPDATE_RECORD_MAX_CU);
+}
+
+#[test]
+fn update_record_authority_as_payer() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated with a larger data field
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test_grown");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer: authority,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test_grown"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    // The record is topped up to the rent of its new size by the authority
+    let record_lamports = mollusk
+        .sysvars
+        .rent
+        .minimum_balance(record_data_updated.data.len());
+    let authority_lamports = authority_data.lamports + record_data.lamports - record_lamports;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .lamports(record_lamports)
+                .build(),
+            Check::account(&authority)
+                .lamports(authority_lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn update_record_shrink_authority_as_payer() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_record(
+        class,
+        0,
+        OWNER,
+        false,
+        0,
+        b"test",
+        b"this is a long data string",
+    );
+    // Record updated with a smaller data field
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"short");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer: authority,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"short"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    // The record keeps the rent of its new size, the excess goes to the authority
+    let record_lamports = mollusk
+        .sysvars
+        .rent
+        .minimum_balance(record_data_updated.data.len());
+    let authority_lamports = authority_data.lamports + record_data.lamports - record_lamports;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .lamports(record_lamports)
+                .build(),
+            Check::account(&authority)
+                .lamports(authority_lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// The record pays for its own growth out of the lamports it holds above its rent
+fn update_record_record_as_payer() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated with a larger data field
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test_grown");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer: record,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test_grown"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data.clone()),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .lamports(record_data.lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record pays for its own growth without holding the rent of its new size
+fn fail_update_record_record_as_payer_not_rent_exempt() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, mut record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer: record,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test_grown"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    // The record only holds the rent of its current size
+    record_data.lamports = mollusk.sysvars.rent.minimum_balance(record_data.data.len());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::AccountNotRentExempt)],
+    );
+}
+
+#[test]
+fn update_record_shrink_refunds_payer() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_record(
+        class,
+        0,
+        OWNER,
+        false,
+        0,
+        b"test",
+        b"this is a long data string",
+    );
+    // Record updated with a smaller data field
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"short");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"short"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    // The record keeps the rent of its new size, the excess goes to the payer
+    let record_lamports = mollusk
+        .sysvars
+        .rent
+        .minimum_balance(record_data_updated.data.len());
+    let payer_lamports = payer_data.lamports + record_data.lamports - record_lamports;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .lamports(record_lamports)
+                .build(),
+            Check::account(&payer).lamports(payer_lamports).build(),
+        ],
+    );
+}
+
+#[test]
+fn update_record_resizes_class_stats() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+    let (_, class_stats_data_updated) = keyed_account_for_class_stats(class, 1, 10);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer: authority,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test_grown"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, class_stats_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class_stats)
+                .data(&class_stats_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class stats account is missing, the record lease is then read in its place
+fn fail_update_record_missing_class_stats() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 1, 4);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let mut instruction = UpdateRecord {
+        authority,
+        payer: authority,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test_grown"),
+    });
+    // Drop the class stats account and keep the lease placeholder
+    instruction.accounts.remove(5);
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn update_record_with_expected_revision() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let record_data = record_with_revision(record_data, 3);
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 4);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordWithRevision {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordWithRevisionInstructionArgs {
+        expected_revision: 3,
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn resize_record_data_only() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"hello world");
+    // Record patched, only the patched bytes and the header bookkeeping change
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"hello there");
+    let record_data_updated = record_with_mutation(record_data_updated, 31, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = ResizeRecordDataOnly {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        lease: None,
+    }
+    .instruction(ResizeRecordDataOnlyInstructionArgs {
+        byte_offset: 6,
+        patch: make_remainder_vec(b"there"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the patch goes past the end of the record data
+fn fail_resize_record_data_only_out_of_bounds() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"hello world");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = ResizeRecordDataOnly {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        lease: None,
+    }
+    .instruction(ResizeRecordDataOnlyInstructionArgs {
+        byte_offset: 8,
+        patch: make_remainder_vec(b"there"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+/// Fails because the updated record account exceeds the maximum record size
+fn fail_update_record_exceeds_max_size() {
+    use crate::state::MAX_RECORD_SIZE;
+
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record, filling `MAX_RECORD_SIZE`
+    let (_, empty_record_data) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"");
+    let data = vec![b'a'; MAX_RECORD_SIZE - empty_record_data.data.len()];
+    let (record, record_data) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", &data);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(&[data.as_slice(), b"a"].concat()),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+/// Fails because the record grows by more than `MAX_PERMITTED_DATA_INCREASE` in one instruction
+fn fail_update_record_exceeds_max_data_increase() {
+    use crate::utils::MAX_PERMITTED_DATA_INCREASE;
+
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(&vec![b'a'; b"test".len() + MAX_PERMITTED_DATA_INCREASE + 1]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the record has been updated since the expected revision
+fn fail_update_record_stale_revision() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let record_data = record_with_revision(record_data, 2);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordWithRevision {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordWithRevisionInstructionArgs {
+        expected_revision: 1,
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::StaleRevision as u32,
+        ))],
+    );
+}
+
+#[test]
+fn record_timestamps() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, false, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let create_instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        class_stats,
+        system_program,
+        authority: None,
+        access_list: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        non_transferable: false,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let update_instruction = UpdateRecord {
+        authority,
+        payer: authority,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 1000;
+
+    let result = mollusk.process_and_validate_instruction(
+        &create_instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data.clone()),
+            (record, Account::default()),
+            (class_stats, Account::default()),
+            (system_program, system_program_data.clone()),
+        ],
+        &[Check::success()],
+    );
+
+    let created_record = result.get_account(&record).expect("Missing record").clone();
+    let created_class_stats = result
+        .get_account(&class_stats)
+        .expect("Missing class stats")
+        .clone();
+    let created = Record::from_bytes(&created_record.data).expect("Invalid record");
+    assert_eq!(created.created_at, 1000);
+    assert_eq!(created.created_at, created.updated_at);
+
+    mollusk.sysvars.clock.unix_timestamp = 2000;
+
+    let result = mollusk.process_and_validate_instruction(
+        &update_instruction,
+        &[
+            (authority, authority_data),
+            (record, created_record),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, created_class_stats),
+        ],
+        &[Check::success()],
+    );
+
+    // The record was counted on creation, its new data length is counted
+    let class_stats_data = ClassStats::from_bytes(
+        &result
+            .get_account(&class_stats)
+            .expect("Missing class stats")
+            .data,
+    )
+    .expect("Invalid class stats");
+    assert_eq!(class_stats_data.record_count, 1);
+    assert_eq!(class_stats_data.total_bytes, 5);
+
+    let updated = Record::from_bytes(&result.get_account(&record).expect("Missing record").data)
+        .expect("Invalid record");
+    assert_eq!(updated.created_at, 1000);
+    assert_eq!(updated.updated_at, 2000);
+    assert!(updated.updated_at > updated.created_at);
+}
+
+#[test]
+/// Fails because the record has expired
+fn fail_update_record_expired() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 2000;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the new record data exceeds the class max record size
+fn fail_update_record_exceeds_max_record_size() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_limits(class_data, 4, 0);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordTooLarge as u32,
+        ))],
+    );
+}
+
+#[test]
+fn update_record_with_metadata() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    // New metadata
+    let new_metadata = Metadata {
+        name: make_u32prefix_string("test2"),
+        symbol: make_u32prefix_string("SRS"),
+        uri: make_u32prefix_string("test"),
+        additional_metadata: vec![],
+    };
+    // Record updated
+    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
+        class,
+        0,
+        OWNER,
+        false,
+        0,
+        "test",
+        Some(&new_metadata.try_to_vec().unwrap()),
+    );
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordTokenizable {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        lease: None,
+        class_stats,
+    }
+    .instruction(UpdateRecordTokenizableInstructionArgs {
+        metadata: Metadata {
+            name: make_u32prefix_string("test2"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because class authority != from authority of the instruction
+fn update_record_with_delegate_incorrect_authority() {
+    // Authority
+    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority: random_authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (random_authority, random_authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+            (class, class_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn update_class_expiry() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordExpiry {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        lease: None,
+    }
+    .instruction(UpdateRecordExpiryInstructionArgs { expiry: 1000 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+    let record_data_updated = record_with_mutation(record_data_updated, 6, authority);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (system_program, system_program_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn grant_record_lease() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = GrantRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+        system_program,
+    }
+    .instruction(GrantRecordLeaseInstructionArgs {
+        lessee: RANDOM_PUBKEY,
+        expires_at: 1000,
+        permissions: 1,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&lease).data(&lease_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the lease is not the lease PDA of the record
+fn fail_grant_record_lease_incorrect_lease() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = GrantRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease: RANDOM_PUBKEY,
+        system_program,
+    }
+    .instruction(GrantRecordLeaseInstructionArgs {
+        lessee: RANDOM_PUBKEY,
+        expires_at: 1000,
+        permissions: 1,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (RANDOM_PUBKEY, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the lease expiry is not in the future
+fn fail_grant_record_lease_already_expired() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, _) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 0, 1);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = GrantRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+        system_program,
+    }
+    .instruction(GrantRecordLeaseInstructionArgs {
+        lessee: RANDOM_PUBKEY,
+        expires_at: 0,
+        permissions: 1,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+/// Fails because the lease is the lease PDA of the record for another lessee
+fn fail_grant_record_lease_other_lessee() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, _) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = GrantRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+        system_program,
+    }
+    .instruction(GrantRecordLeaseInstructionArgs {
+        lessee: NEW_OWNER,
+        expires_at: 1000,
+        permissions: 1,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn create_record_lease() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 5);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+        system_program,
+    }
+    .instruction(CreateRecordLeaseInstructionArgs {
+        lessee: RANDOM_PUBKEY,
+        expiry: 1000,
+        can_update: true,
+        can_freeze: true,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&lease).data(&lease_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the lease allows neither updating nor freezing the record
+fn fail_create_record_lease_without_permission() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, _) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+        system_program,
+    }
+    .instruction(CreateRecordLeaseInstructionArgs {
+        lessee: RANDOM_PUBKEY,
+        expiry: 1000,
+        can_update: false,
+        can_freeze: false,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+/// Fails because the lease expiry is not in the future
+fn fail_create_record_lease_already_expired() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, _) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 0, 1);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+        system_program,
+    }
+    .instruction(CreateRecordLeaseInstructionArgs {
+        lessee: RANDOM_PUBKEY,
+        expiry: 0,
+        can_update: true,
+        can_freeze: false,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn revoke_record_lease() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let instruction = RevokeRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&lease).data(&[]).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+fn renew_record_lease() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+    // Lease renewed
+    let (_, lease_data_renewed) =
+        keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 2000, 1);
+
+    let instruction = RenewRecordLease {
+        authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction(RenewRecordLeaseInstructionArgs { expires_at: 2000 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&lease)
+                .data(&lease_data_renewed.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the renewed lease expiry is not in the future
+fn fail_renew_record_lease_already_expired() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let instruction = RenewRecordLease {
+        authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction(RenewRecordLeaseInstructionArgs { expires_at: 0 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn update_record_with_lease() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    let record_data_updated = record_with_mutation(record_data_updated, 5, lessee);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 1);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority: lessee,
+        payer: lessee,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: Some(lease),
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+            (lease, lease_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the lease does not grant the update expiry permission
+fn fail_update_record_expiry_with_lease_missing_permission() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 1);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordExpiry {
+        authority: lessee,
+        payer: lessee,
+        record,
+        class,
+        system_program,
+        lease: Some(lease),
+    }
+    .instruction(UpdateRecordExpiryInstructionArgs { expiry: 1000 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the record was recreated at the same address after the lease was granted
+fn fail_update_record_with_lease_of_recreated_record() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let record_data = record_with_created_at(record_data, 1000);
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 1);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority: lessee,
+        payer: lessee,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: Some(lease),
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the record was transferred to a new owner after the lease was granted
+fn fail_update_record_with_lease_of_transferred_record() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 1);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority: lessee,
+        payer: lessee,
+        record,
+        class,
+        system_program,
+        class_stats,
+        lease: Some(lease),
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn update_record_expiry_with_lease() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 3);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordExpiry {
+        authority: lessee,
+        payer: lessee,
+        record,
+        class,
+        system_program,
+        lease: Some(lease),
+    }
+    .instruction(UpdateRecordExpiryInstructionArgs { expiry: 1000 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (lease, lease_data),
+        ],
+        &[Check::success()],
+    );
+
+    let updated = Record::from_bytes(&result.get_account(&record).unwrap().data).unwrap();
+    assert_eq!(updated.expiry, 1000);
+}
+
+#[test]
+/// Fails because a lessee cannot expire the record before the end of its lease
+fn fail_update_record_expiry_with_lease_before_lease_end() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 3);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordExpiry {
+        authority: lessee,
+        payer: lessee,
+        record,
+        class,
+        system_program,
+        lease: Some(lease),
+    }
+    .instruction(UpdateRecordExpiryInstructionArgs { expiry: 1 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn expire_record_lease_of_recreated_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let record_data = record_with_created_at(record_data, 1000);
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let instruction = ExpireRecordLease {
+        authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&lease).data(&[]).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+/// Closes the active lease of a deleted record, refunding the class authority
+fn expire_record_lease_of_deleted_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+    // Lease, still active
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let delete_instruction = DeleteRecord {
+        authority: owner,
+        payer: owner,
+        record,
+        class_stats,
+        class: None,
+        token2022_program: None,
+        mint: None,
+    }
+    .instruction();
+
+    let expire_instruction = ExpireRecordLease {
+        authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    let authority_lamports = authority_data.lamports + lease_data.lamports;
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (
+                &delete_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&record).lamports(0).build(),
+                ],
+            ),
+            (
+                &expire_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&lease).data(&[]).lamports(0).build(),
+                    Check::account(&authority)
+                        .lamports(authority_lamports)
+                        .build(),
+                ],
+            ),
+        ],
+        &[
+            (authority, authority_data),
+            (owner, owner_data),
+            (record, record_data),
+            (class_stats, class_stats_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+    );
+}
+
+#[test]
+/// Revokes the lease of a record that no longer exists
+fn revoke_record_lease_of_closed_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record, closed
+    let (record, _) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let instruction = RevokeRecordLease {
+        authority,
+        payer: authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, Account::default()),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&lease).data(&[]).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class is not the class the lease was granted under
+fn fail_expire_record_lease_of_closed_record_other_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Other class
+    let (other_class, other_class_data) =
+        keyed_account_for_class(AUTHORITY, true, false, "other", "test");
+    // Record, closed
+    let (record, _) = keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let instruction = ExpireRecordLease {
+        authority,
+        record,
+        class: other_class,
+        lease,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, Account::default()),
+            (other_class, other_class_data),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the lease is still active over the current record
+fn fail_expire_record_lease_active() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, RANDOM_PUBKEY, 1000, 1);
+
+    let instruction = ExpireRecordLease {
+        authority,
+        record,
+        class,
+        lease,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn transfer_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let record_data_updated = record_with_mutation(record_data_updated, 7, owner);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_record_with_transfer_fee() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class, 5% transfer fee to the authority
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_transfer_fee(class_data, 500, AUTHORITY);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let record_data_updated = record_with_mutation(record_data_updated, 7, owner);
+    // Fee Destination
+    let fee_destination_data = Account::new(1_000_000_000u64, 0, &Pubkey::default());
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: Some(payer),
+        fee_destination: Some(AUTHORITY),
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (payer, payer_data),
+            (AUTHORITY, fee_destination_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+            Check::account(&AUTHORITY)
+                .lamports(1_000_000_000 + 50_000_000)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// The transfer fee is charged on a fixed base of 1 SOL, each basis point being 100_000 lamports
+fn transfer_record_transfer_fee_amounts() {
+    for (transfer_fee_bps, fee) in [(1, 100_000), (250, 25_000_000), (10_000, 1_000_000_000)] {
+        // Owner
+        let (owner, owner_data) = keyed_account_for_owner();
+        // Payer
+        let (payer, payer_data) = keyed_account_for_random_authority();
+        // Class, charging `transfer_fee_bps` to the authority
+        let (class, class_data) = keyed_account_for_class_default();
+        let class_data = class_with_transfer_fee(class_data, transfer_fee_bps, AUTHORITY);
+        // Record
+        let (record, record_data) =
+            keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+        // Fee Destination
+        let fee_destination_data = Account::new(1_000_000_000u64, 0, &Pubkey::default());
+        //System Program
+        let (system_program, system_program_data) = keyed_account_for_system_program();
+
+        let instruction = TransferRecord {
+            authority: owner,
+            record,
+            class: Some(class),
+            payer: Some(payer),
+            fee_destination: Some(AUTHORITY),
+            system_program,
+        }
+        .instruction(TransferRecordInstructionArgs {
+            new_owner: NEW_OWNER,
+        });
+
+        let mollusk = Mollusk::new(
+            &SOLANA_RECORD_SERVICE_ID,
+            "../target/deploy/solana_record_service",
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (owner, owner_data),
+                (record, record_data),
+                (class, class_data),
+                (payer, payer_data),
+                (AUTHORITY, fee_destination_data),
+                (system_program, system_program_data),
+            ],
+            &[
+                Check::success(),
+                Check::account(&AUTHORITY)
+                    .lamports(1_000_000_000 + fee)
+                    .build(),
+                Check::account(&payer)
+                    .lamports(100_000_000_000 - fee)
+                    .build(),
+            ],
+        );
+    }
+}
+
+#[test]
+/// Transfers a record with only the required accounts, without the class and the fee accounts
+fn transfer_record_without_optional_accounts() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let record_data_updated = record_with_mutation(record_data_updated, 7, owner);
+    //System Program
+    let (system_program, _system_program_data) = keyed_account_for_system_program();
+
+    let mut instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: None,
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+    instruction.accounts.truncate(2);
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data)],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the fee destination is not the fee destination of the class
+fn fail_transfer_record_fee_incorrect_destination() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class, 5% transfer fee to the authority
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_transfer_fee(class_data, 500, AUTHORITY);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Fee Destination
+    let fee_destination_data = Account::new(1_000_000_000u64, 0, &Pubkey::default());
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: Some(payer),
+        fee_destination: Some(NEW_OWNER),
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (payer, payer_data),
+            (NEW_OWNER, fee_destination_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the class charges a transfer fee and the fee accounts are not provided
+fn fail_transfer_record_fee_missing_destination() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class, 5% transfer fee to the authority
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_transfer_fee(class_data, 500, AUTHORITY);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, _system_program_data) = keyed_account_for_system_program();
+
+    let mut instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+    instruction.accounts.truncate(3);
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+/// Fails because the class charges a transfer fee and the payer and the fee destination are
+/// omitted
+fn fail_transfer_record_fee_omitted_accounts() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class, 5% transfer fee to the authority
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_transfer_fee(class_data, 500, AUTHORITY);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+/// Fails because the record is non-transferable
+fn fail_transfer_record_non_transferable() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let record_data = record_non_transferable(record_data);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the new owner is the zero address
+fn fail_transfer_record_zero_address() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::default(),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn transfer_record_with_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let record_data_updated = record_with_mutation(record_data_updated, 7, authority);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record is frozen
+fn fail_transfer_record_frozen() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class: Some(class),
+        payer: None,
+        fee_destination: None,
+        system_program,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn clone_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Source Record
+    let (source_record, source_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 1000, b"test", b"test");
+    // Destination Record
+    let (destination_record, destination_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"clone", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CloneRecord {
+        authority: owner,
+        payer: owner,
+        source_record,
+        destination_record,
+        class,
+        class_stats,
+        system_program,
+    }
+    .instruction(CloneRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"clone"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (source_record, source_record_data.clone()),
+            (destination_record, Account::default()),
+            (class, class_data),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&source_record)
+                .data(&source_record_data.data)
+                .build(),
+            Check::account(&destination_record)
+                .data(&destination_record_data.data)
+                .build(),
+            Check::account(&class_stats)
+                .data(&class_stats_data.data)
+                .build(),
+        ],
+    );
+
+    assert!(result.compute_units_consumed <= CLONE_RECORD_MAX_CU);
+}
+
+#[test]
+/// Fails because the destination record is not the record PDA of the class and seed
+fn fail_clone_record_incorrect_destination_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Source Record
+    let (source_record, source_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 1000, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 1, 4);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CloneRecord {
+        authority: owner,
+        payer: owner,
+        source_record,
+        destination_record: RANDOM_PUBKEY,
+        class,
+        class_stats,
+        system_program,
+    }
+    .instruction(CloneRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"clone"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (source_record, source_record_data),
+            (RANDOM_PUBKEY, Account::default()),
+            (class, class_data),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the longer seed makes the destination record exceed `MAX_RECORD_SIZE`
+fn fail_clone_record_too_large() {
+    use crate::state::MAX_RECORD_SIZE;
+
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Source Record, filling `MAX_RECORD_SIZE`
+    let (_, empty_record_data) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"");
+    let data = vec![b'a'; MAX_RECORD_SIZE - empty_record_data.data.len()];
+    let (source_record, source_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", &data);
+    // Destination Record
+    let (destination_record, _) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"clone", &data);
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 1, data.len() as u64);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CloneRecord {
+        authority: owner,
+        payer: owner,
+        source_record,
+        destination_record,
+        class,
+        class_stats,
+        system_program,
+    }
+    .instruction(CloneRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"clone"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (source_record, source_record_data),
+            (destination_record, Account::default()),
+            (class, class_data),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+fn rename_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 1000, b"test", b"test");
+    // New Record
+    let (new_record, new_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 1000, b"renamed", b"test");
+    let new_record_data = record_with_mutation(new_record_data, 24, owner);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RenameRecord {
+        authority: owner,
+        payer: owner,
+        old_record,
+        new_record,
+        class,
+        system_program,
+    }
+    .instruction(RenameRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"renamed"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&old_record).lamports(0).build(),
+            Check::account(&new_record)
+                .data(&new_record_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the new record is not the record PDA of the class and new seed
+fn fail_rename_record_incorrect_new_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 1000, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RenameRecord {
+        authority: owner,
+        payer: owner,
+        old_record,
+        new_record: RANDOM_PUBKEY,
+        class,
+        system_program,
+    }
+    .instruction(RenameRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"renamed"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (old_record, old_record_data),
+            (RANDOM_PUBKEY, Account::default()),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the longer seed makes the new record exceed `MAX_RECORD_SIZE`
+fn fail_rename_record_too_large() {
+    use crate::state::MAX_RECORD_SIZE;
+
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Old Record, filling `MAX_RECORD_SIZE`
+    let (_, empty_record_data) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"");
+    let data = vec![b'a'; MAX_RECORD_SIZE - empty_record_data.data.len()];
+    let (old_record, old_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", &data);
+    // New Record
+    let (new_record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"renamed", &data);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RenameRecord {
+        authority: owner,
+        payer: owner,
+        old_record,
+        new_record,
+        class,
+        system_program,
+    }
+    .instruction(RenameRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"renamed"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+/// Fails because the record is tokenized
+fn fail_rename_record_tokenized() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(class, 1, owner, false, 0, b"test", b"test");
+    // New Record
+    let (new_record, _) = keyed_account_for_record(class, 1, owner, false, 0, b"renamed", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RenameRecord {
+        authority: owner,
+        payer: owner,
+        old_record,
+        new_record,
+        class,
+        system_program,
+    }
+    .instruction(RenameRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"renamed"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::AlreadyTokenized as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the class is frozen
+fn fail_rename_record_frozen_class() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, false, true, "test", "test");
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // New Record
+    let (new_record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"renamed", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RenameRecord {
+        authority: owner,
+        payer: owner,
+        old_record,
+        new_record,
+        class,
+        system_program,
+    }
+    .instruction(RenameRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"renamed"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the class is permissioned and the owner is not the class authority
+fn fail_clone_record_permissioned_class() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Source Record
+    let (source_record, source_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Destination Record
+    let (destination_record, _) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"clone", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CloneRecord {
+        authority: owner,
+        payer: owner,
+        source_record,
+        destination_record,
+        class,
+        class_stats,
+        system_program,
+    }
+    .instruction(CloneRecordInstructionArgs {
+        seed: make_u8prefix_vec_u8(b"clone"),
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (source_record, source_record_data),
+            (destination_record, Account::default()),
+            (class, class_data),
+            (class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn delete_record() {
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    // Records of different sizes return different amounts of lamports
+    let datas: [&[u8]; 3] = [b"", b"test", &[0xaa; 1024]];
+
+    for data in datas {
+        // Owner
+        let (owner, owner_data) = keyed_account_for_owner();
+        // Payer
+        let (payer, payer_data) = keyed_account_for_random_authority();
+        // Class
+        let (class, _class_data) = keyed_account_for_class_default();
+        // Record
+        let (record, mut record_data) =
+            keyed_account_for_record(class, 0, OWNER, false, 0, b"test", data);
+        record_data.lamports = mollusk.sysvars.rent.minimum_balance(record_data.data.len());
+        // Class Stats
+        let (class_stats, class_stats_data) =
+            keyed_account_for_class_stats(class, 1, data.len() as u64);
+        let (_, updated_class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+        let instruction = DeleteRecord {
+            authority: owner,
+            payer,
+            record,
+            class_stats,
+            class: None,
+            token2022_program: None,
+            mint: None,
+        }
+        .instruction();
+
+        // The record is closed, all of its lamports go to the payer
+        let payer_lamports = payer_data.lamports + record_data.lamports;
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (owner, owner_data),
+                (payer, payer_data),
+                (record, record_data),
+                (class_stats, class_stats_data),
+            ],
+            &[
+                Check::success(),
+                Check::account(&record).data(&[0xff]).lamports(0).build(),
+                Check::account(&payer).lamports(payer_lamports).build(),
+                Check::account(&class_stats)
+                    .data(&updated_class_stats_data.data)
+                    .build(),
+            ],
+        );
+    }
+}
+
+#[test]
+/// Fails because the stats account of the class of the record has not been created
+fn fail_delete_record_without_class_stats() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats, never created
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer,
+        record,
+        class_stats,
+        class: None,
+        token2022_program: None,
+        mint: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn batch_delete_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Records
+    let records: Vec<(Pubkey, Account)> = [b"test0", b"test1", b"test2"]
+        .iter()
+        .map(|seed| keyed_account_for_record(class, 0, OWNER, false, 0, *seed, b"test"))
+        .collect();
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 3, 12);
+    let (_, updated_class_stats_data) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = BatchDeleteRecord {
+        authority: owner,
+        payer,
+        class_stats,
+        class: None,
+    }
+    .instruction_with_remaining_accounts(
+        &records
+            .iter()
+            .map(|(record, _)| AccountMeta::new(*record, false))
+            .collect::<Vec<_>>(),
+    );
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    let mut accounts = vec![
+        (owner, owner_data),
+        (payer, payer_data),
+        (class_stats, class_stats_data),
+    ];
+    accounts.extend(records.iter().cloned());
+
+    let mut checks = vec![
+        Check::success(),
+        Check::account(&class_stats)
+            .data(&updated_class_stats_data.data)
+            .build(),
+    ];
+    checks.extend(
+        records
+            .iter()
+            .map(|(record, _)| Check::account(record).data(&[0xff]).build()),
+    );
+
+    mollusk.process_and_validate_instruction(&instruction, &accounts, &checks);
+}
+
+#[test]
+/// Fails because the authority does not own the second record of the batch
+fn fail_batch_delete_record_not_owner() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Records
+    let (record_0, record_0_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test0", b"test");
+    let (record_1, record_1_data) =
+        keyed_account_for_record(class, 0, RANDOM_PUBKEY, false, 0, b"test1", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 2, 8);
+
+    let instruction = BatchDeleteRecord {
+        authority: owner,
+        payer,
+        class_stats,
+        class: None,
+    }
+    .instruction_with_remaining_accounts(&[
+        AccountMeta::new(record_0, false),
+        AccountMeta::new(record_1, false),
+    ]);
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (class_stats, class_stats_data),
+            (record_0, record_0_data),
+            (record_1, record_1_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotRecordOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the batch holds the same record twice
+fn fail_batch_delete_record_duplicate() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, class_stats_data) = keyed_account_for_class_stats(class, 1, 4);
+
+    let instruction = BatchDeleteRecord {
+        authority: owner,
+        payer,
+        class_stats,
+        class: None,
+    }
+    .instruction_with_remaining_accounts(&[
+        AccountMeta::new(record, false),
+        AccountMeta::new(record, false),
+    ]);
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (class_stats, class_stats_data),
+            (record, record_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn create_record_snapshot() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let record_data = record_with_revision(record_data, 2);
+    // Snapshot
+    let (snapshot, _bump) = Pubkey::find_program_address(
+        &[b"snapshot", record.as_ref(), &2u32.to_le_bytes()],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let snapshot_data = RecordSnapshot {
+        discriminator: 5,
+        record,
+        revision: 2,
+        slot: 42,
+        unix_timestamp: 1000,
+        data_hash: solana_program::hash::hash(b"test").to_bytes(),
+    }
+    .try_to_vec()
+    .expect("Invalid snapshot");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordSnapshot {
+        authority: owner,
+        payer: owner,
+        record,
+        snapshot,
+        system_program,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.slot = 42;
+    mollusk.sysvars.clock.unix_timestamp = 1000;
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data.clone()),
+            (snapshot, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&snapshot).data(&snapshot_data).build(),
+        ],
+    );
+
+    let parsed_snapshot = RecordSnapshot::try_from(
+        result
+            .get_account(&snapshot)
+            .expect("Missing snapshot")
+            .data
+            .as_slice(),
+    )
+    .expect("Invalid snapshot");
+    assert_eq!(parsed_snapshot.revision, 2);
+}
+
+#[test]
+/// Fails because the snapshot is not the snapshot PDA of the record revision
+fn fail_create_record_snapshot_incorrect_snapshot() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let record_data = record_with_revision(record_data, 2);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordSnapshot {
+        authority: owner,
+        payer: owner,
+        record,
+        snapshot: RANDOM_PUBKEY,
+        system_program,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (RANDOM_PUBKEY, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the authority is not the record owner
+fn fail_create_record_snapshot_not_owner() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Snapshot
+    let (snapshot, _bump) = Pubkey::find_program_address(
+        &[b"snapshot", record.as_ref(), &0u32.to_le_bytes()],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordSnapshot {
+        authority,
+        payer: authority,
+        record,
+        snapshot,
+        system_program,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (snapshot, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotRecordOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn reclaim_expired_record() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = ReclaimExpiredRecord {
+        payer,
+        record,
+        class_stats,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 2000;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (record, record_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).lamports(0).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record has not expired yet
+fn fail_reclaim_expired_record_not_expired() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = ReclaimExpiredRecord {
+        payer,
+        record,
+        class_stats,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (record, record_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because an expiry of 0 means the record never expires
+fn fail_reclaim_expired_record_no_expiry() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = ReclaimExpiredRecord {
+        payer,
+        record,
+        class_stats,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (record, record_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn delete_record_with_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+
+    let instruction = DeleteRecord {
+        authority,
+        payer,
+        record,
+        class_stats,
+        class: Some(class),
+        token2022_program: None,
+        mint: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class_stats, Account::default()),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+        ],
+    );
+}
+
+#[test]
+fn delete_tokenized_record_with_no_supply() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _) = keyed_account_for_class_default();
+    // Mint
+    let (record, _bump) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mut mint_data) = keyed_account_for_mint(record);
+    mint_data.data_as_mut_slice()[..MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY.len()]
+        .copy_from_slice(MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY);
+    // Record
+    let (_, record_data) = keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    // Token2022 Program
+    let (token2022_program, token2022_program_data) =
+        mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer: owner,
+        record,
+        class_stats,
+        class: None,
+        token2022_program: Some(token2022_program),
+        mint: Some(mint),
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class_stats, Account::default()),
+            (mint, mint_data),
+            (token2022_program, token2022_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+            Check::account(&mint).data(&[]).build(),
+        ],
+    );
+}
+
+#[test]
+fn extend_record_expiry() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 100, b"test", b"test");
+    // Record extended
+    let (_, record_data_extended) =
+        keyed_account_for_record(class, 0, owner, false, 200, b"test", b"test");
+    let record_data_extended = record_with_mutation(record_data_extended, 23, owner);
+
+    let instruction = ExtendRecordExpiry {
+        authority: owner,
+        record,
+        class: None,
+    }
+    .instruction(ExtendRecordExpiryInstructionArgs { new_expiry: 200 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data)],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_extended.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the new expiry is earlier than the current expiry
+fn fail_extend_record_expiry_shorten() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 100, b"test", b"test");
+
+    let instruction = ExtendRecordExpiry {
+        authority: owner,
+        record,
+        class: None,
+    }
+    .instruction(ExtendRecordExpiryInstructionArgs { new_expiry: 50 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data)],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+/// Fails because the new expiry is already in the past
+fn fail_extend_record_expiry_past() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 1, b"test", b"test");
+
+    let instruction = ExtendRecordExpiry {
+        authority: owner,
+        record,
+        class: None,
+    }
+    .instruction(ExtendRecordExpiryInstructionArgs { new_expiry: 1 });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 1000;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data)],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+/// Fails because the record is tokenized
+fn fail_extend_record_expiry_tokenized() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, owner, false, 100, b"test", b"test");
+
+    let instruction = ExtendRecordExpiry {
+        authority: owner,
+        record,
+        class: None,
+    }
+    .instruction(ExtendRecordExpiryInstructionArgs { new_expiry: 200 });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data)],
+        &[Check::err(ProgramError::Custom(
+            SrsError::AlreadyTokenized as u32,
+        ))],
+    );
+}
+
+#[test]
+fn freeze_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let record_data_frozen = record_with_mutation(record_data_frozen, 9, authority);
+
+    let instruction = FreezeRecord {
+        authority,
+        record,
+        class,
+        lease: None,
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record has expired
+fn fail_freeze_record_expired() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+
+    let instruction = FreezeRecord {
+        authority,
+        record,
+        class,
+        lease: None,
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 2000;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn freeze_record_already_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    // Record frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let record_data_frozen = record_with_mutation(record_data_frozen, 9, authority);
+
+    let instruction = FreezeRecord {
+        authority,
+        record,
+        class,
+        lease: None,
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn freeze_record_with_lease() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let record_data_frozen = record_with_mutation(record_data_frozen, 9, lessee);
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 4);
+
+    let instruction = FreezeRecord {
+        authority: lessee,
+        record,
+        class,
+        lease: Some(lease),
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the lease does not grant the freeze permission
+fn fail_freeze_record_with_lease_missing_permission() {
+    // Lessee
+    let (lessee, lessee_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Lease
+    let (lease, lease_data) = keyed_account_for_record_lease(class, record, lessee, 1000, 1);
+
+    let instruction = FreezeRecord {
+        authority: lessee,
+        record,
+        class,
+        lease: Some(lease),
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (lessee, lessee_data),
+            (record, record_data),
+            (class, class_data),
+            (lease, lease_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0]`,
+// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255]`
+
+#[test]
+fn mint_record_token() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+            Check::account(&group).rent_exempt().build(),
+        ],
+    );
+
+    assert!(result.compute_units_consumed <= MINT_TOKENIZED_RECORD_MAX_CU);
+}
+
+#[test]
+/// Fails because the group is not the group PDA of the class
+fn fail_mint_record_token_incorrect_group() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group of another class
+    let (group, _) = keyed_account_for_group(RANDOM_PUBKEY);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the associated token program is not the Associated Token program
+fn fail_mint_record_token_incorrect_associated_token_program() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+
+    // Another program in place of the Associated Token program
+    let associated_token_program = Pubkey::new_unique();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, Account::default()),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::IncorrectProgramId)],
+    );
+}
+
+#[test]
+fn mint_record_token_non_transferable() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    let record_data = record_non_transferable(record_data);
+    // Mint, with the non-transferable extension after the close authority
+    let (mint, mint_data) = keyed_account_for_mint(record);
+    let extension_offset = MINT_DATA_WITH_EXTENSIONS.len() + MINT_CLOSE_AUTHORITY_EXTENSION.len();
+    let mut expected_mint_data = mint_data.data[..extension_offset].to_vec();
+    expected_mint_data.extend_from_slice(MINT_NON_TRANSFERABLE_EXTENSION);
+    expected_mint_data.extend_from_slice(&mint_data.data[extension_offset..]);
+    // Group
+    let (group, _group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&expected_mint_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn mint_record_token_to_recipient() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Recipient
+    let recipient = NEW_OWNER;
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA of the recipient
+    let (token_account, token_account_data) = keyed_account_for_token(recipient, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: Some(recipient),
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+            (recipient, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because minting to another recipient in a permissioned class requires the class authority
+fn fail_mint_record_token_to_recipient_permissioned_class() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Recipient
+    let recipient = NEW_OWNER;
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA of the recipient
+    let (token_account, _) = keyed_account_for_token(recipient, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: Some(recipient),
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+            (recipient, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn mint_record_token_with_additional_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
+        class, 0, owner, false, 0, "test",
+    );
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint_with_additional_metadata(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+            Check::account(&mint).rent_exempt().build(),
+        ],
+    );
+}
+
+#[test]
+fn mint_record_token_with_multiple_additional_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
+            class, 0, owner, false, 0, "test",
+        );
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint_with_multiple_additional_metadata(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+            Check::account(&mint).rent_exempt().build(),
+        ],
+    );
+}
+
+#[test]
+fn mint_record_token_with_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: authority,
+        authority,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (authority, authority_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class group has already reached its max size
+fn fail_mint_record_token_class_capacity_exceeded() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _mint_data) = keyed_account_for_mint(record);
+    // Group with a current size of 1 and a max size of 1
+    let (group, mut group_data) = keyed_account_for_group(class);
+    let group_extension_offset = GROUP_MINT_DATA_WITH_EXTENSIONS.len()
+        + MINT_GROUP_POINTER_EXTENSION.len()
+        + MINT_GROUP_METADATA_POINTER_EXTENSION.len();
+    // TLV header, then update authority, mint, size and max size
+    let max_size_offset = group_extension_offset + 4 + 32 + 32 + 8;
+    group_data.data[max_size_offset..max_size_offset + 8].copy_from_slice(&1u64.to_le_bytes());
+    assert_eq!(
+        unsafe { crate::token2022::Mint::get_group_size_unchecked(&group_data.data) },
+        Ok((1, 1))
+    );
+    // ATA
+    let (token_account, _token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, group_data),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::ClassCapacityExceeded as u32,
+        ))],
+    );
+}
+
+#[test]
+fn freeze_tokenized_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // ATA updated
+    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (class, class_data),
+            (token2022, token2022_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&token_account)
+                .data(&token_account_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record has expired
+fn fail_freeze_tokenized_record_expired() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 1000, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.sysvars.clock.unix_timestamp = 2000;
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (class, class_data),
+            (token2022, token2022_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_freeze_tokenized_record_incorrect_mint() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint of another record, set as the record owner
+    let (other_record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"other"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(other_record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (class, class_data),
+            (token2022, token2022_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn freeze_tokenized_record_already_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // ATA updated
+    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let checks = [
+        Check::success(),
+        Check::account(&token_account)
+            .data(&token_account_data_updated.data)
+            .build(),
+    ];
+
+    // Freezing a second time succeeds and leaves the token account frozen
+    mollusk.process_and_validate_instruction_chain(
+        &[(&instruction, &checks), (&instruction, &checks)],
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (class, class_data),
+            (token2022, token2022_data),
+        ],
+    );
+}
+
+#[test]
+fn freeze_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // ATA updated
+    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&token_account)
+                .data(&token_account_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_tokenized_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::success()],
+    );
+
+    assert!(result.compute_units_consumed <= TRANSFER_TOKENIZED_RECORD_MAX_CU);
+}
+
+#[test]
+/// Transfers with only the baseline accounts and data, without the class, royalty and fee accounts
+fn transfer_tokenized_record_without_optional_accounts() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    //System Program
+    let (system_program, _) = keyed_account_for_system_program();
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let mut instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: None,
+        royalty_config: None,
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    // Only pass the required accounts and the discriminator
+    instruction.accounts.truncate(6);
+    assert_eq!(instruction.data, vec![12]);
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+/// The mint bump is cached in the record, so transferring the token of a record whose mint bump
+/// is far from 255 costs as much as for a mint bump of 255, while searching for the bump would
+/// cost one more derivation for each bump tried
+fn transfer_tokenized_record_cached_mint_bump_compute_units() {
+    // Class
+    let (class, _) = keyed_account_for_class_default();
+
+    let record_address = |seed: &[u8]| {
+        Pubkey::find_program_address(
+            &[b"record", class.as_ref(), seed],
+            &SOLANA_RECORD_SERVICE_ID,
+        )
+        .0
+    };
+    let mint_bump = |seed: &[u8]| {
+        Pubkey::find_program_address(
+            &[b"mint", record_address(seed).as_ref()],
+            &SOLANA_RECORD_SERVICE_ID,
+        )
+        .1
+    };
+
+    // The mint of the "test" record has a bump of 255, find a seed of the same length whose
+    // mint bump is at least 5 bumps lower
+    assert_eq!(mint_bump(b"test"), 255);
+    let low_bump_seed = (0..10_000u32)
+        .map(|i| format!("{i:04}"))
+        .find(|seed| mint_bump(seed.as_bytes()) <= 250)
+        .unwrap();
+
+    let compute_units = |seed: &[u8]| {
+        // Owner
+        let (owner, owner_data) = keyed_account_for_owner();
+        // Mint, the fixture being the mint of the "test" record
+        let (default_mint, _) = keyed_account_for_mint(record_address(b"test"));
+        let (mint, mut mint_data) = keyed_account_for_mint(record_address(seed));
+        let mint_account_data = mint_data.data_as_mut_slice();
+        for offset in 0..mint_account_data.len() - 32 {
+            if mint_account_data[offset..offset + 32] == default_mint.to_bytes() {
+                mint_account_data[offset..offset + 32].copy_from_slice(mint.as_ref());
+            }
+        }
+        // Record
+        let (record, record_data) =
+            keyed_account_for_record(class, 1, mint, false, 0, seed, b"test");
+        // ATA
+        let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+        // New ATA
+        let (new_token_account, new_token_account_data) =
+            keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+        //System Program
+        let (system_program, _) = keyed_account_for_system_program();
+
+        let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+        let mut instruction = TransferTokenizedRecord {
+            authority: owner,
+            record,
+            mint,
+            token_account,
+            new_token_account,
+            token2022,
+            class: None,
+            royalty_config: None,
+            payer: None,
+            destination: None,
+            system_program,
+            fee_destination: None,
+        }
+        .instruction(TransferTokenizedRecordInstructionArgs {
+            token_value_lamports: None,
+        });
+
+        instruction.accounts.truncate(6);
+
+        let mut mollusk = Mollusk::new(
+            &SOLANA_RECORD_SERVICE_ID,
+            "../target/deploy/solana_record_service",
+        );
+
+        mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+        mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+        mollusk
+            .process_and_validate_instruction(
+                &instruction,
+                &[
+                    (owner, owner_data),
+                    (record, record_data),
+                    (mint, mint_data),
+                    (token_account, token_account_data),
+                    (new_token_account, new_token_account_data),
+                    (token2022, token2022_data),
+                ],
+                &[Check::success()],
+            )
+            .compute_units_consumed
+    };
+
+    let highest_bump_compute_units = compute_units(b"test");
+    let low_bump_compute_units = compute_units(low_bump_seed.as_bytes());
+
+    assert!(highest_bump_compute_units <= TRANSFER_TOKENIZED_RECORD_MAX_CU);
+    // Searching for the low bump would cost at least 5 more derivations of ~1500 CUs
+    assert!(low_bump_compute_units.abs_diff(highest_bump_compute_units) < 1_500);
+}
+
+#[test]
+/// Fails because the mint of the record does not have a supply of exactly 1
+fn fail_transfer_tokenized_record_not_nft_mint() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mut mint_data) = keyed_account_for_mint(record_address);
+    // Supply of 2 tokens
+    mint_data.data[36] = 2;
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the record is non-transferable
+fn fail_transfer_tokenized_record_non_transferable() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    let record_data = record_non_transferable(record_data);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the record is non-transferable
+fn fail_transfer_tokenized_record_non_transferable() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 1000, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.sysvars.clock.unix_timestamp = 2000;
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::RecordExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn create_royalty_config() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Royalty Config
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_royalty_config(class, 500, RANDOM_PUBKEY);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRoyaltyConfig {
+        authority,
+        payer: authority,
+        class,
+        royalty_config,
+        system_program,
+    }
+    .instruction(CreateRoyaltyConfigInstructionArgs {
+        basis_points: 500,
+        destination: RANDOM_PUBKEY,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (royalty_config, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&royalty_config)
+                .data(&royalty_config_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the royalty config is not the royalty config PDA of the class
+fn fail_create_royalty_config_incorrect_royalty_config() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRoyaltyConfig {
+        authority,
+        payer: authority,
+        class,
+        royalty_config: RANDOM_PUBKEY,
+        system_program,
+    }
+    .instruction(CreateRoyaltyConfigInstructionArgs {
+        basis_points: 500,
+        destination: RANDOM_PUBKEY,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (RANDOM_PUBKEY, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the royalty exceeds 10000 basis points
+fn fail_create_royalty_config_basis_points_too_high() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Royalty Config
+    let (royalty_config, _) = keyed_account_for_royalty_config(class, 10_001, RANDOM_PUBKEY);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRoyaltyConfig {
+        authority,
+        payer: authority,
+        class,
+        royalty_config,
+        system_program,
+    }
+    .instruction(CreateRoyaltyConfigInstructionArgs {
+        basis_points: 10_001,
+        destination: RANDOM_PUBKEY,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (royalty_config, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn update_royalty_config() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Royalty Config
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_royalty_config(class, 500, RANDOM_PUBKEY);
+    // Royalty Config updated
+    let (_, royalty_config_data_updated) = keyed_account_for_royalty_config(class, 250, NEW_OWNER);
+
+    let instruction = UpdateRoyaltyConfig {
+        authority,
+        class,
+        royalty_config,
+    }
+    .instruction(UpdateRoyaltyConfigInstructionArgs {
+        basis_points: 250,
+        destination: NEW_OWNER,
+    });
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&royalty_config)
+                .data(&royalty_config_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_tokenized_record_with_royalty() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+    // Royalty Config, 5% to the destination
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_royalty_config(class, 500, NEW_OWNER);
+    // Destination
+    let destination_data = Account::new(1_000_000_000u64, 0, &Pubkey::default());
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: Some(payer),
+        destination: Some(NEW_OWNER),
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: Some(1_000_000),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (payer, payer_data),
+            (NEW_OWNER, destination_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&NEW_OWNER)
+                .lamports(1_000_000_000 + 50_000)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_tokenized_record_with_transfer_fee() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class, 5% transfer fee to the authority
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_transfer_fee(class_data, 500, AUTHORITY);
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+    // Fee Destination
+    let fee_destination_data = Account::new(1_000_000_000u64, 0, &Pubkey::default());
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: Some(payer),
+        destination: None,
+        system_program,
+        fee_destination: Some(AUTHORITY),
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (payer, payer_data),
+            (system_program, system_program_data),
+            (AUTHORITY, fee_destination_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&AUTHORITY)
+                .lamports(1_000_000_000 + 50_000_000)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class charges a transfer fee and the fee accounts are not provided
+fn fail_transfer_tokenized_record_fee_missing_destination() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class, 5% transfer fee to the authority
+    let (class, class_data) = keyed_account_for_class_default();
+    let class_data = class_with_transfer_fee(class_data, 500, AUTHORITY);
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+/// Fails because the class charges a royalty and the royalty accounts are not provided
+fn fail_transfer_tokenized_record_royalty_missing_destination() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+    // Royalty Config, 5% to the destination
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_royalty_config(class, 500, NEW_OWNER);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: Some(1_000_000),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+/// Fails because the class is not the class of the record
+fn fail_transfer_tokenized_record_royalty_incorrect_class() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Other class
+    let (other_class, other_class_data) =
+        keyed_account_for_class(AUTHORITY, false, false, "other", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+    // Royalty Config of the other class, 5% to the authority
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_royalty_config(other_class, 500, AUTHORITY);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(other_class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: Some(1_000_000),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (other_class, other_class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+/// Fails because the royalty config is not the royalty config PDA of the class
+fn fail_transfer_tokenized_record_royalty_incorrect_royalty_config() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(RANDOM_PUBKEY),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: Some(1_000_000),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (RANDOM_PUBKEY, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn transfer_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Royalty Config, not created for the class
+    let (royalty_config, royalty_config_data) =
+        keyed_account_for_uninitialized_royalty_config(class);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class: Some(class),
+        royalty_config: Some(royalty_config),
+        payer: None,
+        destination: None,
+        system_program,
+        fee_destination: None,
+    }
+    .instruction(TransferTokenizedRecordInstructionArgs {
+        token_value_lamports: None,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+            (royalty_config, royalty_config_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn burn_tokenized_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = BurnTokenizedRecord {
+        authority: owner,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+        ],
+        &[
+            Check::success(),
+            // The mint is closed and its rent returned
+            Check::account(&mint).lamports(0).space(0).build(),
+        ],
+    );
+
+    assert!(result.compute_units_consumed <= BURN_TOKENIZED_RECORD_MAX_CU);
+}
+
+#[test]
+fn fail_burn_tokenized_record_incorrect_mint() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Mint of another record, set as the record owner
+    let (other_record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"other"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(other_record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = BurnTokenizedRecord {
+        authority: owner,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn burn_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = BurnTokenizedRecord {
+        authority,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class: Some(class),
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn mint_and_burn_tokenized_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let mint_instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let burn_instruction = BurnTokenizedRecord {
+        authority: owner,
+        payer: owner,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (&mint_instruction, &[Check::success()]),
+            (&burn_instruction, &[Check::success()]),
+        ],
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+    );
+}
+
+#[test]
+fn mint_and_burn_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let mint_instruction = MintTokenizedRecord {
+        owner,
+        payer: authority,
+        authority,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let burn_instruction = BurnTokenizedRecord {
+        authority,
+        payer: authority,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class: Some(class),
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (&mint_instruction, &[Check::success()]),
+            (&burn_instruction, &[Check::success()]),
+        ],
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (authority, authority_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+    );
+}
+
+#[test]
+fn update_tokenized_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let burn_instruction = BurnTokenizedRecord {
+        authority,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class: Some(class),
+    }
+    .instruction();
+
+    // New metadata
+    let new_metadata = Metadata {
+        name: make_u32prefix_string("test2"),
+        symbol: make_u32prefix_string("SRS"),
+        uri: make_u32prefix_string("test"),
+        additional_metadata: vec![],
+    };
+
+    // Record updated
+    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
+        class,
+        0,
+        OWNER,
+        false,
+        0,
+        "test",
+        Some(&new_metadata.try_to_vec().unwrap()),
+    );
+    let record_data_updated = record_with_mutation(record_data_updated, 13, authority);
+    let record_data_updated = record_with_mutation(record_data_updated, 5, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let update_instruction = UpdateRecordTokenizable {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        lease: None,
+        class_stats,
+    }
+    .instruction(UpdateRecordTokenizableInstructionArgs {
+        metadata: Metadata {
+            name: make_u32prefix_string("test2"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // Associated Token Program
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+
+    // New Mint
+    let (_, new_mint_data) = keyed_account_for_updated_mint(record);
+
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+
+    let mint_instruction = MintTokenizedRecord {
+        owner,
+        payer: authority,
+        authority,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        recipient: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (&burn_instruction, &[Check::success()]),
+            (
+                &update_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&record)
+                        .data(&record_data_updated.data)
+                        .build(),
+                ],
+            ),
+            (
+                &mint_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&mint).data(&new_mint_data.data).build(),
+                ],
+            ),
         ],
         &[
             (owner, owner_data),
+            (payer, payer_data),
+            (authority, authority_data),
             (record, record_data),
-            (mint, Account::default()),
+            (mint, mint_data),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
+            (group, group_data),
+            (token_account, token_account_data),
             (associated_token_program, associated_token_program_data),
             (token2022, token2022_data),
             (system_program, system_program_data),
+            (class_stats, Account::default()),
         ],
     );
 }
 
 #[test]
-fn mint_and_burn_tokenized_record_delegate() {
+fn update_tokenized_record_metadata() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 1, mint, false, 0, "test", None);
+
+    // New metadata
+    let new_metadata = Metadata {
+        name: make_u32prefix_string("test2"),
+        symbol: make_u32prefix_string("SRS"),
+        uri: make_u32prefix_string("test"),
+        additional_metadata: vec![],
+    };
+
+    // Record updated
+    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
+        class,
+        1,
+        mint,
+        false,
+        0,
+        "test",
+        Some(&new_metadata.try_to_vec().unwrap()),
+    );
+    let record_data_updated = record_with_mutation(record_data_updated, 26, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateTokenizedRecordMetadata {
+        authority,
+        payer,
+        record,
+        mint,
+        class,
+        token2022,
+        system_program,
+        class_stats,
+    }
+    .instruction(UpdateTokenizedRecordMetadataInstructionArgs {
+        expected_revision: 0,
+        metadata: new_metadata,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (class, class_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+
+    // The mint metadata follows the record data
+    assert!(result
+        .get_account(&mint)
+        .unwrap()
+        .data
+        .windows(MINT_METADATA_EXTENSION_UPDATED.len())
+        .any(|extension| extension == MINT_METADATA_EXTENSION_UPDATED));
+}
+
+#[test]
+/// Fails because the token holder is not the class authority
+fn fail_update_tokenized_record_metadata_not_class_authority() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
     // Record
     let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+        keyed_account_for_record_with_metadata(class, 1, mint, false, 0, "test", None);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateTokenizedRecordMetadata {
+        authority: owner,
+        payer,
+        record,
+        mint,
+        class,
+        token2022,
+        system_program,
+        class_stats,
+    }
+    .instruction(UpdateTokenizedRecordMetadataInstructionArgs {
+        expected_revision: 0,
+        metadata: Metadata {
+            name: make_u32prefix_string("test2"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (class, class_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn add_additional_metadata() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
     // Mint
-    let (mint, _) = keyed_account_for_mint(record);
-    // Group
-    let (group, _) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 1, mint, false, 0, "test", None);
+
+    // Record updated
+    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
+        class,
+        1,
+        mint,
+        false,
+        0,
+        "test",
+        Some(METADATA_WITH_ADDITIONAL_METADATA),
+    );
+    let record_data_updated = record_with_mutation(record_data_updated, 27, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
 
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
     let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let mint_instruction = MintTokenizedRecord {
-        owner,
-        payer: authority,
+    let instruction = AddAdditionalMetadata {
         authority,
+        payer,
         record,
         mint,
         class,
-        group,
-        token_account,
-        associated_token_program,
         token2022,
         system_program,
+        class_stats,
     }
-    .instruction();
+    .instruction(AddAdditionalMetadataInstructionArgs {
+        label: "test".to_string(),
+        value: "test".to_string(),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (class, class_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+
+    // The mint metadata follows the record data
+    assert!(result
+        .get_account(&mint)
+        .unwrap()
+        .data
+        .windows(MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len())
+        .any(|extension| extension == MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA));
+}
+
+#[test]
+/// Fails because the record is not tokenized
+fn fail_add_additional_metadata_not_tokenized() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, OWNER, false, 0, "test", None);
 
-    let burn_instruction = BurnTokenizedRecord {
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = AddAdditionalMetadata {
         authority,
-        payer: authority,
+        payer,
         record,
         mint,
-        token_account,
+        class,
         token2022,
-        class: Some(class),
+        system_program,
+        class_stats,
     }
-    .instruction();
+    .instruction(AddAdditionalMetadataInstructionArgs {
+        label: "test".to_string(),
+        value: "test".to_string(),
+    });
 
     let mut mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
     mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
 
-    mollusk.process_and_validate_instruction_chain(
-        &[
-            (&mint_instruction, &[Check::success()]),
-            (&burn_instruction, &[Check::success()]),
-        ],
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (owner, owner_data),
-            (payer, payer_data),
             (authority, authority_data),
+            (payer, payer_data),
             (record, record_data),
-            (mint, Account::default()),
+            (mint, mint_data),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
             (token2022, token2022_data),
             (system_program, system_program_data),
+            (class_stats, Account::default()),
         ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotTokenized as u32,
+        ))],
     );
 }
 
 #[test]
-fn update_tokenized_record() {
+fn remove_additional_metadata() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
@@ -2497,133 +10881,401 @@ fn update_tokenized_record() {
         &[b"record", &class.as_ref(), b"test"],
         &SOLANA_RECORD_SERVICE_ID,
     );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    let (mint, mint_data) = keyed_account_for_mint_with_additional_metadata(record_address);
     // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-
-    let burn_instruction = BurnTokenizedRecord {
-        authority,
-        payer,
-        record,
-        mint,
-        token_account,
-        token2022,
-        class: Some(class),
-    }
-    .instruction();
-
-    // New metadata
-    let new_metadata = Metadata {
-        name: make_u32prefix_string("test2"),
-        symbol: make_u32prefix_string("SRS"),
-        uri: make_u32prefix_string("test"),
-        additional_metadata: vec![],
-    };
-
-    // Record updated
-    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
+    let (record, record_data) = keyed_account_for_record_with_metadata(
         class,
-        0,
-        OWNER,
+        1,
+        mint,
         false,
         0,
         "test",
-        Some(&new_metadata.try_to_vec().unwrap()),
+        Some(METADATA_WITH_ADDITIONAL_METADATA),
     );
 
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record_with_metadata(class, 1, mint, false, 0, "test", None);
+    let record_data_updated = record_with_mutation(record_data_updated, 28, authority);
+    let record_data_updated = record_with_revision(record_data_updated, 1);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Class Stats
+    let (class_stats, _) = keyed_account_for_class_stats(class, 0, 0);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let update_instruction = UpdateRecordTokenizable {
+    let instruction = RemoveAdditionalMetadata {
         authority,
         payer,
         record,
+        mint,
         class,
+        token2022,
         system_program,
+        class_stats,
     }
-    .instruction(UpdateRecordTokenizableInstructionArgs {
-        metadata: Metadata {
-            name: make_u32prefix_string("test2"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![],
-        },
+    .instruction(RemoveAdditionalMetadataInstructionArgs {
+        label: "test".to_string(),
     });
 
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // Associated Token Program
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
+    let mut mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
 
-    // New Mint
-    let (_, new_mint_data) = keyed_account_for_updated_mint(record);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (class, class_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+            (class_stats, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+
+    // The mint metadata follows the record data
+    assert!(result
+        .get_account(&mint)
+        .unwrap()
+        .data
+        .windows(MINT_METADATA_EXTENSION.len())
+        .any(|extension| extension == MINT_METADATA_EXTENSION));
+}
+
+#[test]
+fn parse_class_and_record_from_bytes() {
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "metadata");
+    let (_, class_bump) = Pubkey::find_program_address(
+        &[b"class", AUTHORITY.as_ref(), b"test"],
+        &SOLANA_RECORD_SERVICE_ID,
+    );
+    // Record
+    let (_record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, true, 42, b"test", b"data");
+
+    let parsed_class = Class::try_from(class_data.data.as_slice()).expect("Invalid class");
+    assert_eq!(
+        parsed_class,
+        Class::from_bytes(&class_data.data).expect("Invalid class")
+    );
+    assert_eq!(parsed_class.authority, AUTHORITY);
+    assert!(parsed_class.is_permissioned);
+    assert_eq!(parsed_class.bump, class_bump);
+    assert_eq!(parsed_class.name.as_str(), "test");
+
+    let parsed_record = Record::try_from(record_data.data.as_slice()).expect("Invalid record");
+    assert_eq!(
+        parsed_record,
+        Record::from_bytes(&record_data.data).expect("Invalid record")
+    );
+    assert_eq!(parsed_record.expiry, 42);
+    assert_eq!(
+        parsed_record.bump,
+        Pubkey::find_program_address(
+            &[b"record", class.as_ref(), b"test"],
+            &SOLANA_RECORD_SERVICE_ID
+        )
+        .1
+    );
+    assert_eq!(parsed_record.mint_bump, 0);
+    assert_eq!(parsed_record.seed.as_slice(), b"test");
+    assert_eq!(parsed_record.data.as_slice(), b"data");
+
+    // A record cannot be parsed as a class
+    assert_eq!(
+        Class::try_from(record_data.data.as_slice()),
+        Err(solana_record_service_client::SrsError::InvalidDiscriminator)
+    );
+}
+
+#[test]
+fn initialize_account_3_instruction_data() {
+    let data = crate::token2022::InitializeAccount3::instruction_data(&OWNER.to_bytes());
+
+    // InitializeAccount3 discriminator followed by the owner
+    assert_eq!(data[0], 18);
+    assert_eq!(&data[1..], OWNER.as_ref());
+}
+
+#[test]
+fn approve_checked_instruction_data() {
+    let data = crate::token2022::ApproveChecked::instruction_data(1, 0);
+
+    // ApproveChecked discriminator followed by the amount and decimals
+    assert_eq!(data, [13, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+}
 
+#[test]
+fn update_group_max_size_instruction_data() {
+    let data = crate::token2022::UpdateGroupMaxSize::instruction_data(101);
+
+    // spl_token_group_interface:update_group_max_size discriminator followed by the max size
+    assert_eq!(
+        &data[..8],
+        &[0x6c, 0x25, 0xab, 0x8f, 0xf8, 0x1e, 0x12, 0x6e]
+    );
+    assert_eq!(&data[8..], &101u64.to_le_bytes());
+}
+
+proptest! {
+    #[test]
+    fn byte_writer_str_with_length_round_trip(s in "\\PC{0,32}") {
+        let mut data = vec![0u8; 1 + s.len()];
+        let mut writer = ByteWriter::new_with_offset(&mut data, 0);
+        writer.write_str_with_length(&s).unwrap();
+        prop_assert_eq!(writer.remaining_bytes(), 0);
+
+        let mut reader = ByteReader::new(&data);
+        prop_assert_eq!(reader.read_str_with_length().unwrap(), s.as_str());
+        prop_assert_eq!(reader.remaining_bytes(), 0);
+    }
+
+    #[test]
+    fn byte_reader_bytes_with_length_round_trip(data in prop::collection::vec(any::<u8>(), 0..300)) {
+        let mut reader = ByteReader::new(&data);
+        let Ok(bytes) = reader.read_bytes_with_length() else {
+            // Only a missing or too long length prefix fails
+            prop_assert!(data.is_empty() || data[0] as usize >= data.len());
+            return Ok(());
+        };
+
+        // Writing the bytes back produces the bytes that were read
+        let consumed = data.len() - reader.remaining_bytes();
+        let mut written = vec![0u8; consumed];
+        ByteWriter::new_with_offset(&mut written, 0)
+            .write_bytes_with_length(bytes)
+            .unwrap();
+        prop_assert_eq!(&written[..], &data[..consumed]);
+    }
+
+    #[test]
+    fn byte_reader_past_end_fails(data in prop::collection::vec(any::<u8>(), 0..64), extra in 1usize..64) {
+        let mut reader = ByteReader::new(&data);
+        let len = data.len() + extra;
+
+        prop_assert_eq!(reader.read_bytes(len), Err(PinocchioError::InvalidInstructionData));
+        prop_assert_eq!(reader.read_str(len), Err(PinocchioError::InvalidInstructionData));
+
+        // Failed reads leave the reader where it was
+        reader.read_bytes(data.len()).unwrap();
+        prop_assert_eq!(reader.read::<u8>(), Err(PinocchioError::InvalidInstructionData));
+        prop_assert_eq!(reader.read::<u64>(), Err(PinocchioError::InvalidInstructionData));
+        prop_assert_eq!(reader.read_str_with_length(), Err(PinocchioError::InvalidInstructionData));
+    }
+}
+
+#[test]
+fn owner_type_try_from_u8() {
+    use crate::state::OwnerType;
+
+    for owner_type in [OwnerType::Pubkey, OwnerType::Token] {
+        assert_eq!(OwnerType::try_from(u8::from(owner_type)), Ok(owner_type));
+    }
+
+    // Unknown owner types are rejected
+    for value in 2..=u8::MAX {
+        assert_eq!(
+            OwnerType::try_from(value),
+            Err(PinocchioError::InvalidAccountData)
+        );
+    }
+}
+
+#[test]
+fn transfer_record_to_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+    let owner = OWNER;
+    // Old Class
+    let (old_class, old_class_data) = keyed_account_for_class_default();
+    // New Class
+    let (new_class, new_class_data) =
+        keyed_account_for_class(AUTHORITY, false, false, "other", "test");
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(old_class, 0, owner, false, 1000, b"test", b"test");
+    // New Record
+    let (new_record, new_record_data) =
+        keyed_account_for_record(new_class, 0, owner, false, 1000, b"test", b"test");
+    let new_record_data = record_with_mutation(new_record_data, 38, authority);
+    // Class Stats
+    let (old_class_stats, old_class_stats_data) = keyed_account_for_class_stats(old_class, 1, 4);
+    let (_, emptied_old_class_stats_data) = keyed_account_for_class_stats(old_class, 0, 0);
+    let (new_class_stats, new_class_stats_data) = keyed_account_for_class_stats(new_class, 1, 4);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let mint_instruction = MintTokenizedRecord {
-        owner,
-        payer: authority,
+    let instruction = TransferRecordToClass {
         authority,
-        record,
-        mint,
-        class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
+        payer: authority,
+        old_class,
+        new_class,
+        old_record,
+        new_record,
+        old_class_stats,
+        new_class_stats,
         system_program,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &SOLANA_RECORD_SERVICE_ID,
         "../target/deploy/solana_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (old_class, old_class_data),
+            (new_class, new_class_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (old_class_stats, old_class_stats_data),
+            (new_class_stats, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&old_record).lamports(0).build(),
+            Check::account(&new_record)
+                .data(&new_record_data.data)
+                .build(),
+            Check::account(&old_class_stats)
+                .data(&emptied_old_class_stats_data.data)
+                .build(),
+            Check::account(&new_class_stats)
+                .data(&new_class_stats_data.data)
+                .build(),
+        ],
+    );
+}
 
-    mollusk.process_and_validate_instruction_chain(
+#[test]
+/// Fails because the record is tokenized
+fn fail_transfer_record_to_class_tokenized() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Old Class
+    let (old_class, old_class_data) = keyed_account_for_class_default();
+    // New Class
+    let (new_class, new_class_data) =
+        keyed_account_for_class(AUTHORITY, false, false, "other", "test");
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(old_class, 1, OWNER, false, 0, b"test", b"test");
+    // New Record
+    let (new_record, _) = keyed_account_for_record(new_class, 1, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (old_class_stats, old_class_stats_data) = keyed_account_for_class_stats(old_class, 1, 4);
+    let (new_class_stats, _) = keyed_account_for_class_stats(new_class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecordToClass {
+        authority,
+        payer: authority,
+        old_class,
+        new_class,
+        old_record,
+        new_record,
+        old_class_stats,
+        new_class_stats,
+        system_program,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (
-                &burn_instruction, 
-                &[Check::success()]
-            ),
-            (
-                &update_instruction,
-                &[
-                    Check::success(),
-                    Check::account(&record)
-                        .data(&record_data_updated.data)
-                        .build(),
-                ],
-            ),
-            (
-                &mint_instruction,
-                &[
-                    Check::success(),
-                    Check::account(&mint).data(&new_mint_data.data).build(),
-                ],
-            ),
+            (authority, authority_data),
+            (old_class, old_class_data),
+            (new_class, new_class_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (old_class_stats, old_class_stats_data),
+            (new_class_stats, Account::default()),
+            (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::AlreadyTokenized as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the authority is not the class authority of the new class
+fn fail_transfer_record_to_class_not_new_class_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Old Class
+    let (old_class, old_class_data) = keyed_account_for_class_default();
+    // New Class
+    let (new_class, new_class_data) =
+        keyed_account_for_class(RANDOM_PUBKEY, false, false, "other", "test");
+    // Old Record
+    let (old_record, old_record_data) =
+        keyed_account_for_record(old_class, 0, OWNER, false, 0, b"test", b"test");
+    // New Record
+    let (new_record, _) = keyed_account_for_record(new_class, 0, OWNER, false, 0, b"test", b"test");
+    // Class Stats
+    let (old_class_stats, old_class_stats_data) = keyed_account_for_class_stats(old_class, 1, 4);
+    let (new_class_stats, _) = keyed_account_for_class_stats(new_class, 0, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = TransferRecordToClass {
+        authority,
+        payer: authority,
+        old_class,
+        new_class,
+        old_record,
+        new_record,
+        old_class_stats,
+        new_class_stats,
+        system_program,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &SOLANA_RECORD_SERVICE_ID,
+        "../target/deploy/solana_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (owner, owner_data),
-            (payer, payer_data),
             (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (class, class_data),
-            (group, group_data),
-            (token_account, token_account_data),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
+            (old_class, old_class_data),
+            (new_class, new_class_data),
+            (old_record, old_record_data),
+            (new_record, Account::default()),
+            (old_class_stats, old_class_stats_data),
+            (new_class_stats, Account::default()),
             (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            SrsError::NotClassAuthority as u32,
+        ))],
     );
 }
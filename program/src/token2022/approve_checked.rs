@@ -0,0 +1,85 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::token2022::constants::TOKEN_2022_PROGRAM_ID;
+
+/// Approves a delegate, checking the mint and decimals.
+///
+/// ### Accounts:
+///  0. `[WRITE]` The source account.
+///  1. `[]` The token mint.
+///  2. `[]` The delegate.
+///  3. `[SIGNER]` The source account owner.
+pub struct ApproveChecked<'a> {
+    /// Source Account.
+    pub source: &'a AccountInfo,
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// Delegate Account.
+    pub delegate: &'a AccountInfo,
+    /// Source Owner Account.
+    pub owner: &'a AccountInfo,
+    /// Amount the delegate is approved for.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+const DISCRIMINATOR_OFFSET: usize = 0;
+const AMOUNT_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const DECIMALS_OFFSET: usize = AMOUNT_OFFSET + size_of::<u64>();
+const INSTRUCTION_DATA_LEN: usize = DECIMALS_OFFSET + size_of::<u8>();
+
+impl ApproveChecked<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub(crate) fn instruction_data(amount: u64, decimals: u8) -> [u8; INSTRUCTION_DATA_LEN] {
+        const DISCRIMINATOR: u8 = 0x0d;
+
+        // instruction data
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        // -  [9]: decimals (1 byte, u8)
+        let mut instruction_data = [0u8; INSTRUCTION_DATA_LEN];
+
+        instruction_data[DISCRIMINATOR_OFFSET] = DISCRIMINATOR;
+        instruction_data[AMOUNT_OFFSET..DECIMALS_OFFSET].copy_from_slice(&amount.to_le_bytes());
+        instruction_data[DECIMALS_OFFSET] = decimals;
+
+        instruction_data
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Account metadata
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.source.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly(self.delegate.key()),
+            AccountMeta::readonly_signer(self.owner.key()),
+        ];
+
+        let instruction_data = Self::instruction_data(self.amount, self.decimals);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.source, self.mint, self.delegate, self.owner],
+            signers,
+        )
+    }
+}
@@ -5,6 +5,7 @@ pub const TOKEN_2022_MINT_LEN: usize = 0x52;
 pub const TOKEN_2022_MINT_BASE_LEN: usize = 0x54;
 pub const TOKEN_2022_PERMANENT_DELEGATE_LEN: usize = 0x24;
 pub const TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN: usize = 0x24;
+pub const TOKEN_2022_NON_TRANSFERABLE_LEN: usize = 0x04;
 pub const TOKEN_2022_METADATA_POINTER_LEN: usize = 0x44;
 pub const TOKEN_2022_METADATA_LEN: usize = 0x44;
 pub const TOKEN_2022_GROUP_POINTER_LEN: usize = 0x44;
@@ -12,6 +13,65 @@ pub const TOKEN_2022_GROUP_LEN: usize = 0x54;
 pub const TOKEN_2022_MEMBER_POINTER_LEN: usize = 0x44;
 pub const TOKEN_2022_MEMBER_LEN: usize = 0x4C;
 
+// Token2022 layout parts the lengths above are built from
+const PUBKEY_LEN: usize = 32;
+const COPTION_PUBKEY_LEN: usize = 4 + PUBKEY_LEN;
+/// Extension type (u16) followed by the extension length (u16)
+const TLV_HEADER_LEN: usize = 2 + 2;
+/// Token accounts and mints share the same base length, padded for mints
+const TOKEN_2022_ACCOUNT_LEN: usize = 165;
+const ACCOUNT_TYPE_LEN: usize = 1;
+
+const _: () = assert!(
+    TOKEN_2022_MINT_LEN == COPTION_PUBKEY_LEN + 8 + 1 + 1 + COPTION_PUBKEY_LEN,
+    "mint: mint authority + supply + decimals + is initialized + freeze authority"
+);
+const _: () = assert!(
+    TOKEN_2022_MINT_BASE_LEN == TOKEN_2022_ACCOUNT_LEN - TOKEN_2022_MINT_LEN + ACCOUNT_TYPE_LEN,
+    "mint base: padding up to the token account length + account type"
+);
+const _: () = assert!(
+    TOKEN_2022_PERMANENT_DELEGATE_LEN == TLV_HEADER_LEN + PUBKEY_LEN,
+    "permanent delegate: tlv header + delegate"
+);
+const _: () = assert!(
+    TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN == TLV_HEADER_LEN + PUBKEY_LEN,
+    "close mint authority: tlv header + close authority"
+);
+const _: () = assert!(
+    TOKEN_2022_NON_TRANSFERABLE_LEN == TLV_HEADER_LEN,
+    "non transferable: tlv header, no payload"
+);
+const _: () = assert!(
+    TOKEN_2022_METADATA_POINTER_LEN == TLV_HEADER_LEN + PUBKEY_LEN * 2,
+    "metadata pointer: tlv header + authority + metadata address"
+);
+const _: () = assert!(
+    TOKEN_2022_METADATA_LEN == TLV_HEADER_LEN + PUBKEY_LEN * 2,
+    "metadata: tlv header + update authority + mint, before the variable fields"
+);
+const _: () = assert!(
+    TOKEN_2022_GROUP_POINTER_LEN == TLV_HEADER_LEN + PUBKEY_LEN * 2,
+    "group pointer: tlv header + authority + group address"
+);
+const _: () = assert!(
+    TOKEN_2022_GROUP_LEN == TLV_HEADER_LEN + PUBKEY_LEN * 2 + 8 + 8,
+    "group: tlv header + update authority + mint + size + max size"
+);
+const _: () = assert!(
+    TOKEN_2022_MEMBER_POINTER_LEN == TLV_HEADER_LEN + PUBKEY_LEN * 2,
+    "member pointer: tlv header + authority + member address"
+);
+const _: () = assert!(
+    TOKEN_2022_MEMBER_LEN == TLV_HEADER_LEN + PUBKEY_LEN * 2 + 8,
+    "member: tlv header + mint + group + member number"
+);
+
+// Tokenized Record Constants
+/// SRS mints are always NFT-style: a supply of exactly 1 with 0 decimals
+pub const TOKENIZED_RECORD_AMOUNT: u64 = 1;
+pub const TOKENIZED_RECORD_DECIMALS: u8 = 0;
+
 // TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
 pub const TOKEN_2022_PROGRAM_ID: Pubkey = [
     0x06, 0xdd, 0xf6, 0xe1, 0xee, 0x75, 0x8f, 0xde, 0x18, 0x42, 0x5d, 0xbc, 0xe4, 0x6c, 0xcd, 0xda,
@@ -0,0 +1,69 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::token2022::constants::TOKEN_2022_PROGRAM_ID;
+
+/// Initialize a new token account, without requiring the Rent sysvar.
+///
+/// ### Accounts:
+///   0. `[WRITABLE]` Token account
+///   1. `[]` Mint account
+pub struct InitializeAccount3<'a> {
+    /// Token Account.
+    pub account: &'a AccountInfo,
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+    /// Owner of the new token account.
+    pub owner: &'a Pubkey,
+}
+
+const DISCRIMINATOR_OFFSET: usize = 0;
+const OWNER_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const INSTRUCTION_DATA_LEN: usize = OWNER_OFFSET + size_of::<Pubkey>();
+
+impl InitializeAccount3<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    const DISCRIMINATOR: u8 = 0x12;
+
+    #[inline(always)]
+    pub(crate) fn instruction_data(owner: &Pubkey) -> [u8; INSTRUCTION_DATA_LEN] {
+        // instruction data
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..33]: owner (32 bytes, Pubkey)
+        let mut instruction_data = [0u8; INSTRUCTION_DATA_LEN];
+
+        instruction_data[DISCRIMINATOR_OFFSET] = Self::DISCRIMINATOR;
+        instruction_data[OWNER_OFFSET..].copy_from_slice(owner);
+
+        instruction_data
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Account metadata
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::readonly(self.mint.key()),
+        ];
+
+        let instruction_data = Self::instruction_data(self.owner);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.account, self.mint], signers)
+    }
+}
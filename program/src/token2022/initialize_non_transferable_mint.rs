@@ -0,0 +1,55 @@
+use core::{mem::size_of, slice::from_raw_parts};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::{
+    token2022::constants::TOKEN_2022_PROGRAM_ID,
+    utils::{write_bytes, UNINIT_BYTE},
+};
+
+/// Initializes a Non Transferable Mint.
+///
+/// ### Accounts:
+///   0. `[WRITE]`  The mint account to initialize as non transferable.
+pub struct InitializeNonTransferableMint<'a> {
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+}
+
+impl InitializeNonTransferableMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    const DISCRIMINATOR_OFFSET: usize = 0;
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const DISCRIMINATOR: u8 = 0x20;
+
+        // Account metadata
+        let account_metas: [AccountMeta; 1] = [AccountMeta::writable(self.mint.key())];
+
+        // instruction data
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; size_of::<u8>()];
+
+        write_bytes(
+            &mut instruction_data[Self::DISCRIMINATOR_OFFSET..],
+            &[DISCRIMINATOR],
+        );
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len()) },
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)
+    }
+}
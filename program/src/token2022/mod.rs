@@ -4,6 +4,9 @@ pub use initialize_mint_close_authority::*;
 pub mod initialize_permanent_delegate;
 pub use initialize_permanent_delegate::*;
 
+pub mod initialize_non_transferable_mint;
+pub use initialize_non_transferable_mint::*;
+
 pub mod initialize_metadata_pointer;
 pub use initialize_metadata_pointer::*;
 
@@ -25,9 +28,15 @@ pub use initialize_member::*;
 pub mod update_metadata;
 pub use update_metadata::*;
 
+pub mod remove_key;
+pub use remove_key::*;
+
 pub mod initialize_mint_2;
 pub use initialize_mint_2::*;
 
+pub mod initialize_account_3;
+pub use initialize_account_3::*;
+
 pub mod close_account;
 pub use close_account::*;
 
@@ -46,6 +55,18 @@ pub use thaw_account::*;
 pub mod burn_checked;
 pub use burn_checked::*;
 
+pub mod revoke;
+pub use revoke::*;
+
+pub mod approve_checked;
+pub use approve_checked::*;
+
+pub mod set_authority;
+pub use set_authority::*;
+
+pub mod update_group_max_size;
+pub use update_group_max_size::*;
+
 pub mod state;
 pub use state::*;
 
@@ -0,0 +1,93 @@
+use core::mem::size_of;
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::{
+    token2022::constants::TOKEN_2022_PROGRAM_ID,
+    utils::{write_bytes, UNINIT_BYTE},
+};
+
+/// Removes an additional metadata key from a Token-2022 mint.
+///
+/// ### Accounts:
+/// 0. `[WRITE]` Metadata account
+/// 1. `[SIGNER]` Update authority
+///
+/// ### Data: 0. `RemoveKey`
+///
+/// pub struct RemoveKey {
+///    /// If the idempotent flag is set, the instruction does not fail if the key is missing
+///    pub idempotent: bool,
+///    /// Key to remove from the additional metadata
+///    pub key: String,
+/// }
+pub struct RemoveKey<'a> {
+    /// Metadata Account [writable]
+    pub metadata: &'a AccountInfo,
+    /// Update Authority Account [signer]
+    pub update_authority: &'a AccountInfo,
+    /// Whether a missing key is ignored
+    pub idempotent: bool,
+    /// Length prefixed key to remove
+    pub key: &'a [u8],
+}
+
+const DISCRIMINATOR_OFFSET: usize = 0;
+const IDEMPOTENT_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<[u8; 8]>();
+const KEY_OFFSET: usize = IDEMPOTENT_OFFSET + size_of::<bool>();
+
+impl RemoveKey<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const DISCRIMINATOR: [u8; 8] = [0xea, 0x12, 0x20, 0x38, 0x59, 0x8d, 0x25, 0xb5];
+
+        // Account metadata
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.metadata.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
+        // instruction data
+        // - [0]: instruction discriminator (8 bytes, [u8;8])
+        // - [8]: idempotent (bool)
+        // - [9..]: key (u32 length prefixed)
+        let instruction_data_size = DISCRIMINATOR.len() + size_of::<bool>() + self.key.len();
+        let mut instruction_data = [UNINIT_BYTE; 2_000];
+
+        write_bytes(
+            &mut instruction_data[DISCRIMINATOR_OFFSET..],
+            &DISCRIMINATOR,
+        );
+
+        // Write idempotent at offset [8]
+        write_bytes(
+            &mut instruction_data[IDEMPOTENT_OFFSET..],
+            &[self.idempotent as u8],
+        );
+
+        // Write key at offset [9..]
+        write_bytes(&mut instruction_data[KEY_OFFSET..], self.key);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, instruction_data_size) },
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.metadata, self.update_authority],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::token2022::constants::TOKEN_2022_PROGRAM_ID;
+
+/// Revokes the delegate's authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[SIGNER]` The source account owner.
+pub struct Revoke<'a> {
+    /// Source Account.
+    pub source: &'a AccountInfo,
+    /// Source Owner Account.
+    pub owner: &'a AccountInfo,
+}
+
+impl Revoke<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const DISCRIMINATOR: u8 = 0x05;
+
+        // account metadata
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.source.key()),
+            AccountMeta::readonly_signer(self.owner.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &[DISCRIMINATOR],
+        };
+
+        invoke_signed(&instruction, &[self.source, self.owner], signers)
+    }
+}
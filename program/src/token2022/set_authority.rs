@@ -0,0 +1,101 @@
+use core::{mem::size_of, slice::from_raw_parts};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    token2022::constants::TOKEN_2022_PROGRAM_ID,
+    utils::{write_bytes, UNINIT_BYTE},
+};
+
+/// Authority types of the Token2022 `SetAuthority` instruction.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum AuthorityType {
+    /// Authority to mint new tokens
+    MintTokens = 0,
+    /// Authority to freeze any account associated with the mint
+    FreezeAccount = 1,
+    /// Owner of a given token account
+    AccountOwner = 2,
+    /// Authority to close a token account
+    CloseAccount = 3,
+}
+
+/// Sets a new authority of a mint or account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint or account to change the authority of.
+///   1. `[SIGNER]` The current authority of the mint or account.
+pub struct SetAuthority<'a> {
+    /// Mint or Token Account.
+    pub owned: &'a AccountInfo,
+    /// Current Authority Account.
+    pub owner: &'a AccountInfo,
+    /// Type of the authority to update.
+    pub authority_type: AuthorityType,
+    /// New authority, `None` to relinquish the authority.
+    pub new_authority: Option<&'a Pubkey>,
+}
+
+const DISCRIMINATOR_OFFSET: usize = 0;
+const AUTHORITY_TYPE_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const HAS_NEW_AUTHORITY_OFFSET: usize = AUTHORITY_TYPE_OFFSET + size_of::<u8>();
+const NEW_AUTHORITY_OFFSET: usize = HAS_NEW_AUTHORITY_OFFSET + size_of::<u8>();
+
+impl SetAuthority<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const DISCRIMINATOR: u8 = 0x06;
+
+        // Account metadata
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.owned.key()),
+            AccountMeta::readonly_signer(self.owner.key()),
+        ];
+
+        // Instruction data layout:
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1]: authority_type (1 byte, u8)
+        // -  [2]: new_authority presence flag (1 byte, u8)
+        // -  [3..35]: new_authority (optional, 32 bytes, Pubkey)
+        let mut instruction_data = [UNINIT_BYTE; 35];
+
+        write_bytes(
+            &mut instruction_data[DISCRIMINATOR_OFFSET..],
+            &[DISCRIMINATOR],
+        );
+
+        write_bytes(
+            &mut instruction_data[AUTHORITY_TYPE_OFFSET..],
+            &[self.authority_type as u8],
+        );
+
+        // Set COption & new_authority at offset [2..35]
+        let instruction_data_size = if let Some(new_authority) = self.new_authority {
+            write_bytes(&mut instruction_data[HAS_NEW_AUTHORITY_OFFSET..], &[1]);
+            write_bytes(&mut instruction_data[NEW_AUTHORITY_OFFSET..], new_authority);
+            instruction_data.len()
+        } else {
+            write_bytes(&mut instruction_data[HAS_NEW_AUTHORITY_OFFSET..], &[0]);
+            NEW_AUTHORITY_OFFSET
+        };
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, instruction_data_size) },
+        };
+
+        invoke_signed(&instruction, &[self.owned, self.owner], signers)
+    }
+}
@@ -1,5 +1,7 @@
-use crate::token2022::constants::TOKEN_2022_PROGRAM_ID;
-use core::mem::size_of;
+use crate::token2022::constants::{
+    TOKEN_2022_MINT_BASE_LEN, TOKEN_2022_MINT_LEN, TOKEN_2022_PROGRAM_ID,
+};
+use core::{mem::size_of, ops::Range};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 const TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET: usize = 165;
@@ -7,6 +9,18 @@ const TOKEN_IS_FROZEN_FLAG: u8 = 2;
 const MINT_DISCRIMINATOR: u8 = 0x01;
 const TOKEN_ACCOUNT_DISCRIMINATOR: u8 = 0x02;
 const TOKEN_ACCOUNT_SUPPLY_OFFSET: usize = 36;
+const TOKEN_ACCOUNT_DECIMALS_OFFSET: usize = TOKEN_ACCOUNT_SUPPLY_OFFSET + size_of::<u64>();
+
+/// Extensions of a mint start after the mint base data and the account type
+const EXTENSIONS_OFFSET: usize = TOKEN_2022_MINT_LEN + TOKEN_2022_MINT_BASE_LEN;
+const GROUP_EXTENSION_TYPE: u16 = 21;
+/// Offsets of the group sizes within the group extension value
+const GROUP_SIZE_OFFSET: usize = size_of::<Pubkey>() * 2;
+const GROUP_MAX_SIZE_OFFSET: usize = GROUP_SIZE_OFFSET + size_of::<u64>();
+const METADATA_EXTENSION_TYPE: u16 = 19;
+/// Offset of the token metadata within the metadata extension value, after the update authority
+/// and the mint
+const TOKEN_METADATA_OFFSET: usize = size_of::<Pubkey>() * 2;
 
 #[repr(C)]
 pub struct Mint<'info> {
@@ -61,15 +75,98 @@ impl<'info> Mint<'info> {
             )
         )
     }
+
+    pub fn get_decimals(account_info: &AccountInfo) -> Result<u8, ProgramError> {
+        if unsafe { account_info.owner().ne(&TOKEN_2022_PROGRAM_ID) } {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(account_info.try_borrow_data()?[TOKEN_ACCOUNT_DECIMALS_OFFSET])
+    }
+
+    /// Returns the `(size, max_size)` of the group extension of a group mint
+    ///
+    /// # Safety
+    /// Token Program ID is not checked
+    pub unsafe fn get_group_size_unchecked(data: &[u8]) -> Result<(u64, u64), ProgramError> {
+        let group = Self::get_extension_unchecked(data, GROUP_EXTENSION_TYPE)?;
+
+        if group.len() < GROUP_MAX_SIZE_OFFSET + size_of::<u64>() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let size = u64::from_le_bytes(
+            group[GROUP_SIZE_OFFSET..GROUP_SIZE_OFFSET + size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        );
+
+        let max_size = u64::from_le_bytes(
+            group[GROUP_MAX_SIZE_OFFSET..GROUP_MAX_SIZE_OFFSET + size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        );
+
+        Ok((size, max_size))
+    }
+
+    /// Returns the token metadata stored in the metadata extension of a mint
+    ///
+    /// # Safety
+    /// Token Program ID is not checked
+    pub unsafe fn get_token_metadata_unchecked(
+        data: &'info [u8],
+    ) -> Result<TokenMetadata<'info>, ProgramError> {
+        let metadata = Self::get_extension_unchecked(data, METADATA_EXTENSION_TYPE)?;
+
+        TokenMetadata::from_bytes(
+            metadata
+                .get(TOKEN_METADATA_OFFSET..)
+                .ok_or(ProgramError::InvalidAccountData)?,
+        )
+    }
+
+    /// Returns the value of the extension of type `extension_type`, walking the TLV entries so
+    /// that it does not depend on the order the extensions were initialized in
+    ///
+    /// # Safety
+    /// Token Program ID is not checked
+    unsafe fn get_extension_unchecked(
+        data: &[u8],
+        extension_type: u16,
+    ) -> Result<&[u8], ProgramError> {
+        let mut offset = EXTENSIONS_OFFSET;
+
+        while offset + size_of::<u16>() * 2 <= data.len() {
+            let current_type =
+                u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap());
+            offset += size_of::<u16>();
+            let len = u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap())
+                as usize;
+            offset += size_of::<u16>();
+
+            if offset + len > data.len() {
+                break;
+            }
+
+            if current_type == extension_type {
+                return Ok(&data[offset..offset + len]);
+            }
+
+            offset += len;
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
 }
 
 const TOKEN_MINT_OFFSET: usize = 0;
 const TOKEN_OWNER_OFFSET: usize = TOKEN_MINT_OFFSET + size_of::<Pubkey>();
-const TOKEN_IS_FROZEN_OFFSET: usize = TOKEN_OWNER_OFFSET
-    + size_of::<Pubkey>()
-    + size_of::<u64>()
-    + size_of::<u32>()
-    + size_of::<Pubkey>();
+const TOKEN_AMOUNT_OFFSET: usize = TOKEN_OWNER_OFFSET + size_of::<Pubkey>();
+/// `COption<Pubkey>`, a u32 tag followed by the delegate
+const TOKEN_DELEGATE_OFFSET: usize = TOKEN_AMOUNT_OFFSET + size_of::<u64>();
+const TOKEN_IS_FROZEN_OFFSET: usize =
+    TOKEN_DELEGATE_OFFSET + size_of::<u32>() + size_of::<Pubkey>();
 
 #[repr(C)]
 pub struct Token<'info> {
@@ -111,3 +208,101 @@ impl<'info> Token<'info> {
         Ok(data[TOKEN_IS_FROZEN_OFFSET].eq(&TOKEN_IS_FROZEN_FLAG))
     }
 }
+
+/// Borrowed view over a serialized token metadata, the layout of the data of
+/// tokenized records
+pub struct TokenMetadata<'info> {
+    /// `name`, `symbol` and `uri`, each with its u32 length prefix
+    pub fields: [&'info [u8]; 3],
+    /// Number of additional metadata entries
+    pub additional_metadata_len: u32,
+    /// Additional metadata entries, without their count prefix
+    pub additional_metadata: &'info [u8],
+}
+
+impl<'info> TokenMetadata<'info> {
+    /// Read a u32 length prefixed field, returning it with its prefix
+    #[inline(always)]
+    fn read_field(data: &'info [u8], offset: &mut usize) -> Result<&'info [u8], ProgramError> {
+        let len = data
+            .get(*offset..*offset + size_of::<u32>())
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let end = *offset + size_of::<u32>() + u32::from_le_bytes(len.try_into().unwrap()) as usize;
+
+        let field = data.get(*offset..end).ok_or(ProgramError::InvalidAccountData)?;
+        *offset = end;
+
+        Ok(field)
+    }
+
+    /// Parse a serialized token metadata, every entry must be well formed
+    pub fn from_bytes(data: &'info [u8]) -> Result<Self, ProgramError> {
+        let mut offset = 0;
+
+        let name = Self::read_field(data, &mut offset)?;
+        let symbol = Self::read_field(data, &mut offset)?;
+        let uri = Self::read_field(data, &mut offset)?;
+
+        let additional_metadata_len = u32::from_le_bytes(
+            data.get(offset..offset + size_of::<u32>())
+                .ok_or(ProgramError::InvalidAccountData)?
+                .try_into()
+                .unwrap(),
+        );
+        offset += size_of::<u32>();
+
+        let additional_metadata = &data[offset..];
+
+        // Check that every entry is complete and nothing trails them
+        let mut entries_offset = 0;
+        for _ in 0..additional_metadata_len {
+            Self::read_field(additional_metadata, &mut entries_offset)?;
+            Self::read_field(additional_metadata, &mut entries_offset)?;
+        }
+
+        if entries_offset != additional_metadata.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            fields: [name, symbol, uri],
+            additional_metadata_len,
+            additional_metadata,
+        })
+    }
+
+    /// Offset of the additional metadata count in the serialized metadata
+    pub fn additional_metadata_len_offset(&self) -> usize {
+        self.fields.iter().map(|field| field.len()).sum()
+    }
+
+    /// Find the entry of a length prefixed `key`, returning its range in the
+    /// serialized metadata
+    pub fn find_entry(&self, key: &[u8]) -> Option<Range<usize>> {
+        let mut start = self.additional_metadata_len_offset() + size_of::<u32>();
+
+        for (entry_key, entry) in self.entries() {
+            if entry_key.eq(key) {
+                return Some(start..start + entry.len());
+            }
+            start += entry.len();
+        }
+
+        None
+    }
+
+    /// Iterate over the additional metadata entries as `(key, entry)`, where
+    /// `key` is the length prefixed key and `entry` the prefixed key and value
+    pub fn entries(&self) -> impl Iterator<Item = (&'info [u8], &'info [u8])> {
+        let data = self.additional_metadata;
+        let mut offset = 0;
+
+        (0..self.additional_metadata_len).map_while(move |_| {
+            let start = offset;
+            let key = Self::read_field(data, &mut offset).ok()?;
+            Self::read_field(data, &mut offset).ok()?;
+
+            Some((key, &data[start..offset]))
+        })
+    }
+}
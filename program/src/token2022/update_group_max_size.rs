@@ -0,0 +1,70 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::token2022::constants::TOKEN_2022_PROGRAM_ID;
+
+/// Updates the max size of a Group.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The group account.
+///   1. `[SIGNER]` The group update authority.
+pub struct UpdateGroupMaxSize<'a> {
+    /// Group Account.
+    pub group: &'a AccountInfo,
+    /// Update Authority Account.
+    pub update_authority: &'a AccountInfo,
+    /// New Max Size, which cannot be lower than the current group size.
+    pub max_size: u64,
+}
+
+const DISCRIMINATOR_OFFSET: usize = 0;
+const MAX_SIZE_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<[u8; 8]>();
+const INSTRUCTION_DATA_LEN: usize = MAX_SIZE_OFFSET + size_of::<u64>();
+
+impl UpdateGroupMaxSize<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[inline(always)]
+    pub(crate) fn instruction_data(max_size: u64) -> [u8; INSTRUCTION_DATA_LEN] {
+        const UPDATE_GROUP_MAX_SIZE_DISCRIMINATOR: [u8; 8] =
+            [0x6c, 0x25, 0xab, 0x8f, 0xf8, 0x1e, 0x12, 0x6e];
+
+        // instruction data
+        // - [0..8]: instruction discriminator (8 bytes, [u8;8])
+        // - [8..16]: maxSize (8 bytes, u64)
+        let mut instruction_data = [0u8; INSTRUCTION_DATA_LEN];
+
+        instruction_data[DISCRIMINATOR_OFFSET..MAX_SIZE_OFFSET]
+            .copy_from_slice(&UPDATE_GROUP_MAX_SIZE_DISCRIMINATOR);
+        instruction_data[MAX_SIZE_OFFSET..].copy_from_slice(&max_size.to_le_bytes());
+
+        instruction_data
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // Account metadata
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.group.key()),
+            AccountMeta::readonly_signer(self.update_authority.key()),
+        ];
+
+        let instruction_data = Self::instruction_data(self.max_size);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.group, self.update_authority], signers)
+    }
+}
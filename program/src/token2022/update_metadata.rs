@@ -32,13 +32,15 @@ pub struct UpdateMetadata<'a> {
     pub metadata: &'a AccountInfo,
     /// Update Authority Account [signer]
     pub update_authority: &'a AccountInfo,
-    /// URI to update the metadata to
-    pub additional_metadata: &'a [u8],
+    /// Field to update (0 = Name, 1 = Symbol, 2 = Uri, 3 = Key)
+    pub field: u8,
+    /// Length prefixed value, preceded by the length prefixed key for `Key`
+    pub value: &'a [u8],
 }
 
 const DISCRIMINATOR_OFFSET: usize = 0;
 const FIELD_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<[u8; 8]>();
-const ADDITIONAL_METADATA_LENGTH_OFFSET: usize = FIELD_OFFSET + size_of::<u8>();
+const VALUE_OFFSET: usize = FIELD_OFFSET + size_of::<u8>();
 
 impl UpdateMetadata<'_> {
     #[inline(always)]
@@ -58,9 +60,8 @@ impl UpdateMetadata<'_> {
         // instruction data
         // - [0]: instruction discriminator (8 bytes, [u8;8])
         // - [8]: field (u8)
-        // - [9..13]: additional metadata length (u32)
-        // - [13..13+additional_metadata.len()]: additional metadata bytes
-        let instruction_data_size = DISCRIMINATOR.len() + size_of::<u8>() + self.additional_metadata.len();
+        // - [9..]: value bytes
+        let instruction_data_size = DISCRIMINATOR.len() + size_of::<u8>() + self.value.len();
         let mut instruction_data = [UNINIT_BYTE; 2_000];
 
         write_bytes(
@@ -69,13 +70,10 @@ impl UpdateMetadata<'_> {
         );
 
         // Write field at offset [8]
-        write_bytes(&mut instruction_data[FIELD_OFFSET..], &[3]);
+        write_bytes(&mut instruction_data[FIELD_OFFSET..], &[self.field]);
 
-        // Write additional metadata length at offset [9..13]
-        write_bytes(
-            &mut instruction_data[ADDITIONAL_METADATA_LENGTH_OFFSET..],
-            self.additional_metadata,
-        );
+        // Write value at offset [9..]
+        write_bytes(&mut instruction_data[VALUE_OFFSET..], self.value);
 
         let instruction = Instruction {
             program_id: &TOKEN_2022_PROGRAM_ID,
@@ -1,16 +1,53 @@
+use crate::state::MAX_RECORD_SIZE;
 use core::mem::size_of;
 use pinocchio::{
     account_info::{AccountInfo, RefMut},
     program_error::ProgramError,
+    pubkey::Pubkey,
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 use pinocchio_system::instructions::Transfer;
 pub struct Context<'info> {
+    /// The required accounts of the instruction
     pub accounts: &'info [AccountInfo],
+    /// The optional accounts following the required ones
+    pub remaining_accounts: &'info [AccountInfo],
     pub data: &'info [u8],
 }
 
+impl<'info> Context<'info> {
+    /// Context of an instruction without optional accounts
+    #[inline(always)]
+    pub fn new(accounts: &'info [AccountInfo], data: &'info [u8]) -> Self {
+        Self {
+            accounts,
+            remaining_accounts: &[],
+            data,
+        }
+    }
+
+    /// Context of an instruction taking `fixed_count` required accounts,
+    /// followed by optional accounts
+    ///
+    /// With fewer than `fixed_count` accounts, all of them are kept as
+    /// required accounts so the instruction fails with `NotEnoughAccountKeys`
+    #[inline(always)]
+    pub fn with_remaining_accounts(
+        accounts: &'info [AccountInfo],
+        data: &'info [u8],
+        fixed_count: usize,
+    ) -> Self {
+        let (accounts, remaining_accounts) = accounts.split_at(fixed_count.min(accounts.len()));
+
+        Self {
+            accounts,
+            remaining_accounts,
+            data,
+        }
+    }
+}
+
 /// A global `#[panic_handler]` for `no_std` programs.
 ///
 /// This macro sets up a default panic handler that logs the location (file,
@@ -55,12 +92,44 @@ macro_rules! nostd_panic_handler {
     };
 }
 
+/// Compute the sha256 hash of `data` through the `sol_sha256` syscall
+#[inline(always)]
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    #[allow(unused_mut)]
+    let mut hash = [0u8; 32];
+
+    #[cfg(any(target_os = "solana", target_arch = "bpf"))]
+    unsafe {
+        let vals = [data];
+        pinocchio::syscalls::sol_sha256(
+            vals.as_ptr() as *const u8,
+            vals.len() as u64,
+            hash.as_mut_ptr(),
+        );
+    }
+
+    #[cfg(not(any(target_os = "solana", target_arch = "bpf")))]
+    core::hint::black_box(data);
+
+    hash
+}
+
+/// Check whether `key` is the all-zeros pubkey, which is the system program
+/// id and never a valid authority
+#[inline(always)]
+pub fn is_system_program(key: &Pubkey) -> bool {
+    key.eq(&[0u8; 32])
+}
+
+/// Maximum number of bytes the runtime lets an account grow by within a single instruction
+pub const MAX_PERMITTED_DATA_INCREASE: usize = 1024 * 10;
 
 /// Resize an account and handle lamport transfers based on the new size
 ///
 /// This function will:
 /// 1. Calculate the new minimum balance required for rent exemption
-/// 2. Transfer lamports if the new size requires more or less balance
+/// 2. Transfer lamports if the new size requires more or less balance, unless the payer is the
+///    account itself
 /// 3. Reallocate the account to the new size
 ///
 /// # Arguments
@@ -74,8 +143,13 @@ pub fn resize_account(
     new_size: usize,
     zero_out: bool,
 ) -> ProgramResult {
-    // Check if the new size is bigger than 10KB
-    if new_size > 1024 * 10 {
+    // Check that the new size does not exceed the maximum record size
+    if new_size > MAX_RECORD_SIZE {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Check that the account does not grow by more than the runtime allows in one instruction
+    if new_size.saturating_sub(target_account.data_len()) > MAX_PERMITTED_DATA_INCREASE {
         return Err(ProgramError::InvalidAccountData);
     }
 
@@ -88,6 +162,16 @@ pub fn resize_account(
     let rent = Rent::get()?;
     let new_minimum_balance = rent.minimum_balance(new_size);
 
+    // An account paying for its own resize keeps its lamports, a self-transfer would borrow it
+    // twice, so it is only reallocated and must already cover the rent of its new size
+    if payer.key().eq(target_account.key()) {
+        if new_minimum_balance > target_account.lamports() {
+            return Err(ProgramError::AccountNotRentExempt);
+        }
+
+        return target_account.realloc(new_size, zero_out);
+    }
+
     // First handle lamport transfers
     match new_minimum_balance.cmp(&target_account.lamports()) {
         core::cmp::Ordering::Greater => {
@@ -284,3 +368,18 @@ pub fn write_bytes(destination: &mut [core::mem::MaybeUninit<u8>], source: &[u8]
         d.write(*s);
     }
 }
+
+/// Formats raw bytes, like pubkeys and record seeds, as lowercase hex
+#[cfg(test)]
+pub(crate) struct Hex<'a>(pub &'a [u8]);
+
+#[cfg(test)]
+impl core::fmt::Debug for Hex<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
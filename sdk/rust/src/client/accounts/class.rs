@@ -22,7 +22,17 @@ pub struct Class {
     pub authority: Pubkey,
     pub is_permissioned: bool,
     pub is_frozen: bool,
+    pub transfer_fee_bps: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_destination: Pubkey,
+    pub max_record_size: u32,
+    pub max_records: u32,
+    pub bump: u8,
     pub name: U8PrefixString,
+    pub metadata_schema_uri: U8PrefixString,
     pub metadata: RemainderStr,
 }
 
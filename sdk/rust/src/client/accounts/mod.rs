@@ -6,7 +6,21 @@
 //!
 
 pub(crate) mod r#class;
+pub(crate) mod r#class_registry;
+pub(crate) mod r#class_registry_page;
+pub(crate) mod r#class_stats;
 pub(crate) mod r#record;
+pub(crate) mod r#record_access_list;
+pub(crate) mod r#record_lease;
+pub(crate) mod r#record_snapshot;
+pub(crate) mod r#royalty_config;
 
 pub use self::r#class::*;
+pub use self::r#class_registry::*;
+pub use self::r#class_registry_page::*;
+pub use self::r#class_stats::*;
 pub use self::r#record::*;
+pub use self::r#record_access_list::*;
+pub use self::r#record_lease::*;
+pub use self::r#record_snapshot::*;
+pub use self::r#royalty_config::*;
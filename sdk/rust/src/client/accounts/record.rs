@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::types::RecordMutation;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use kaigan::types::RemainderVec;
@@ -27,7 +28,15 @@ pub struct Record {
     )]
     pub owner: Pubkey,
     pub is_frozen: bool,
+    pub non_transferable: bool,
     pub expiry: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub revision: u32,
+    pub mutation_cursor: u8,
+    pub recent_mutations: [RecordMutation; 5],
+    pub bump: u8,
+    pub mint_bump: u8,
     pub seed: U8PrefixVec<u8>,
     pub data: RemainderVec<u8>,
 }
@@ -4,3 +4,7 @@
 //!
 //! <https://github.com/codama-idl/codama>
 //!
+
+pub(crate) mod r#solana_record_service;
+
+pub use self::r#solana_record_service::SolanaRecordServiceError;
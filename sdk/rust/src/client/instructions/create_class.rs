@@ -19,8 +19,14 @@ pub struct CreateClass {
     pub payer: solana_program::pubkey::Pubkey,
     /// New class account to be initialized
     pub class: solana_program::pubkey::Pubkey,
+    /// Class registry of the authority, created on the first class
+    pub class_registry: solana_program::pubkey::Pubkey,
+    /// Class registry page the new class is appended to
+    pub class_registry_page: solana_program::pubkey::Pubkey,
     /// System Program used to open our new class account
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Class stats account of the class
+    pub class_stats: solana_program::pubkey::Pubkey,
 }
 
 impl CreateClass {
@@ -37,7 +43,7 @@ impl CreateClass {
         args: CreateClassInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -48,10 +54,22 @@ impl CreateClass {
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.class, false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.class_registry,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.class_registry_page,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.class_stats,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&CreateClassInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -88,7 +106,10 @@ impl Default for CreateClassInstructionData {
 pub struct CreateClassInstructionArgs {
     pub is_permissioned: bool,
     pub is_frozen: bool,
+    pub max_record_size: u32,
+    pub max_records: u32,
     pub name: U8PrefixString,
+    pub metadata_schema_uri: U8PrefixString,
     pub metadata: RemainderStr,
 }
 
@@ -99,16 +120,25 @@ pub struct CreateClassInstructionArgs {
 ///   0. `[signer]` authority
 ///   1. `[writable, signer]` payer
 ///   2. `[writable]` class
-///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[writable]` class_registry
+///   4. `[writable]` class_registry_page
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[writable]` class_stats
 #[derive(Clone, Debug, Default)]
 pub struct CreateClassBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
+    class_registry: Option<solana_program::pubkey::Pubkey>,
+    class_registry_page: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    class_stats: Option<solana_program::pubkey::Pubkey>,
     is_permissioned: Option<bool>,
     is_frozen: Option<bool>,
+    max_record_size: Option<u32>,
+    max_records: Option<u32>,
     name: Option<U8PrefixString>,
+    metadata_schema_uri: Option<U8PrefixString>,
     metadata: Option<RemainderStr>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -135,6 +165,21 @@ impl CreateClassBuilder {
         self.class = Some(class);
         self
     }
+    /// Class registry of the authority, created on the first class
+    #[inline(always)]
+    pub fn class_registry(&mut self, class_registry: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.class_registry = Some(class_registry);
+        self
+    }
+    /// Class registry page the new class is appended to
+    #[inline(always)]
+    pub fn class_registry_page(
+        &mut self,
+        class_registry_page: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.class_registry_page = Some(class_registry_page);
+        self
+    }
     /// `[optional account, default to '11111111111111111111111111111111']`
     /// System Program used to open our new class account
     #[inline(always)]
@@ -142,6 +187,12 @@ impl CreateClassBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Class stats account of the class
+    #[inline(always)]
+    pub fn class_stats(&mut self, class_stats: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.class_stats = Some(class_stats);
+        self
+    }
     #[inline(always)]
     pub fn is_permissioned(&mut self, is_permissioned: bool) -> &mut Self {
         self.is_permissioned = Some(is_permissioned);
@@ -153,11 +204,26 @@ impl CreateClassBuilder {
         self
     }
     #[inline(always)]
+    pub fn max_record_size(&mut self, max_record_size: u32) -> &mut Self {
+        self.max_record_size = Some(max_record_size);
+        self
+    }
+    #[inline(always)]
+    pub fn max_records(&mut self, max_records: u32) -> &mut Self {
+        self.max_records = Some(max_records);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: U8PrefixString) -> &mut Self {
         self.name = Some(name);
         self
     }
     #[inline(always)]
+    pub fn metadata_schema_uri(&mut self, metadata_schema_uri: U8PrefixString) -> &mut Self {
+        self.metadata_schema_uri = Some(metadata_schema_uri);
+        self
+    }
+    #[inline(always)]
     pub fn metadata(&mut self, metadata: RemainderStr) -> &mut Self {
         self.metadata = Some(metadata);
         self
@@ -186,9 +252,14 @@ impl CreateClassBuilder {
             authority: self.authority.expect("authority is not set"),
             payer: self.payer.expect("payer is not set"),
             class: self.class.expect("class is not set"),
+            class_registry: self.class_registry.expect("class_registry is not set"),
+            class_registry_page: self
+                .class_registry_page
+                .expect("class_registry_page is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            class_stats: self.class_stats.expect("class_stats is not set"),
         };
         let args = CreateClassInstructionArgs {
             is_permissioned: self
@@ -196,7 +267,16 @@ impl CreateClassBuilder {
                 .clone()
                 .expect("is_permissioned is not set"),
             is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
+            max_record_size: self
+                .max_record_size
+                .clone()
+                .expect("max_record_size is not set"),
+            max_records: self.max_records.clone().expect("max_records is not set"),
             name: self.name.clone().expect("name is not set"),
+            metadata_schema_uri: self
+                .metadata_schema_uri
+                .clone()
+                .expect("metadata_schema_uri is not set"),
             metadata: self.metadata.clone().expect("metadata is not set"),
         };
 
@@ -212,8 +292,14 @@ pub struct CreateClassCpiAccounts<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// New class account to be initialized
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class registry of the authority, created on the first class
+    pub class_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class registry page the new class is appended to
+    pub class_registry_page: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to open our new class account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the class
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `create_class` CPI instruction.
@@ -226,8 +312,14 @@ pub struct CreateClassCpi<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// New class account to be initialized
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class registry of the authority, created on the first class
+    pub class_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class registry page the new class is appended to
+    pub class_registry_page: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to open our new class account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the class
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateClassInstructionArgs,
 }
@@ -243,7 +335,10 @@ impl<'a, 'b> CreateClassCpi<'a, 'b> {
             authority: accounts.authority,
             payer: accounts.payer,
             class: accounts.class,
+            class_registry: accounts.class_registry,
+            class_registry_page: accounts.class_registry_page,
             system_program: accounts.system_program,
+            class_stats: accounts.class_stats,
             __args: args,
         }
     }
@@ -281,7 +376,7 @@ impl<'a, 'b> CreateClassCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -294,10 +389,22 @@ impl<'a, 'b> CreateClassCpi<'a, 'b> {
             *self.class.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.class_registry.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.class_registry_page.key,
+            false,
+        ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.class_stats.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -314,12 +421,15 @@ impl<'a, 'b> CreateClassCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.class.clone());
+        account_infos.push(self.class_registry.clone());
+        account_infos.push(self.class_registry_page.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.class_stats.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -339,7 +449,10 @@ impl<'a, 'b> CreateClassCpi<'a, 'b> {
 ///   0. `[signer]` authority
 ///   1. `[writable, signer]` payer
 ///   2. `[writable]` class
-///   3. `[]` system_program
+///   3. `[writable]` class_registry
+///   4. `[writable]` class_registry_page
+///   5. `[]` system_program
+///   6. `[writable]` class_stats
 #[derive(Clone, Debug)]
 pub struct CreateClassCpiBuilder<'a, 'b> {
     instruction: Box<CreateClassCpiBuilderInstruction<'a, 'b>>,
@@ -352,10 +465,16 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
             authority: None,
             payer: None,
             class: None,
+            class_registry: None,
+            class_registry_page: None,
             system_program: None,
+            class_stats: None,
             is_permissioned: None,
             is_frozen: None,
+            max_record_size: None,
+            max_records: None,
             name: None,
+            metadata_schema_uri: None,
             metadata: None,
             __remaining_accounts: Vec::new(),
         });
@@ -382,6 +501,24 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
         self.instruction.class = Some(class);
         self
     }
+    /// Class registry of the authority, created on the first class
+    #[inline(always)]
+    pub fn class_registry(
+        &mut self,
+        class_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_registry = Some(class_registry);
+        self
+    }
+    /// Class registry page the new class is appended to
+    #[inline(always)]
+    pub fn class_registry_page(
+        &mut self,
+        class_registry_page: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_registry_page = Some(class_registry_page);
+        self
+    }
     /// System Program used to open our new class account
     #[inline(always)]
     pub fn system_program(
@@ -391,6 +528,15 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Class stats account of the class
+    #[inline(always)]
+    pub fn class_stats(
+        &mut self,
+        class_stats: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_stats = Some(class_stats);
+        self
+    }
     #[inline(always)]
     pub fn is_permissioned(&mut self, is_permissioned: bool) -> &mut Self {
         self.instruction.is_permissioned = Some(is_permissioned);
@@ -402,11 +548,26 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn max_record_size(&mut self, max_record_size: u32) -> &mut Self {
+        self.instruction.max_record_size = Some(max_record_size);
+        self
+    }
+    #[inline(always)]
+    pub fn max_records(&mut self, max_records: u32) -> &mut Self {
+        self.instruction.max_records = Some(max_records);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: U8PrefixString) -> &mut Self {
         self.instruction.name = Some(name);
         self
     }
     #[inline(always)]
+    pub fn metadata_schema_uri(&mut self, metadata_schema_uri: U8PrefixString) -> &mut Self {
+        self.instruction.metadata_schema_uri = Some(metadata_schema_uri);
+        self
+    }
+    #[inline(always)]
     pub fn metadata(&mut self, metadata: RemainderStr) -> &mut Self {
         self.instruction.metadata = Some(metadata);
         self
@@ -463,7 +624,22 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
                 .is_frozen
                 .clone()
                 .expect("is_frozen is not set"),
+            max_record_size: self
+                .instruction
+                .max_record_size
+                .clone()
+                .expect("max_record_size is not set"),
+            max_records: self
+                .instruction
+                .max_records
+                .clone()
+                .expect("max_records is not set"),
             name: self.instruction.name.clone().expect("name is not set"),
+            metadata_schema_uri: self
+                .instruction
+                .metadata_schema_uri
+                .clone()
+                .expect("metadata_schema_uri is not set"),
             metadata: self
                 .instruction
                 .metadata
@@ -479,10 +655,25 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
 
             class: self.instruction.class.expect("class is not set"),
 
+            class_registry: self
+                .instruction
+                .class_registry
+                .expect("class_registry is not set"),
+
+            class_registry_page: self
+                .instruction
+                .class_registry_page
+                .expect("class_registry_page is not set"),
+
             system_program: self
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            class_stats: self
+                .instruction
+                .class_stats
+                .expect("class_stats is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -498,10 +689,16 @@ struct CreateClassCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    class_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    class_registry_page: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    class_stats: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     is_permissioned: Option<bool>,
     is_frozen: Option<bool>,
+    max_record_size: Option<u32>,
+    max_records: Option<u32>,
     name: Option<U8PrefixString>,
+    metadata_schema_uri: Option<U8PrefixString>,
     metadata: Option<RemainderStr>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -23,8 +23,12 @@ pub struct CreateRecord {
     pub record: solana_program::pubkey::Pubkey,
     /// System Program used to create our record account
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Class stats account of the class, created on the first record
+    pub class_stats: solana_program::pubkey::Pubkey,
     /// Optional authority for permissioned classes
     pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// Optional record access list for permissioned classes
+    pub access_list: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl CreateRecord {
@@ -41,7 +45,7 @@ impl CreateRecord {
         args: CreateRecordInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.owner, true,
         ));
@@ -59,6 +63,10 @@ impl CreateRecord {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.class_stats,
+            false,
+        ));
         if let Some(authority) = self.authority {
             accounts.push(solana_program::instruction::AccountMeta::new_readonly(
                 authority, true,
@@ -69,6 +77,17 @@ impl CreateRecord {
                 false,
             ));
         }
+        if let Some(access_list) = self.access_list {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                access_list,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&CreateRecordInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -104,6 +123,7 @@ impl Default for CreateRecordInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateRecordInstructionArgs {
     pub expiration: i64,
+    pub non_transferable: bool,
     pub seed: U8PrefixVec<u8>,
     pub data: RemainderVec<u8>,
 }
@@ -117,7 +137,9 @@ pub struct CreateRecordInstructionArgs {
 ///   2. `[writable]` class
 ///   3. `[writable]` record
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   5. `[signer, optional]` authority
+///   5. `[writable]` class_stats
+///   6. `[signer, optional]` authority
+///   7. `[optional]` access_list
 #[derive(Clone, Debug, Default)]
 pub struct CreateRecordBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -125,8 +147,11 @@ pub struct CreateRecordBuilder {
     class: Option<solana_program::pubkey::Pubkey>,
     record: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    class_stats: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
+    access_list: Option<solana_program::pubkey::Pubkey>,
     expiration: Option<i64>,
+    non_transferable: Option<bool>,
     seed: Option<U8PrefixVec<u8>>,
     data: Option<RemainderVec<u8>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
@@ -167,6 +192,12 @@ impl CreateRecordBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Class stats account of the class, created on the first record
+    #[inline(always)]
+    pub fn class_stats(&mut self, class_stats: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.class_stats = Some(class_stats);
+        self
+    }
     /// `[optional account]`
     /// Optional authority for permissioned classes
     #[inline(always)]
@@ -174,12 +205,27 @@ impl CreateRecordBuilder {
         self.authority = authority;
         self
     }
+    /// `[optional account]`
+    /// Optional record access list for permissioned classes
+    #[inline(always)]
+    pub fn access_list(
+        &mut self,
+        access_list: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.access_list = access_list;
+        self
+    }
     #[inline(always)]
     pub fn expiration(&mut self, expiration: i64) -> &mut Self {
         self.expiration = Some(expiration);
         self
     }
     #[inline(always)]
+    pub fn non_transferable(&mut self, non_transferable: bool) -> &mut Self {
+        self.non_transferable = Some(non_transferable);
+        self
+    }
+    #[inline(always)]
     pub fn seed(&mut self, seed: U8PrefixVec<u8>) -> &mut Self {
         self.seed = Some(seed);
         self
@@ -217,10 +263,16 @@ impl CreateRecordBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            class_stats: self.class_stats.expect("class_stats is not set"),
             authority: self.authority,
+            access_list: self.access_list,
         };
         let args = CreateRecordInstructionArgs {
             expiration: self.expiration.clone().expect("expiration is not set"),
+            non_transferable: self
+                .non_transferable
+                .clone()
+                .expect("non_transferable is not set"),
             seed: self.seed.clone().expect("seed is not set"),
             data: self.data.clone().expect("data is not set"),
         };
@@ -241,8 +293,12 @@ pub struct CreateRecordCpiAccounts<'a, 'b> {
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to create our record account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the class, created on the first record
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority for permissioned classes
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Optional record access list for permissioned classes
+    pub access_list: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `create_record` CPI instruction.
@@ -259,8 +315,12 @@ pub struct CreateRecordCpi<'a, 'b> {
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to create our record account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the class, created on the first record
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority for permissioned classes
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Optional record access list for permissioned classes
+    pub access_list: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateRecordInstructionArgs,
 }
@@ -278,7 +338,9 @@ impl<'a, 'b> CreateRecordCpi<'a, 'b> {
             class: accounts.class,
             record: accounts.record,
             system_program: accounts.system_program,
+            class_stats: accounts.class_stats,
             authority: accounts.authority,
+            access_list: accounts.access_list,
             __args: args,
         }
     }
@@ -316,7 +378,7 @@ impl<'a, 'b> CreateRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.owner.key,
             true,
@@ -337,6 +399,10 @@ impl<'a, 'b> CreateRecordCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.class_stats.key,
+            false,
+        ));
         if let Some(authority) = self.authority {
             accounts.push(solana_program::instruction::AccountMeta::new_readonly(
                 *authority.key,
@@ -348,6 +414,17 @@ impl<'a, 'b> CreateRecordCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(access_list) = self.access_list {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *access_list.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -364,16 +441,20 @@ impl<'a, 'b> CreateRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.class.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.class_stats.clone());
         if let Some(authority) = self.authority {
             account_infos.push(authority.clone());
         }
+        if let Some(access_list) = self.access_list {
+            account_infos.push(access_list.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -395,7 +476,9 @@ impl<'a, 'b> CreateRecordCpi<'a, 'b> {
 ///   2. `[writable]` class
 ///   3. `[writable]` record
 ///   4. `[]` system_program
-///   5. `[signer, optional]` authority
+///   5. `[writable]` class_stats
+///   6. `[signer, optional]` authority
+///   7. `[optional]` access_list
 #[derive(Clone, Debug)]
 pub struct CreateRecordCpiBuilder<'a, 'b> {
     instruction: Box<CreateRecordCpiBuilderInstruction<'a, 'b>>,
@@ -410,8 +493,11 @@ impl<'a, 'b> CreateRecordCpiBuilder<'a, 'b> {
             class: None,
             record: None,
             system_program: None,
+            class_stats: None,
             authority: None,
+            access_list: None,
             expiration: None,
+            non_transferable: None,
             seed: None,
             data: None,
             __remaining_accounts: Vec::new(),
@@ -454,6 +540,15 @@ impl<'a, 'b> CreateRecordCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Class stats account of the class, created on the first record
+    #[inline(always)]
+    pub fn class_stats(
+        &mut self,
+        class_stats: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_stats = Some(class_stats);
+        self
+    }
     /// `[optional account]`
     /// Optional authority for permissioned classes
     #[inline(always)]
@@ -464,12 +559,27 @@ impl<'a, 'b> CreateRecordCpiBuilder<'a, 'b> {
         self.instruction.authority = authority;
         self
     }
+    /// `[optional account]`
+    /// Optional record access list for permissioned classes
+    #[inline(always)]
+    pub fn access_list(
+        &mut self,
+        access_list: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.access_list = access_list;
+        self
+    }
     #[inline(always)]
     pub fn expiration(&mut self, expiration: i64) -> &mut Self {
         self.instruction.expiration = Some(expiration);
         self
     }
     #[inline(always)]
+    pub fn non_transferable(&mut self, non_transferable: bool) -> &mut Self {
+        self.instruction.non_transferable = Some(non_transferable);
+        self
+    }
+    #[inline(always)]
     pub fn seed(&mut self, seed: U8PrefixVec<u8>) -> &mut Self {
         self.instruction.seed = Some(seed);
         self
@@ -526,6 +636,11 @@ impl<'a, 'b> CreateRecordCpiBuilder<'a, 'b> {
                 .expiration
                 .clone()
                 .expect("expiration is not set"),
+            non_transferable: self
+                .instruction
+                .non_transferable
+                .clone()
+                .expect("non_transferable is not set"),
             seed: self.instruction.seed.clone().expect("seed is not set"),
             data: self.instruction.data.clone().expect("data is not set"),
         };
@@ -545,7 +660,14 @@ impl<'a, 'b> CreateRecordCpiBuilder<'a, 'b> {
                 .system_program
                 .expect("system_program is not set"),
 
+            class_stats: self
+                .instruction
+                .class_stats
+                .expect("class_stats is not set"),
+
             authority: self.instruction.authority,
+
+            access_list: self.instruction.access_list,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -563,8 +685,11 @@ struct CreateRecordCpiBuilderInstruction<'a, 'b> {
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    class_stats: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    access_list: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     expiration: Option<i64>,
+    non_transferable: Option<bool>,
     seed: Option<U8PrefixVec<u8>>,
     data: Option<RemainderVec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -17,6 +17,8 @@ pub struct DeleteRecord {
     pub payer: solana_program::pubkey::Pubkey,
     /// Record account to be updated
     pub record: solana_program::pubkey::Pubkey,
+    /// Class stats account of the record class
+    pub class_stats: solana_program::pubkey::Pubkey,
     /// Class account of the record
     pub class: Option<solana_program::pubkey::Pubkey>,
     /// Token2022 Program used to close the mint account
@@ -35,7 +37,7 @@ impl DeleteRecord {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -47,6 +49,10 @@ impl DeleteRecord {
             self.record,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.class_stats,
+            false,
+        ));
         if let Some(class) = self.class {
             accounts.push(solana_program::instruction::AccountMeta::new_readonly(
                 class, false,
@@ -112,14 +118,16 @@ impl Default for DeleteRecordInstructionData {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable, signer]` payer
 ///   2. `[writable]` record
-///   3. `[optional]` class
-///   4. `[optional]` token2022_program
-///   5. `[writable, optional]` mint
+///   3. `[writable]` class_stats
+///   4. `[optional]` class
+///   5. `[optional]` token2022_program
+///   6. `[writable, optional]` mint
 #[derive(Clone, Debug, Default)]
 pub struct DeleteRecordBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     payer: Option<solana_program::pubkey::Pubkey>,
     record: Option<solana_program::pubkey::Pubkey>,
+    class_stats: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
     token2022_program: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
@@ -148,6 +156,12 @@ impl DeleteRecordBuilder {
         self.record = Some(record);
         self
     }
+    /// Class stats account of the record class
+    #[inline(always)]
+    pub fn class_stats(&mut self, class_stats: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.class_stats = Some(class_stats);
+        self
+    }
     /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
@@ -196,6 +210,7 @@ impl DeleteRecordBuilder {
             authority: self.authority.expect("authority is not set"),
             payer: self.payer.expect("payer is not set"),
             record: self.record.expect("record is not set"),
+            class_stats: self.class_stats.expect("class_stats is not set"),
             class: self.class,
             token2022_program: self.token2022_program,
             mint: self.mint,
@@ -213,6 +228,8 @@ pub struct DeleteRecordCpiAccounts<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Record account to be updated
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the record class
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
     /// Class account of the record
     pub class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Token2022 Program used to close the mint account
@@ -231,6 +248,8 @@ pub struct DeleteRecordCpi<'a, 'b> {
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Record account to be updated
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the record class
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
     /// Class account of the record
     pub class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Token2022 Program used to close the mint account
@@ -249,6 +268,7 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             authority: accounts.authority,
             payer: accounts.payer,
             record: accounts.record,
+            class_stats: accounts.class_stats,
             class: accounts.class,
             token2022_program: accounts.token2022_program,
             mint: accounts.mint,
@@ -288,7 +308,7 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -301,6 +321,10 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             *self.record.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.class_stats.key,
+            false,
+        ));
         if let Some(class) = self.class {
             accounts.push(solana_program::instruction::AccountMeta::new_readonly(
                 *class.key, false,
@@ -346,11 +370,12 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.record.clone());
+        account_infos.push(self.class_stats.clone());
         if let Some(class) = self.class {
             account_infos.push(class.clone());
         }
@@ -379,9 +404,10 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable, signer]` payer
 ///   2. `[writable]` record
-///   3. `[optional]` class
-///   4. `[optional]` token2022_program
-///   5. `[writable, optional]` mint
+///   3. `[writable]` class_stats
+///   4. `[optional]` class
+///   5. `[optional]` token2022_program
+///   6. `[writable, optional]` mint
 #[derive(Clone, Debug)]
 pub struct DeleteRecordCpiBuilder<'a, 'b> {
     instruction: Box<DeleteRecordCpiBuilderInstruction<'a, 'b>>,
@@ -394,6 +420,7 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
             authority: None,
             payer: None,
             record: None,
+            class_stats: None,
             class: None,
             token2022_program: None,
             mint: None,
@@ -425,6 +452,15 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
         self.instruction.record = Some(record);
         self
     }
+    /// Class stats account of the record class
+    #[inline(always)]
+    pub fn class_stats(
+        &mut self,
+        class_stats: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_stats = Some(class_stats);
+        self
+    }
     /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
@@ -505,6 +541,11 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
 
             record: self.instruction.record.expect("record is not set"),
 
+            class_stats: self
+                .instruction
+                .class_stats
+                .expect("class_stats is not set"),
+
             class: self.instruction.class,
 
             token2022_program: self.instruction.token2022_program,
@@ -524,6 +565,7 @@ struct DeleteRecordCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    class_stats: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token2022_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
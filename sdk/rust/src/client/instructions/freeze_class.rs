@@ -11,24 +11,20 @@ use borsh::BorshSerialize;
 /// Accounts.
 #[derive(Debug)]
 pub struct FreezeClass {
-    /// Authority used to freeze/thaw a class
+    /// Authority used to freeze a class
     pub authority: solana_program::pubkey::Pubkey,
-    /// Class account to be frozen/thawed
+    /// Class account to be frozen
     pub class: solana_program::pubkey::Pubkey,
 }
 
 impl FreezeClass {
-    pub fn instruction(
-        &self,
-        args: FreezeClassInstructionArgs,
-    ) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(args, &[])
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
-        args: FreezeClassInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
@@ -40,9 +36,7 @@ impl FreezeClass {
             self.class, false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let mut data = borsh::to_vec(&FreezeClassInstructionData::new()).unwrap();
-        let mut args = borsh::to_vec(&args).unwrap();
-        data.append(&mut args);
+        let data = borsh::to_vec(&FreezeClassInstructionData::new()).unwrap();
 
         solana_program::instruction::Instruction {
             program_id: crate::SOLANA_RECORD_SERVICE_ID,
@@ -70,12 +64,6 @@ impl Default for FreezeClassInstructionData {
     }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct FreezeClassInstructionArgs {
-    pub is_frozen: bool,
-}
-
 /// Instruction builder for `FreezeClass`.
 ///
 /// ### Accounts:
@@ -86,7 +74,6 @@ pub struct FreezeClassInstructionArgs {
 pub struct FreezeClassBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
-    is_frozen: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -94,23 +81,18 @@ impl FreezeClassBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Authority used to freeze/thaw a class
+    /// Authority used to freeze a class
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
         self
     }
-    /// Class account to be frozen/thawed
+    /// Class account to be frozen
     #[inline(always)]
     pub fn class(&mut self, class: solana_program::pubkey::Pubkey) -> &mut Self {
         self.class = Some(class);
         self
     }
-    #[inline(always)]
-    pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
-        self.is_frozen = Some(is_frozen);
-        self
-    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -135,19 +117,16 @@ impl FreezeClassBuilder {
             authority: self.authority.expect("authority is not set"),
             class: self.class.expect("class is not set"),
         };
-        let args = FreezeClassInstructionArgs {
-            is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
-        };
 
-        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
     }
 }
 
 /// `freeze_class` CPI accounts.
 pub struct FreezeClassCpiAccounts<'a, 'b> {
-    /// Authority used to freeze/thaw a class
+    /// Authority used to freeze a class
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Class account to be frozen/thawed
+    /// Class account to be frozen
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
@@ -155,25 +134,21 @@ pub struct FreezeClassCpiAccounts<'a, 'b> {
 pub struct FreezeClassCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Authority used to freeze/thaw a class
+    /// Authority used to freeze a class
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Class account to be frozen/thawed
+    /// Class account to be frozen
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The arguments for the instruction.
-    pub __args: FreezeClassInstructionArgs,
 }
 
 impl<'a, 'b> FreezeClassCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: FreezeClassCpiAccounts<'a, 'b>,
-        args: FreezeClassInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
             authority: accounts.authority,
             class: accounts.class,
-            __args: args,
         }
     }
     #[inline(always)]
@@ -226,9 +201,7 @@ impl<'a, 'b> FreezeClassCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let mut data = borsh::to_vec(&FreezeClassInstructionData::new()).unwrap();
-        let mut args = borsh::to_vec(&self.__args).unwrap();
-        data.append(&mut args);
+        let data = borsh::to_vec(&FreezeClassInstructionData::new()).unwrap();
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::SOLANA_RECORD_SERVICE_ID,
@@ -268,12 +241,11 @@ impl<'a, 'b> FreezeClassCpiBuilder<'a, 'b> {
             __program: program,
             authority: None,
             class: None,
-            is_frozen: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
-    /// Authority used to freeze/thaw a class
+    /// Authority used to freeze a class
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -282,17 +254,12 @@ impl<'a, 'b> FreezeClassCpiBuilder<'a, 'b> {
         self.instruction.authority = Some(authority);
         self
     }
-    /// Class account to be frozen/thawed
+    /// Class account to be frozen
     #[inline(always)]
     pub fn class(&mut self, class: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.class = Some(class);
         self
     }
-    #[inline(always)]
-    pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
-        self.instruction.is_frozen = Some(is_frozen);
-        self
-    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -334,20 +301,12 @@ impl<'a, 'b> FreezeClassCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
-        let args = FreezeClassInstructionArgs {
-            is_frozen: self
-                .instruction
-                .is_frozen
-                .clone()
-                .expect("is_frozen is not set"),
-        };
         let instruction = FreezeClassCpi {
             __program: self.instruction.__program,
 
             authority: self.instruction.authority.expect("authority is not set"),
 
             class: self.instruction.class.expect("class is not set"),
-            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -361,7 +320,6 @@ struct FreezeClassCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    is_frozen: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -17,6 +17,8 @@ pub struct FreezeRecord {
     pub record: solana_program::pubkey::Pubkey,
     /// Class account of the record
     pub class: solana_program::pubkey::Pubkey,
+    /// Active record lease held by the authority
+    pub lease: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl FreezeRecord {
@@ -33,7 +35,7 @@ impl FreezeRecord {
         args: FreezeRecordInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -45,6 +47,16 @@ impl FreezeRecord {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.class, false,
         ));
+        if let Some(lease) = self.lease {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                lease, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&FreezeRecordInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -89,11 +101,13 @@ pub struct FreezeRecordInstructionArgs {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
 ///   2. `[]` class
+///   3. `[optional]` lease
 #[derive(Clone, Debug, Default)]
 pub struct FreezeRecordBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     record: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
+    lease: Option<solana_program::pubkey::Pubkey>,
     is_frozen: Option<bool>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -120,6 +134,13 @@ impl FreezeRecordBuilder {
         self.class = Some(class);
         self
     }
+    /// `[optional account]`
+    /// Active record lease held by the authority
+    #[inline(always)]
+    pub fn lease(&mut self, lease: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.lease = lease;
+        self
+    }
     #[inline(always)]
     pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
         self.is_frozen = Some(is_frozen);
@@ -149,6 +170,7 @@ impl FreezeRecordBuilder {
             authority: self.authority.expect("authority is not set"),
             record: self.record.expect("record is not set"),
             class: self.class.expect("class is not set"),
+            lease: self.lease,
         };
         let args = FreezeRecordInstructionArgs {
             is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
@@ -166,6 +188,8 @@ pub struct FreezeRecordCpiAccounts<'a, 'b> {
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
     /// Class account of the record
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Active record lease held by the authority
+    pub lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `freeze_record` CPI instruction.
@@ -178,6 +202,8 @@ pub struct FreezeRecordCpi<'a, 'b> {
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
     /// Class account of the record
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Active record lease held by the authority
+    pub lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: FreezeRecordInstructionArgs,
 }
@@ -193,6 +219,7 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             authority: accounts.authority,
             record: accounts.record,
             class: accounts.class,
+            lease: accounts.lease,
             __args: args,
         }
     }
@@ -230,7 +257,7 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -243,6 +270,16 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             *self.class.key,
             false,
         ));
+        if let Some(lease) = self.lease {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *lease.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -259,11 +296,14 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
+        if let Some(lease) = self.lease {
+            account_infos.push(lease.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -283,6 +323,7 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
 ///   2. `[]` class
+///   3. `[optional]` lease
 #[derive(Clone, Debug)]
 pub struct FreezeRecordCpiBuilder<'a, 'b> {
     instruction: Box<FreezeRecordCpiBuilderInstruction<'a, 'b>>,
@@ -295,6 +336,7 @@ impl<'a, 'b> FreezeRecordCpiBuilder<'a, 'b> {
             authority: None,
             record: None,
             class: None,
+            lease: None,
             is_frozen: None,
             __remaining_accounts: Vec::new(),
         });
@@ -324,6 +366,16 @@ impl<'a, 'b> FreezeRecordCpiBuilder<'a, 'b> {
         self.instruction.class = Some(class);
         self
     }
+    /// `[optional account]`
+    /// Active record lease held by the authority
+    #[inline(always)]
+    pub fn lease(
+        &mut self,
+        lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lease = lease;
+        self
+    }
     #[inline(always)]
     pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
         self.instruction.is_frozen = Some(is_frozen);
@@ -385,6 +437,8 @@ impl<'a, 'b> FreezeRecordCpiBuilder<'a, 'b> {
             record: self.instruction.record.expect("record is not set"),
 
             class: self.instruction.class.expect("class is not set"),
+
+            lease: self.instruction.lease,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -400,6 +454,7 @@ struct FreezeRecordCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     is_frozen: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -33,6 +33,8 @@ pub struct MintTokenizedRecord {
     pub token2022: solana_program::pubkey::Pubkey,
     /// System Program used to create our token
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Wallet receiving the record token, defaults to the record owner
+    pub recipient: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl MintTokenizedRecord {
@@ -45,7 +47,7 @@ impl MintTokenizedRecord {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.owner, false,
         ));
@@ -85,6 +87,16 @@ impl MintTokenizedRecord {
             self.system_program,
             false,
         ));
+        if let Some(recipient) = self.recipient {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                recipient, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&MintTokenizedRecordInstructionData::new()).unwrap();
 
@@ -129,6 +141,7 @@ impl Default for MintTokenizedRecordInstructionData {
 ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
 ///   9. `[optional]` token2022 (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   11. `[optional]` recipient
 #[derive(Clone, Debug, Default)]
 pub struct MintTokenizedRecordBuilder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -142,6 +155,7 @@ pub struct MintTokenizedRecordBuilder {
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
     token2022: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    recipient: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -221,6 +235,13 @@ impl MintTokenizedRecordBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Wallet receiving the record token, defaults to the record owner
+    #[inline(always)]
+    pub fn recipient(&mut self, recipient: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.recipient = recipient;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -259,6 +280,7 @@ impl MintTokenizedRecordBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            recipient: self.recipient,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -289,6 +311,8 @@ pub struct MintTokenizedRecordCpiAccounts<'a, 'b> {
     pub token2022: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to create our token
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Wallet receiving the record token, defaults to the record owner
+    pub recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `mint_tokenized_record` CPI instruction.
@@ -317,6 +341,8 @@ pub struct MintTokenizedRecordCpi<'a, 'b> {
     pub token2022: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to create our token
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Wallet receiving the record token, defaults to the record owner
+    pub recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
@@ -337,6 +363,7 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             associated_token_program: accounts.associated_token_program,
             token2022: accounts.token2022,
             system_program: accounts.system_program,
+            recipient: accounts.recipient,
         }
     }
     #[inline(always)]
@@ -373,7 +400,7 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.owner.key,
             false,
@@ -418,6 +445,17 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(recipient) = self.recipient {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *recipient.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -432,7 +470,7 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.payer.clone());
@@ -445,6 +483,9 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
         account_infos.push(self.associated_token_program.clone());
         account_infos.push(self.token2022.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(recipient) = self.recipient {
+            account_infos.push(recipient.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -472,6 +513,7 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
 ///   8. `[]` associated_token_program
 ///   9. `[]` token2022
 ///   10. `[]` system_program
+///   11. `[optional]` recipient
 #[derive(Clone, Debug)]
 pub struct MintTokenizedRecordCpiBuilder<'a, 'b> {
     instruction: Box<MintTokenizedRecordCpiBuilderInstruction<'a, 'b>>,
@@ -492,6 +534,7 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
             associated_token_program: None,
             token2022: None,
             system_program: None,
+            recipient: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -580,6 +623,16 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Wallet receiving the record token, defaults to the record owner
+    #[inline(always)]
+    pub fn recipient(
+        &mut self,
+        recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.recipient = recipient;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -654,6 +707,8 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            recipient: self.instruction.recipient,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -676,6 +731,7 @@ struct MintTokenizedRecordCpiBuilderInstruction<'a, 'b> {
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token2022: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,36 +5,90 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_additional_metadata;
+pub(crate) mod r#batch_create_record;
+pub(crate) mod r#batch_delete_record;
 pub(crate) mod r#burn_tokenized_record;
+pub(crate) mod r#clone_record;
 pub(crate) mod r#create_class;
 pub(crate) mod r#create_record;
+pub(crate) mod r#create_record_access_list;
+pub(crate) mod r#create_record_lease;
+pub(crate) mod r#create_record_snapshot;
 pub(crate) mod r#create_record_tokenizable;
+pub(crate) mod r#create_royalty_config;
+pub(crate) mod r#delete_class;
 pub(crate) mod r#delete_record;
+pub(crate) mod r#delete_record_access_list;
+pub(crate) mod r#expire_record_lease;
+pub(crate) mod r#extend_record_expiry;
 pub(crate) mod r#freeze_class;
 pub(crate) mod r#freeze_record;
 pub(crate) mod r#freeze_tokenized_record;
+pub(crate) mod r#grant_record_lease;
 pub(crate) mod r#mint_tokenized_record;
+pub(crate) mod r#reclaim_expired_record;
+pub(crate) mod r#remove_additional_metadata;
+pub(crate) mod r#rename_record;
+pub(crate) mod r#renew_record_lease;
+pub(crate) mod r#resize_record_data_only;
+pub(crate) mod r#revoke_record_lease;
 pub(crate) mod r#transfer_record;
+pub(crate) mod r#transfer_record_to_class;
 pub(crate) mod r#transfer_tokenized_record;
+pub(crate) mod r#unfreeze_class;
 pub(crate) mod r#update_class_authority;
 pub(crate) mod r#update_class_metadata;
+pub(crate) mod r#update_class_permission;
+pub(crate) mod r#update_group_metadata;
 pub(crate) mod r#update_record;
+pub(crate) mod r#update_record_access_list;
 pub(crate) mod r#update_record_expiry;
 pub(crate) mod r#update_record_tokenizable;
+pub(crate) mod r#update_record_with_revision;
+pub(crate) mod r#update_royalty_config;
+pub(crate) mod r#update_tokenized_record_metadata;
 
+pub use self::r#add_additional_metadata::*;
+pub use self::r#batch_create_record::*;
+pub use self::r#batch_delete_record::*;
 pub use self::r#burn_tokenized_record::*;
+pub use self::r#clone_record::*;
 pub use self::r#create_class::*;
 pub use self::r#create_record::*;
+pub use self::r#create_record_access_list::*;
+pub use self::r#create_record_lease::*;
+pub use self::r#create_record_snapshot::*;
 pub use self::r#create_record_tokenizable::*;
+pub use self::r#create_royalty_config::*;
+pub use self::r#delete_class::*;
 pub use self::r#delete_record::*;
+pub use self::r#delete_record_access_list::*;
+pub use self::r#expire_record_lease::*;
+pub use self::r#extend_record_expiry::*;
 pub use self::r#freeze_class::*;
 pub use self::r#freeze_record::*;
 pub use self::r#freeze_tokenized_record::*;
+pub use self::r#grant_record_lease::*;
 pub use self::r#mint_tokenized_record::*;
+pub use self::r#reclaim_expired_record::*;
+pub use self::r#remove_additional_metadata::*;
+pub use self::r#rename_record::*;
+pub use self::r#renew_record_lease::*;
+pub use self::r#resize_record_data_only::*;
+pub use self::r#revoke_record_lease::*;
 pub use self::r#transfer_record::*;
+pub use self::r#transfer_record_to_class::*;
 pub use self::r#transfer_tokenized_record::*;
+pub use self::r#unfreeze_class::*;
 pub use self::r#update_class_authority::*;
 pub use self::r#update_class_metadata::*;
+pub use self::r#update_class_permission::*;
+pub use self::r#update_group_metadata::*;
 pub use self::r#update_record::*;
+pub use self::r#update_record_access_list::*;
 pub use self::r#update_record_expiry::*;
 pub use self::r#update_record_tokenizable::*;
+pub use self::r#update_record_with_revision::*;
+pub use self::r#update_royalty_config::*;
+pub use self::r#update_tokenized_record_metadata::*;
@@ -16,8 +16,14 @@ pub struct TransferRecord {
     pub authority: solana_program::pubkey::Pubkey,
     /// Record account to be updated
     pub record: solana_program::pubkey::Pubkey,
-    /// Class account of the record
+    /// Class account of the record, required to pay the transfer fee
     pub class: Option<solana_program::pubkey::Pubkey>,
+    /// Account paying the transfer fee, required when the class charges a transfer fee
+    pub payer: Option<solana_program::pubkey::Pubkey>,
+    /// Fee destination of the class, required when the class charges a transfer fee
+    pub fee_destination: Option<solana_program::pubkey::Pubkey>,
+    /// System Program used to pay the transfer fee
+    pub system_program: solana_program::pubkey::Pubkey,
 }
 
 impl TransferRecord {
@@ -34,7 +40,7 @@ impl TransferRecord {
         args: TransferRecordInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -53,6 +59,29 @@ impl TransferRecord {
                 false,
             ));
         }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(fee_destination) = self.fee_destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&TransferRecordInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -97,11 +126,17 @@ pub struct TransferRecordInstructionArgs {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
 ///   2. `[optional]` class
+///   3. `[writable, signer, optional]` payer
+///   4. `[writable, optional]` fee_destination
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct TransferRecordBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     record: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    fee_destination: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
     new_owner: Option<Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -123,12 +158,36 @@ impl TransferRecordBuilder {
         self
     }
     /// `[optional account]`
-    /// Class account of the record
+    /// Class account of the record, required to pay the transfer fee
     #[inline(always)]
     pub fn class(&mut self, class: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.class = class;
         self
     }
+    /// `[optional account]`
+    /// Account paying the transfer fee, required when the class charges a transfer fee
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// Fee destination of the class, required when the class charges a transfer fee
+    #[inline(always)]
+    pub fn fee_destination(
+        &mut self,
+        fee_destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_destination = fee_destination;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to pay the transfer fee
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
     #[inline(always)]
     pub fn new_owner(&mut self, new_owner: Pubkey) -> &mut Self {
         self.new_owner = Some(new_owner);
@@ -158,6 +217,11 @@ impl TransferRecordBuilder {
             authority: self.authority.expect("authority is not set"),
             record: self.record.expect("record is not set"),
             class: self.class,
+            payer: self.payer,
+            fee_destination: self.fee_destination,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
         let args = TransferRecordInstructionArgs {
             new_owner: self.new_owner.clone().expect("new_owner is not set"),
@@ -173,8 +237,14 @@ pub struct TransferRecordCpiAccounts<'a, 'b> {
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Record account to be updated
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Class account of the record
+    /// Class account of the record, required to pay the transfer fee
     pub class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Account paying the transfer fee, required when the class charges a transfer fee
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Fee destination of the class, required when the class charges a transfer fee
+    pub fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System Program used to pay the transfer fee
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `transfer_record` CPI instruction.
@@ -185,8 +255,14 @@ pub struct TransferRecordCpi<'a, 'b> {
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Record account to be updated
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Class account of the record
+    /// Class account of the record, required to pay the transfer fee
     pub class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Account paying the transfer fee, required when the class charges a transfer fee
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Fee destination of the class, required when the class charges a transfer fee
+    pub fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System Program used to pay the transfer fee
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: TransferRecordInstructionArgs,
 }
@@ -202,6 +278,9 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             authority: accounts.authority,
             record: accounts.record,
             class: accounts.class,
+            payer: accounts.payer,
+            fee_destination: accounts.fee_destination,
+            system_program: accounts.system_program,
             __args: args,
         }
     }
@@ -239,7 +318,7 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -258,6 +337,31 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer.key, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(fee_destination) = self.fee_destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -274,13 +378,20 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.record.clone());
         if let Some(class) = self.class {
             account_infos.push(class.clone());
         }
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(fee_destination) = self.fee_destination {
+            account_infos.push(fee_destination.clone());
+        }
+        account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -300,6 +411,9 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
 ///   2. `[optional]` class
+///   3. `[writable, signer, optional]` payer
+///   4. `[writable, optional]` fee_destination
+///   5. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct TransferRecordCpiBuilder<'a, 'b> {
     instruction: Box<TransferRecordCpiBuilderInstruction<'a, 'b>>,
@@ -312,6 +426,9 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
             authority: None,
             record: None,
             class: None,
+            payer: None,
+            fee_destination: None,
+            system_program: None,
             new_owner: None,
             __remaining_accounts: Vec::new(),
         });
@@ -336,7 +453,7 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// Class account of the record
+    /// Class account of the record, required to pay the transfer fee
     #[inline(always)]
     pub fn class(
         &mut self,
@@ -345,6 +462,35 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
         self.instruction.class = class;
         self
     }
+    /// `[optional account]`
+    /// Account paying the transfer fee, required when the class charges a transfer fee
+    #[inline(always)]
+    pub fn payer(
+        &mut self,
+        payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// Fee destination of the class, required when the class charges a transfer fee
+    #[inline(always)]
+    pub fn fee_destination(
+        &mut self,
+        fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_destination = fee_destination;
+        self
+    }
+    /// System Program used to pay the transfer fee
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
     #[inline(always)]
     pub fn new_owner(&mut self, new_owner: Pubkey) -> &mut Self {
         self.instruction.new_owner = Some(new_owner);
@@ -406,6 +552,15 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
             record: self.instruction.record.expect("record is not set"),
 
             class: self.instruction.class,
+
+            payer: self.instruction.payer,
+
+            fee_destination: self.instruction.fee_destination,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -421,6 +576,9 @@ struct TransferRecordCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     new_owner: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
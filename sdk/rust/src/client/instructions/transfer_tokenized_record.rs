@@ -23,21 +23,35 @@ pub struct TransferTokenizedRecord {
     pub record: solana_program::pubkey::Pubkey,
     /// Token2022 Program used to freeze/unfreeze the tokenized record
     pub token2022: solana_program::pubkey::Pubkey,
-    /// Class account of the record
+    /// Class account of the record, required with the royalty config and to pay the transfer fee
     pub class: Option<solana_program::pubkey::Pubkey>,
+    /// Royalty config account of the record class
+    pub royalty_config: Option<solana_program::pubkey::Pubkey>,
+    /// Account paying the royalty and the transfer fee, required when the class charges either
+    pub payer: Option<solana_program::pubkey::Pubkey>,
+    /// Royalty destination of the royalty config, required when the class charges a royalty
+    pub destination: Option<solana_program::pubkey::Pubkey>,
+    /// System Program used to pay the royalty and the transfer fee
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// Fee destination of the class, required when the class charges a transfer fee
+    pub fee_destination: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl TransferTokenizedRecord {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: TransferTokenizedRecordInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: TransferTokenizedRecordInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -71,8 +85,55 @@ impl TransferTokenizedRecord {
                 false,
             ));
         }
+        if let Some(royalty_config) = self.royalty_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                royalty_config,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(payer, true));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(fee_destination) = self.fee_destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_destination,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&TransferTokenizedRecordInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&TransferTokenizedRecordInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::SOLANA_RECORD_SERVICE_ID,
@@ -100,6 +161,37 @@ impl Default for TransferTokenizedRecordInstructionData {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransferTokenizedRecordInstructionArgs {
+    pub token_value_lamports: Option<u64>,
+}
+
+/// `token_value_lamports` is a remainder option, omitted from the instruction data when `None`
+impl BorshSerialize for TransferTokenizedRecordInstructionArgs {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self.token_value_lamports {
+            Some(token_value_lamports) => token_value_lamports.serialize(writer),
+            None => Ok(()),
+        }
+    }
+}
+
+impl BorshDeserialize for TransferTokenizedRecordInstructionArgs {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut remainder = Vec::new();
+        reader.read_to_end(&mut remainder)?;
+        let token_value_lamports = if remainder.is_empty() {
+            None
+        } else {
+            Some(u64::try_from_slice(&remainder)?)
+        };
+        Ok(Self {
+            token_value_lamports,
+        })
+    }
+}
+
 /// Instruction builder for `TransferTokenizedRecord`.
 ///
 /// ### Accounts:
@@ -111,6 +203,11 @@ impl Default for TransferTokenizedRecordInstructionData {
 ///   4. `[]` record
 ///   5. `[optional]` token2022 (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   6. `[optional]` class
+///   7. `[optional]` royalty_config
+///   8. `[writable, signer, optional]` payer
+///   9. `[writable, optional]` destination
+///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   11. `[writable, optional]` fee_destination
 #[derive(Clone, Debug, Default)]
 pub struct TransferTokenizedRecordBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
@@ -120,6 +217,12 @@ pub struct TransferTokenizedRecordBuilder {
     record: Option<solana_program::pubkey::Pubkey>,
     token2022: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
+    royalty_config: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    fee_destination: Option<solana_program::pubkey::Pubkey>,
+    token_value_lamports: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -168,12 +271,62 @@ impl TransferTokenizedRecordBuilder {
         self
     }
     /// `[optional account]`
-    /// Class account of the record
+    /// Class account of the record, required with the royalty config and to pay the transfer fee
     #[inline(always)]
     pub fn class(&mut self, class: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.class = class;
         self
     }
+    /// `[optional account]`
+    /// Royalty config account of the record class
+    #[inline(always)]
+    pub fn royalty_config(
+        &mut self,
+        royalty_config: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.royalty_config = royalty_config;
+        self
+    }
+    /// `[optional account]`
+    /// Account paying the royalty and the transfer fee, required when the class charges either
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// Royalty destination of the royalty config, required when the class charges a royalty
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.destination = destination;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to pay the royalty and the transfer fee
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Fee destination of the class, required when the class charges a transfer fee
+    #[inline(always)]
+    pub fn fee_destination(
+        &mut self,
+        fee_destination: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.fee_destination = fee_destination;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn token_value_lamports(&mut self, token_value_lamports: u64) -> &mut Self {
+        self.token_value_lamports = Some(token_value_lamports);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -206,9 +359,19 @@ impl TransferTokenizedRecordBuilder {
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
             class: self.class,
+            royalty_config: self.royalty_config,
+            payer: self.payer,
+            destination: self.destination,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            fee_destination: self.fee_destination,
+        };
+        let args = TransferTokenizedRecordInstructionArgs {
+            token_value_lamports: self.token_value_lamports.clone(),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -226,8 +389,18 @@ pub struct TransferTokenizedRecordCpiAccounts<'a, 'b> {
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token2022 Program used to freeze/unfreeze the tokenized record
     pub token2022: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Class account of the record
+    /// Class account of the record, required with the royalty config and to pay the transfer fee
     pub class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Royalty config account of the record class
+    pub royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Account paying the royalty and the transfer fee, required when the class charges either
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Royalty destination of the royalty config, required when the class charges a royalty
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System Program used to pay the royalty and the transfer fee
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee destination of the class, required when the class charges a transfer fee
+    pub fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `transfer_tokenized_record` CPI instruction.
@@ -246,14 +419,27 @@ pub struct TransferTokenizedRecordCpi<'a, 'b> {
     pub record: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token2022 Program used to freeze/unfreeze the tokenized record
     pub token2022: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Class account of the record
+    /// Class account of the record, required with the royalty config and to pay the transfer fee
     pub class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Royalty config account of the record class
+    pub royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Account paying the royalty and the transfer fee, required when the class charges either
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Royalty destination of the royalty config, required when the class charges a royalty
+    pub destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// System Program used to pay the royalty and the transfer fee
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Fee destination of the class, required when the class charges a transfer fee
+    pub fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: TransferTokenizedRecordInstructionArgs,
 }
 
 impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: TransferTokenizedRecordCpiAccounts<'a, 'b>,
+        args: TransferTokenizedRecordInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -264,6 +450,12 @@ impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
             record: accounts.record,
             token2022: accounts.token2022,
             class: accounts.class,
+            royalty_config: accounts.royalty_config,
+            payer: accounts.payer,
+            destination: accounts.destination,
+            system_program: accounts.system_program,
+            fee_destination: accounts.fee_destination,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -300,7 +492,7 @@ impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -335,6 +527,53 @@ impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(royalty_config) = self.royalty_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *royalty_config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer.key, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(destination) = self.destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(fee_destination) = self.fee_destination {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_destination.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -342,14 +581,16 @@ impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&TransferTokenizedRecordInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&TransferTokenizedRecordInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::SOLANA_RECORD_SERVICE_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint.clone());
@@ -360,6 +601,19 @@ impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
         if let Some(class) = self.class {
             account_infos.push(class.clone());
         }
+        if let Some(royalty_config) = self.royalty_config {
+            account_infos.push(royalty_config.clone());
+        }
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
+        if let Some(destination) = self.destination {
+            account_infos.push(destination.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        if let Some(fee_destination) = self.fee_destination {
+            account_infos.push(fee_destination.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -383,6 +637,11 @@ impl<'a, 'b> TransferTokenizedRecordCpi<'a, 'b> {
 ///   4. `[]` record
 ///   5. `[]` token2022
 ///   6. `[optional]` class
+///   7. `[optional]` royalty_config
+///   8. `[writable, signer, optional]` payer
+///   9. `[writable, optional]` destination
+///   10. `[]` system_program
+///   11. `[writable, optional]` fee_destination
 #[derive(Clone, Debug)]
 pub struct TransferTokenizedRecordCpiBuilder<'a, 'b> {
     instruction: Box<TransferTokenizedRecordCpiBuilderInstruction<'a, 'b>>,
@@ -399,6 +658,12 @@ impl<'a, 'b> TransferTokenizedRecordCpiBuilder<'a, 'b> {
             record: None,
             token2022: None,
             class: None,
+            royalty_config: None,
+            payer: None,
+            destination: None,
+            system_program: None,
+            fee_destination: None,
+            token_value_lamports: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -455,7 +720,7 @@ impl<'a, 'b> TransferTokenizedRecordCpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// Class account of the record
+    /// Class account of the record, required with the royalty config and to pay the transfer fee
     #[inline(always)]
     pub fn class(
         &mut self,
@@ -464,6 +729,61 @@ impl<'a, 'b> TransferTokenizedRecordCpiBuilder<'a, 'b> {
         self.instruction.class = class;
         self
     }
+    /// `[optional account]`
+    /// Royalty config account of the record class
+    #[inline(always)]
+    pub fn royalty_config(
+        &mut self,
+        royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.royalty_config = royalty_config;
+        self
+    }
+    /// `[optional account]`
+    /// Account paying the royalty and the transfer fee, required when the class charges either
+    #[inline(always)]
+    pub fn payer(
+        &mut self,
+        payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
+    /// `[optional account]`
+    /// Royalty destination of the royalty config, required when the class charges a royalty
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.destination = destination;
+        self
+    }
+    /// System Program used to pay the royalty and the transfer fee
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Fee destination of the class, required when the class charges a transfer fee
+    #[inline(always)]
+    pub fn fee_destination(
+        &mut self,
+        fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.fee_destination = fee_destination;
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn token_value_lamports(&mut self, token_value_lamports: u64) -> &mut Self {
+        self.instruction.token_value_lamports = Some(token_value_lamports);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -505,6 +825,9 @@ impl<'a, 'b> TransferTokenizedRecordCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = TransferTokenizedRecordInstructionArgs {
+            token_value_lamports: self.instruction.token_value_lamports.clone(),
+        };
         let instruction = TransferTokenizedRecordCpi {
             __program: self.instruction.__program,
 
@@ -527,6 +850,20 @@ impl<'a, 'b> TransferTokenizedRecordCpiBuilder<'a, 'b> {
             token2022: self.instruction.token2022.expect("token2022 is not set"),
 
             class: self.instruction.class,
+
+            royalty_config: self.instruction.royalty_config,
+
+            payer: self.instruction.payer,
+
+            destination: self.instruction.destination,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            fee_destination: self.instruction.fee_destination,
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -545,6 +882,12 @@ struct TransferTokenizedRecordCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token2022: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    royalty_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_value_lamports: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
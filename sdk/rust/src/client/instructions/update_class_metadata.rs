@@ -8,6 +8,8 @@
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use kaigan::types::RemainderStr;
+use kaigan::types::U8PrefixString;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 #[derive(Debug)]
@@ -85,6 +87,11 @@ impl Default for UpdateClassMetadataInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateClassMetadataInstructionArgs {
+    pub max_record_size: u32,
+    pub max_records: u32,
+    pub transfer_fee_bps: u16,
+    pub fee_destination: Pubkey,
+    pub metadata_schema_uri: U8PrefixString,
     pub metadata: RemainderStr,
 }
 
@@ -102,6 +109,11 @@ pub struct UpdateClassMetadataBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    max_record_size: Option<u32>,
+    max_records: Option<u32>,
+    transfer_fee_bps: Option<u16>,
+    fee_destination: Option<Pubkey>,
+    metadata_schema_uri: Option<U8PrefixString>,
     metadata: Option<RemainderStr>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -136,6 +148,31 @@ impl UpdateClassMetadataBuilder {
         self
     }
     #[inline(always)]
+    pub fn max_record_size(&mut self, max_record_size: u32) -> &mut Self {
+        self.max_record_size = Some(max_record_size);
+        self
+    }
+    #[inline(always)]
+    pub fn max_records(&mut self, max_records: u32) -> &mut Self {
+        self.max_records = Some(max_records);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_fee_bps(&mut self, transfer_fee_bps: u16) -> &mut Self {
+        self.transfer_fee_bps = Some(transfer_fee_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_destination(&mut self, fee_destination: Pubkey) -> &mut Self {
+        self.fee_destination = Some(fee_destination);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata_schema_uri(&mut self, metadata_schema_uri: U8PrefixString) -> &mut Self {
+        self.metadata_schema_uri = Some(metadata_schema_uri);
+        self
+    }
+    #[inline(always)]
     pub fn metadata(&mut self, metadata: RemainderStr) -> &mut Self {
         self.metadata = Some(metadata);
         self
@@ -169,6 +206,23 @@ impl UpdateClassMetadataBuilder {
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
         let args = UpdateClassMetadataInstructionArgs {
+            max_record_size: self
+                .max_record_size
+                .clone()
+                .expect("max_record_size is not set"),
+            max_records: self.max_records.clone().expect("max_records is not set"),
+            transfer_fee_bps: self
+                .transfer_fee_bps
+                .clone()
+                .expect("transfer_fee_bps is not set"),
+            fee_destination: self
+                .fee_destination
+                .clone()
+                .expect("fee_destination is not set"),
+            metadata_schema_uri: self
+                .metadata_schema_uri
+                .clone()
+                .expect("metadata_schema_uri is not set"),
             metadata: self.metadata.clone().expect("metadata is not set"),
         };
 
@@ -325,6 +379,11 @@ impl<'a, 'b> UpdateClassMetadataCpiBuilder<'a, 'b> {
             payer: None,
             class: None,
             system_program: None,
+            max_record_size: None,
+            max_records: None,
+            transfer_fee_bps: None,
+            fee_destination: None,
+            metadata_schema_uri: None,
             metadata: None,
             __remaining_accounts: Vec::new(),
         });
@@ -361,6 +420,31 @@ impl<'a, 'b> UpdateClassMetadataCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn max_record_size(&mut self, max_record_size: u32) -> &mut Self {
+        self.instruction.max_record_size = Some(max_record_size);
+        self
+    }
+    #[inline(always)]
+    pub fn max_records(&mut self, max_records: u32) -> &mut Self {
+        self.instruction.max_records = Some(max_records);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_fee_bps(&mut self, transfer_fee_bps: u16) -> &mut Self {
+        self.instruction.transfer_fee_bps = Some(transfer_fee_bps);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_destination(&mut self, fee_destination: Pubkey) -> &mut Self {
+        self.instruction.fee_destination = Some(fee_destination);
+        self
+    }
+    #[inline(always)]
+    pub fn metadata_schema_uri(&mut self, metadata_schema_uri: U8PrefixString) -> &mut Self {
+        self.instruction.metadata_schema_uri = Some(metadata_schema_uri);
+        self
+    }
+    #[inline(always)]
     pub fn metadata(&mut self, metadata: RemainderStr) -> &mut Self {
         self.instruction.metadata = Some(metadata);
         self
@@ -407,6 +491,31 @@ impl<'a, 'b> UpdateClassMetadataCpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = UpdateClassMetadataInstructionArgs {
+            max_record_size: self
+                .instruction
+                .max_record_size
+                .clone()
+                .expect("max_record_size is not set"),
+            max_records: self
+                .instruction
+                .max_records
+                .clone()
+                .expect("max_records is not set"),
+            transfer_fee_bps: self
+                .instruction
+                .transfer_fee_bps
+                .clone()
+                .expect("transfer_fee_bps is not set"),
+            fee_destination: self
+                .instruction
+                .fee_destination
+                .clone()
+                .expect("fee_destination is not set"),
+            metadata_schema_uri: self
+                .instruction
+                .metadata_schema_uri
+                .clone()
+                .expect("metadata_schema_uri is not set"),
             metadata: self
                 .instruction
                 .metadata
@@ -442,6 +551,11 @@ struct UpdateClassMetadataCpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    max_record_size: Option<u32>,
+    max_records: Option<u32>,
+    transfer_fee_bps: Option<u16>,
+    fee_destination: Option<Pubkey>,
+    metadata_schema_uri: Option<U8PrefixString>,
     metadata: Option<RemainderStr>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
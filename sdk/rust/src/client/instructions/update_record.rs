@@ -22,6 +22,10 @@ pub struct UpdateRecord {
     pub class: solana_program::pubkey::Pubkey,
     /// System Program used to extend our record account
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Class stats account of the record class
+    pub class_stats: solana_program::pubkey::Pubkey,
+    /// Active record lease held by the authority
+    pub lease: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl UpdateRecord {
@@ -38,7 +42,7 @@ impl UpdateRecord {
         args: UpdateRecordInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -57,6 +61,20 @@ impl UpdateRecord {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.class_stats,
+            false,
+        ));
+        if let Some(lease) = self.lease {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                lease, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UpdateRecordInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -103,6 +121,8 @@ pub struct UpdateRecordInstructionArgs {
 ///   2. `[writable]` record
 ///   3. `[]` class
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable]` class_stats
+///   6. `[optional]` lease
 #[derive(Clone, Debug, Default)]
 pub struct UpdateRecordBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
@@ -110,6 +130,8 @@ pub struct UpdateRecordBuilder {
     record: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    class_stats: Option<solana_program::pubkey::Pubkey>,
+    lease: Option<solana_program::pubkey::Pubkey>,
     data: Option<RemainderVec<u8>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -149,6 +171,19 @@ impl UpdateRecordBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Class stats account of the record class
+    #[inline(always)]
+    pub fn class_stats(&mut self, class_stats: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.class_stats = Some(class_stats);
+        self
+    }
+    /// `[optional account]`
+    /// Active record lease held by the authority
+    #[inline(always)]
+    pub fn lease(&mut self, lease: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.lease = lease;
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: RemainderVec<u8>) -> &mut Self {
         self.data = Some(data);
@@ -182,6 +217,8 @@ impl UpdateRecordBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            class_stats: self.class_stats.expect("class_stats is not set"),
+            lease: self.lease,
         };
         let args = UpdateRecordInstructionArgs {
             data: self.data.clone().expect("data is not set"),
@@ -203,6 +240,10 @@ pub struct UpdateRecordCpiAccounts<'a, 'b> {
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to extend our record account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the record class
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Active record lease held by the authority
+    pub lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `update_record` CPI instruction.
@@ -219,6 +260,10 @@ pub struct UpdateRecordCpi<'a, 'b> {
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to extend our record account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Class stats account of the record class
+    pub class_stats: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Active record lease held by the authority
+    pub lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateRecordInstructionArgs,
 }
@@ -236,6 +281,8 @@ impl<'a, 'b> UpdateRecordCpi<'a, 'b> {
             record: accounts.record,
             class: accounts.class,
             system_program: accounts.system_program,
+            class_stats: accounts.class_stats,
+            lease: accounts.lease,
             __args: args,
         }
     }
@@ -273,7 +320,7 @@ impl<'a, 'b> UpdateRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -294,6 +341,20 @@ impl<'a, 'b> UpdateRecordCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.class_stats.key,
+            false,
+        ));
+        if let Some(lease) = self.lease {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *lease.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -310,13 +371,17 @@ impl<'a, 'b> UpdateRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.class_stats.clone());
+        if let Some(lease) = self.lease {
+            account_infos.push(lease.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -338,6 +403,8 @@ impl<'a, 'b> UpdateRecordCpi<'a, 'b> {
 ///   2. `[writable]` record
 ///   3. `[]` class
 ///   4. `[]` system_program
+///   5. `[writable]` class_stats
+///   6. `[optional]` lease
 #[derive(Clone, Debug)]
 pub struct UpdateRecordCpiBuilder<'a, 'b> {
     instruction: Box<UpdateRecordCpiBuilderInstruction<'a, 'b>>,
@@ -352,6 +419,8 @@ impl<'a, 'b> UpdateRecordCpiBuilder<'a, 'b> {
             record: None,
             class: None,
             system_program: None,
+            class_stats: None,
+            lease: None,
             data: None,
             __remaining_accounts: Vec::new(),
         });
@@ -396,6 +465,25 @@ impl<'a, 'b> UpdateRecordCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Class stats account of the record class
+    #[inline(always)]
+    pub fn class_stats(
+        &mut self,
+        class_stats: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_stats = Some(class_stats);
+        self
+    }
+    /// `[optional account]`
+    /// Active record lease held by the authority
+    #[inline(always)]
+    pub fn lease(
+        &mut self,
+        lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lease = lease;
+        self
+    }
     #[inline(always)]
     pub fn data(&mut self, data: RemainderVec<u8>) -> &mut Self {
         self.instruction.data = Some(data);
@@ -460,6 +548,13 @@ impl<'a, 'b> UpdateRecordCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            class_stats: self
+                .instruction
+                .class_stats
+                .expect("class_stats is not set"),
+
+            lease: self.instruction.lease,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -477,6 +572,8 @@ struct UpdateRecordCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    class_stats: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     data: Option<RemainderVec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -21,6 +21,8 @@ pub struct UpdateRecordExpiry {
     pub class: solana_program::pubkey::Pubkey,
     /// System Program used to extend our record account
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Active record lease held by the authority
+    pub lease: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl UpdateRecordExpiry {
@@ -37,7 +39,7 @@ impl UpdateRecordExpiry {
         args: UpdateRecordExpiryInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -56,6 +58,16 @@ impl UpdateRecordExpiry {
             self.system_program,
             false,
         ));
+        if let Some(lease) = self.lease {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                lease, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UpdateRecordExpiryInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -102,6 +114,7 @@ pub struct UpdateRecordExpiryInstructionArgs {
 ///   2. `[writable]` record
 ///   3. `[]` class
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` lease
 #[derive(Clone, Debug, Default)]
 pub struct UpdateRecordExpiryBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
@@ -109,6 +122,7 @@ pub struct UpdateRecordExpiryBuilder {
     record: Option<solana_program::pubkey::Pubkey>,
     class: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    lease: Option<solana_program::pubkey::Pubkey>,
     expiry: Option<i64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -148,6 +162,13 @@ impl UpdateRecordExpiryBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Active record lease held by the authority
+    #[inline(always)]
+    pub fn lease(&mut self, lease: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.lease = lease;
+        self
+    }
     #[inline(always)]
     pub fn expiry(&mut self, expiry: i64) -> &mut Self {
         self.expiry = Some(expiry);
@@ -181,6 +202,7 @@ impl UpdateRecordExpiryBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            lease: self.lease,
         };
         let args = UpdateRecordExpiryInstructionArgs {
             expiry: self.expiry.clone().expect("expiry is not set"),
@@ -202,6 +224,8 @@ pub struct UpdateRecordExpiryCpiAccounts<'a, 'b> {
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to extend our record account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Active record lease held by the authority
+    pub lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `update_record_expiry` CPI instruction.
@@ -218,6 +242,8 @@ pub struct UpdateRecordExpiryCpi<'a, 'b> {
     pub class: &'b solana_program::account_info::AccountInfo<'a>,
     /// System Program used to extend our record account
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Active record lease held by the authority
+    pub lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateRecordExpiryInstructionArgs,
 }
@@ -235,6 +261,7 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             record: accounts.record,
             class: accounts.class,
             system_program: accounts.system_program,
+            lease: accounts.lease,
             __args: args,
         }
     }
@@ -272,7 +299,7 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -293,6 +320,16 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(lease) = self.lease {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *lease.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::SOLANA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -309,13 +346,16 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(lease) = self.lease {
+            account_infos.push(lease.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -337,6 +377,7 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
 ///   2. `[writable]` record
 ///   3. `[]` class
 ///   4. `[]` system_program
+///   5. `[optional]` lease
 #[derive(Clone, Debug)]
 pub struct UpdateRecordExpiryCpiBuilder<'a, 'b> {
     instruction: Box<UpdateRecordExpiryCpiBuilderInstruction<'a, 'b>>,
@@ -351,6 +392,7 @@ impl<'a, 'b> UpdateRecordExpiryCpiBuilder<'a, 'b> {
             record: None,
             class: None,
             system_program: None,
+            lease: None,
             expiry: None,
             __remaining_accounts: Vec::new(),
         });
@@ -395,6 +437,16 @@ impl<'a, 'b> UpdateRecordExpiryCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Active record lease held by the authority
+    #[inline(always)]
+    pub fn lease(
+        &mut self,
+        lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.lease = lease;
+        self
+    }
     #[inline(always)]
     pub fn expiry(&mut self, expiry: i64) -> &mut Self {
         self.instruction.expiry = Some(expiry);
@@ -459,6 +511,8 @@ impl<'a, 'b> UpdateRecordExpiryCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            lease: self.instruction.lease,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -476,6 +530,7 @@ struct UpdateRecordExpiryCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     class: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    lease: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     expiry: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -6,7 +6,11 @@
 //!
 
 pub(crate) mod r#additional_metadata;
+pub(crate) mod r#batch_record;
 pub(crate) mod r#metadata;
+pub(crate) mod r#record_mutation;
 
 pub use self::r#additional_metadata::*;
+pub use self::r#batch_record::*;
 pub use self::r#metadata::*;
+pub use self::r#record_mutation::*;
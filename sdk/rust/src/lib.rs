@@ -6,3 +6,5 @@
 //!
 pub mod client;
 pub use client::*;
+pub mod state;
+pub use state::SrsError;
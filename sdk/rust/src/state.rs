@@ -0,0 +1,378 @@
+//! Offset-based parsing of on-chain SRS accounts.
+//!
+//! These conversions mirror the layout used by the program in
+//! `program/src/state` and only rely on `core`, so they can be used from
+//! both `std` and `no_std` environments.
+
+use core::{fmt, mem::size_of, str};
+
+use borsh::BorshDeserialize;
+use kaigan::types::{RemainderStr, RemainderVec, U8PrefixString, U8PrefixVec};
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    accounts::{Class, ClassRegistry, ClassRegistryPage, ClassStats, Record, RecordSnapshot},
+    types::RecordMutation,
+    SOLANA_RECORD_SERVICE_ID,
+};
+
+/// Errors returned when parsing raw SRS account data
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SrsError {
+    /// The account discriminator does not match the expected account type
+    InvalidDiscriminator,
+    /// The account data is shorter than the layout requires
+    InvalidDataLength,
+    /// A string field is not valid utf8
+    InvalidUtf8,
+}
+
+impl fmt::Display for SrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SrsError::InvalidDiscriminator => f.write_str("Invalid account discriminator"),
+            SrsError::InvalidDataLength => f.write_str("Invalid account data length"),
+            SrsError::InvalidUtf8 => f.write_str("Invalid utf8 string"),
+        }
+    }
+}
+
+/// Class offsets
+const CLASS_DISCRIMINATOR: u8 = 1;
+const CLASS_DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_AUTHORITY_OFFSET: usize = CLASS_DISCRIMINATOR_OFFSET + size_of::<u8>();
+const CLASS_IS_PERMISSIONED_OFFSET: usize = CLASS_AUTHORITY_OFFSET + size_of::<Pubkey>();
+const CLASS_IS_FROZEN_OFFSET: usize = CLASS_IS_PERMISSIONED_OFFSET + size_of::<bool>();
+const CLASS_TRANSFER_FEE_BPS_OFFSET: usize = CLASS_IS_FROZEN_OFFSET + size_of::<bool>();
+const CLASS_FEE_DESTINATION_OFFSET: usize = CLASS_TRANSFER_FEE_BPS_OFFSET + size_of::<u16>();
+const CLASS_MAX_RECORD_SIZE_OFFSET: usize = CLASS_FEE_DESTINATION_OFFSET + size_of::<Pubkey>();
+const CLASS_MAX_RECORDS_OFFSET: usize = CLASS_MAX_RECORD_SIZE_OFFSET + size_of::<u32>();
+const CLASS_BUMP_OFFSET: usize = CLASS_MAX_RECORDS_OFFSET + size_of::<u32>();
+const CLASS_NAME_LEN_OFFSET: usize = CLASS_BUMP_OFFSET + size_of::<u8>();
+
+/// Record offsets
+const RECORD_DISCRIMINATOR: u8 = 2;
+const RECORD_DISCRIMINATOR_OFFSET: usize = 0;
+const RECORD_CLASS_OFFSET: usize = RECORD_DISCRIMINATOR_OFFSET + size_of::<u8>();
+const RECORD_OWNER_TYPE_OFFSET: usize = RECORD_CLASS_OFFSET + size_of::<Pubkey>();
+const RECORD_OWNER_OFFSET: usize = RECORD_OWNER_TYPE_OFFSET + size_of::<u8>();
+const RECORD_IS_FROZEN_OFFSET: usize = RECORD_OWNER_OFFSET + size_of::<Pubkey>();
+const RECORD_NON_TRANSFERABLE_OFFSET: usize = RECORD_IS_FROZEN_OFFSET + size_of::<bool>();
+const RECORD_EXPIRY_OFFSET: usize = RECORD_NON_TRANSFERABLE_OFFSET + size_of::<bool>();
+const RECORD_CREATED_AT_OFFSET: usize = RECORD_EXPIRY_OFFSET + size_of::<i64>();
+const RECORD_UPDATED_AT_OFFSET: usize = RECORD_CREATED_AT_OFFSET + size_of::<i64>();
+const RECORD_REVISION_OFFSET: usize = RECORD_UPDATED_AT_OFFSET + size_of::<i64>();
+const RECORD_MUTATION_CURSOR_OFFSET: usize = RECORD_REVISION_OFFSET + size_of::<u32>();
+const RECORD_RECENT_MUTATIONS_OFFSET: usize = RECORD_MUTATION_CURSOR_OFFSET + size_of::<u8>();
+const RECORD_MUTATION_SIZE: usize = size_of::<u8>() + size_of::<i64>() + size_of::<Pubkey>();
+const RECORD_MAX_RECENT_MUTATIONS: usize = 5;
+const RECORD_BUMP_OFFSET: usize =
+    RECORD_RECENT_MUTATIONS_OFFSET + RECORD_MUTATION_SIZE * RECORD_MAX_RECENT_MUTATIONS;
+const RECORD_MINT_BUMP_OFFSET: usize = RECORD_BUMP_OFFSET + size_of::<u8>();
+const RECORD_SEED_LEN_OFFSET: usize = RECORD_MINT_BUMP_OFFSET + size_of::<u8>();
+
+/// Class stats offsets
+const CLASS_STATS_DISCRIMINATOR: u8 = 4;
+const CLASS_STATS_DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_STATS_RECORD_COUNT_OFFSET: usize = CLASS_STATS_DISCRIMINATOR_OFFSET + size_of::<u8>();
+const CLASS_STATS_TOTAL_BYTES_OFFSET: usize = CLASS_STATS_RECORD_COUNT_OFFSET + size_of::<u64>();
+const CLASS_STATS_SIZE: usize = CLASS_STATS_TOTAL_BYTES_OFFSET + size_of::<u64>();
+
+/// Record snapshot offsets
+const RECORD_SNAPSHOT_DISCRIMINATOR: u8 = 5;
+const RECORD_SNAPSHOT_DISCRIMINATOR_OFFSET: usize = 0;
+const RECORD_SNAPSHOT_RECORD_OFFSET: usize = RECORD_SNAPSHOT_DISCRIMINATOR_OFFSET + size_of::<u8>();
+const RECORD_SNAPSHOT_REVISION_OFFSET: usize = RECORD_SNAPSHOT_RECORD_OFFSET + size_of::<Pubkey>();
+const RECORD_SNAPSHOT_SLOT_OFFSET: usize = RECORD_SNAPSHOT_REVISION_OFFSET + size_of::<u32>();
+const RECORD_SNAPSHOT_UNIX_TIMESTAMP_OFFSET: usize = RECORD_SNAPSHOT_SLOT_OFFSET + size_of::<u64>();
+const RECORD_SNAPSHOT_DATA_HASH_OFFSET: usize =
+    RECORD_SNAPSHOT_UNIX_TIMESTAMP_OFFSET + size_of::<i64>();
+const RECORD_SNAPSHOT_SIZE: usize = RECORD_SNAPSHOT_DATA_HASH_OFFSET + 32;
+
+/// Class registry offsets
+const CLASS_REGISTRY_DISCRIMINATOR: u8 = 8;
+const CLASS_REGISTRY_DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_REGISTRY_CLASS_COUNT_OFFSET: usize =
+    CLASS_REGISTRY_DISCRIMINATOR_OFFSET + size_of::<u8>();
+const CLASS_REGISTRY_SIZE: usize = CLASS_REGISTRY_CLASS_COUNT_OFFSET + size_of::<u32>();
+
+/// Class registry page offsets
+const CLASS_REGISTRY_PAGE_DISCRIMINATOR: u8 = 9;
+const CLASS_REGISTRY_PAGE_DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_REGISTRY_PAGE_COUNT_OFFSET: usize =
+    CLASS_REGISTRY_PAGE_DISCRIMINATOR_OFFSET + size_of::<u8>();
+const CLASS_REGISTRY_PAGE_CLASSES_OFFSET: usize =
+    CLASS_REGISTRY_PAGE_COUNT_OFFSET + size_of::<u32>();
+
+/// Number of classes of a full class registry page
+pub const CLASSES_PER_PAGE: u32 = 256;
+
+#[inline(always)]
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + size_of::<u32>()].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + size_of::<u64>()].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    i64::from_le_bytes(data[offset..offset + size_of::<i64>()].try_into().unwrap())
+}
+
+#[inline(always)]
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(
+        data[offset..offset + size_of::<Pubkey>()]
+            .try_into()
+            .unwrap(),
+    )
+}
+
+impl TryFrom<&[u8]> for Class {
+    type Error = SrsError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() <= CLASS_NAME_LEN_OFFSET {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        if data[CLASS_DISCRIMINATOR_OFFSET].ne(&CLASS_DISCRIMINATOR) {
+            return Err(SrsError::InvalidDiscriminator);
+        }
+
+        let name_end =
+            CLASS_NAME_LEN_OFFSET + size_of::<u8>() + data[CLASS_NAME_LEN_OFFSET] as usize;
+        if data.len() <= name_end {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        let schema_end = name_end + size_of::<u8>() + data[name_end] as usize;
+        if data.len() < schema_end {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        let name = U8PrefixString::try_from_slice(&data[CLASS_NAME_LEN_OFFSET..name_end])
+            .map_err(|_| SrsError::InvalidUtf8)?;
+        let metadata_schema_uri = U8PrefixString::try_from_slice(&data[name_end..schema_end])
+            .map_err(|_| SrsError::InvalidUtf8)?;
+        let metadata = str::from_utf8(&data[schema_end..]).map_err(|_| SrsError::InvalidUtf8)?;
+
+        Ok(Self {
+            discriminator: CLASS_DISCRIMINATOR,
+            authority: read_pubkey(data, CLASS_AUTHORITY_OFFSET),
+            is_permissioned: data[CLASS_IS_PERMISSIONED_OFFSET] == 1,
+            is_frozen: data[CLASS_IS_FROZEN_OFFSET] == 1,
+            transfer_fee_bps: read_u16(data, CLASS_TRANSFER_FEE_BPS_OFFSET),
+            fee_destination: read_pubkey(data, CLASS_FEE_DESTINATION_OFFSET),
+            max_record_size: read_u32(data, CLASS_MAX_RECORD_SIZE_OFFSET),
+            max_records: read_u32(data, CLASS_MAX_RECORDS_OFFSET),
+            bump: data[CLASS_BUMP_OFFSET],
+            name,
+            metadata_schema_uri,
+            metadata: metadata
+                .parse::<RemainderStr>()
+                .map_err(|_| SrsError::InvalidUtf8)?,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for Record {
+    type Error = SrsError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() <= RECORD_SEED_LEN_OFFSET {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        if data[RECORD_DISCRIMINATOR_OFFSET].ne(&RECORD_DISCRIMINATOR) {
+            return Err(SrsError::InvalidDiscriminator);
+        }
+
+        let seed_end =
+            RECORD_SEED_LEN_OFFSET + size_of::<u8>() + data[RECORD_SEED_LEN_OFFSET] as usize;
+        if data.len() < seed_end {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        let seed = U8PrefixVec::<u8>::try_from_slice(&data[RECORD_SEED_LEN_OFFSET..seed_end])
+            .map_err(|_| SrsError::InvalidDataLength)?;
+        let record_data = RemainderVec::<u8>::try_from_slice(&data[seed_end..])
+            .map_err(|_| SrsError::InvalidDataLength)?;
+
+        Ok(Self {
+            discriminator: RECORD_DISCRIMINATOR,
+            class: read_pubkey(data, RECORD_CLASS_OFFSET),
+            owner_type: data[RECORD_OWNER_TYPE_OFFSET],
+            owner: read_pubkey(data, RECORD_OWNER_OFFSET),
+            is_frozen: data[RECORD_IS_FROZEN_OFFSET] == 1,
+            non_transferable: data[RECORD_NON_TRANSFERABLE_OFFSET] == 1,
+            expiry: read_i64(data, RECORD_EXPIRY_OFFSET),
+            created_at: read_i64(data, RECORD_CREATED_AT_OFFSET),
+            updated_at: read_i64(data, RECORD_UPDATED_AT_OFFSET),
+            revision: read_u32(data, RECORD_REVISION_OFFSET),
+            mutation_cursor: data[RECORD_MUTATION_CURSOR_OFFSET],
+            recent_mutations: core::array::from_fn(|i| {
+                let offset = RECORD_RECENT_MUTATIONS_OFFSET + i * RECORD_MUTATION_SIZE;
+                RecordMutation {
+                    instruction_type: data[offset],
+                    timestamp: i64::from_le_bytes(
+                        data[offset + size_of::<u8>()..offset + size_of::<u8>() + size_of::<i64>()]
+                            .try_into()
+                            .unwrap(),
+                    ),
+                    actor: read_pubkey(data, offset + size_of::<u8>() + size_of::<i64>()),
+                }
+            }),
+            bump: data[RECORD_BUMP_OFFSET],
+            mint_bump: data[RECORD_MINT_BUMP_OFFSET],
+            seed,
+            data: record_data,
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for ClassStats {
+    type Error = SrsError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != CLASS_STATS_SIZE {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        if data[CLASS_STATS_DISCRIMINATOR_OFFSET].ne(&CLASS_STATS_DISCRIMINATOR) {
+            return Err(SrsError::InvalidDiscriminator);
+        }
+
+        Ok(Self {
+            discriminator: CLASS_STATS_DISCRIMINATOR,
+            record_count: read_u64(data, CLASS_STATS_RECORD_COUNT_OFFSET),
+            total_bytes: read_u64(data, CLASS_STATS_TOTAL_BYTES_OFFSET),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for RecordSnapshot {
+    type Error = SrsError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != RECORD_SNAPSHOT_SIZE {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        if data[RECORD_SNAPSHOT_DISCRIMINATOR_OFFSET].ne(&RECORD_SNAPSHOT_DISCRIMINATOR) {
+            return Err(SrsError::InvalidDiscriminator);
+        }
+
+        Ok(Self {
+            discriminator: RECORD_SNAPSHOT_DISCRIMINATOR,
+            record: read_pubkey(data, RECORD_SNAPSHOT_RECORD_OFFSET),
+            revision: read_u32(data, RECORD_SNAPSHOT_REVISION_OFFSET),
+            slot: read_u64(data, RECORD_SNAPSHOT_SLOT_OFFSET),
+            unix_timestamp: read_i64(data, RECORD_SNAPSHOT_UNIX_TIMESTAMP_OFFSET),
+            data_hash: data[RECORD_SNAPSHOT_DATA_HASH_OFFSET..RECORD_SNAPSHOT_SIZE]
+                .try_into()
+                .unwrap(),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for ClassRegistry {
+    type Error = SrsError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != CLASS_REGISTRY_SIZE {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        if data[CLASS_REGISTRY_DISCRIMINATOR_OFFSET].ne(&CLASS_REGISTRY_DISCRIMINATOR) {
+            return Err(SrsError::InvalidDiscriminator);
+        }
+
+        Ok(Self {
+            discriminator: CLASS_REGISTRY_DISCRIMINATOR,
+            class_count: read_u32(data, CLASS_REGISTRY_CLASS_COUNT_OFFSET),
+        })
+    }
+}
+
+impl ClassRegistry {
+    /// Number of pages the classes of the registry are stored in
+    pub fn page_count(&self) -> u32 {
+        self.class_count.div_ceil(CLASSES_PER_PAGE)
+    }
+}
+
+impl TryFrom<&[u8]> for ClassRegistryPage {
+    type Error = SrsError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() < CLASS_REGISTRY_PAGE_CLASSES_OFFSET {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        if data[CLASS_REGISTRY_PAGE_DISCRIMINATOR_OFFSET].ne(&CLASS_REGISTRY_PAGE_DISCRIMINATOR) {
+            return Err(SrsError::InvalidDiscriminator);
+        }
+
+        let count = read_u32(data, CLASS_REGISTRY_PAGE_COUNT_OFFSET) as usize;
+        if data.len() != CLASS_REGISTRY_PAGE_CLASSES_OFFSET + count * size_of::<Pubkey>() {
+            return Err(SrsError::InvalidDataLength);
+        }
+
+        Ok(Self {
+            discriminator: CLASS_REGISTRY_PAGE_DISCRIMINATOR,
+            classes: (0..count)
+                .map(|index| {
+                    read_pubkey(
+                        data,
+                        CLASS_REGISTRY_PAGE_CLASSES_OFFSET + index * size_of::<Pubkey>(),
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Address of the class registry of `authority`
+pub fn find_class_registry_address(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"registry", authority.as_ref()],
+        &SOLANA_RECORD_SERVICE_ID,
+    )
+}
+
+/// Address of the class registry page `page` of `authority`
+pub fn find_class_registry_page_address(authority: &Pubkey, page: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"registry", authority.as_ref(), &page.to_le_bytes()],
+        &SOLANA_RECORD_SERVICE_ID,
+    )
+}
+
+/// Address of the class registry page the next class of `registry` is appended to
+pub fn find_next_class_registry_page_address(
+    authority: &Pubkey,
+    registry: Option<&ClassRegistry>,
+) -> (Pubkey, u8) {
+    let class_count = registry.map_or(0, |registry| registry.class_count);
+    find_class_registry_page_address(authority, class_count / CLASSES_PER_PAGE)
+}
+
+/// Classes of the pages of a class registry, in creation order and without
+/// the deleted classes
+pub fn find_all_classes<'a>(pages: impl IntoIterator<Item = &'a ClassRegistryPage>) -> Vec<Pubkey> {
+    pages
+        .into_iter()
+        .flat_map(|page| page.classes.iter())
+        .filter(|class| **class != Pubkey::default())
+        .copied()
+        .collect()
+}
@@ -26,6 +26,8 @@ import {
   publicKey as publicKeySerializer,
   string,
   struct,
+  u16,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 
@@ -36,7 +38,13 @@ export type ClassAccountData = {
   authority: PublicKey;
   isPermissioned: boolean;
   isFrozen: boolean;
+  transferFeeBps: number;
+  feeDestination: PublicKey;
+  maxRecordSize: number;
+  maxRecords: number;
+  bump: number;
   name: string;
+  metadataSchemaUri: string;
   metadata: string;
 };
 
@@ -44,7 +52,13 @@ export type ClassAccountDataArgs = {
   authority: PublicKey;
   isPermissioned: boolean;
   isFrozen: boolean;
+  transferFeeBps: number;
+  feeDestination: PublicKey;
+  maxRecordSize: number;
+  maxRecords: number;
+  bump: number;
   name: string;
+  metadataSchemaUri: string;
   metadata: string;
 };
 
@@ -59,7 +73,13 @@ export function getClassAccountDataSerializer(): Serializer<
         ['authority', publicKeySerializer()],
         ['isPermissioned', bool()],
         ['isFrozen', bool()],
+        ['transferFeeBps', u16()],
+        ['feeDestination', publicKeySerializer()],
+        ['maxRecordSize', u32()],
+        ['maxRecords', u32()],
+        ['bump', u8()],
         ['name', string({ size: u8() })],
+        ['metadataSchemaUri', string({ size: u8() })],
         ['metadata', string({ size: 'variable' })],
       ],
       { description: 'ClassAccountData' }
@@ -137,14 +157,26 @@ export function getClassGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
       authority: PublicKey;
       isPermissioned: boolean;
       isFrozen: boolean;
+      transferFeeBps: number;
+      feeDestination: PublicKey;
+      maxRecordSize: number;
+      maxRecords: number;
+      bump: number;
       name: string;
+      metadataSchemaUri: string;
       metadata: string;
     }>({
       discriminator: [0, u8()],
       authority: [1, publicKeySerializer()],
       isPermissioned: [33, bool()],
       isFrozen: [34, bool()],
-      name: [35, string({ size: u8() })],
+      transferFeeBps: [35, u16()],
+      feeDestination: [37, publicKeySerializer()],
+      maxRecordSize: [69, u32()],
+      maxRecords: [73, u32()],
+      bump: [77, u8()],
+      name: [78, string({ size: u8() })],
+      metadataSchemaUri: [null, string({ size: u8() })],
       metadata: [null, string({ size: 'variable' })],
     })
     .deserializeUsing<Class>((account) => deserializeClass(account));
@@ -7,4 +7,11 @@
  */
 
 export * from './class';
+export * from './classRegistry';
+export * from './classRegistryPage';
+export * from './classStats';
 export * from './record';
+export * from './recordAccessList';
+export * from './recordLease';
+export * from './recordSnapshot';
+export * from './royaltyConfig';
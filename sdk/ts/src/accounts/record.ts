@@ -21,14 +21,21 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  array,
   bool,
   bytes,
   i64,
   mapSerializer,
   publicKey as publicKeySerializer,
   struct,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  RecordMutation,
+  RecordMutationArgs,
+  getRecordMutationSerializer,
+} from '../types';
 
 export type Record = Account<RecordAccountData>;
 
@@ -38,7 +45,15 @@ export type RecordAccountData = {
   ownerType: number;
   owner: PublicKey;
   isFrozen: boolean;
+  nonTransferable: boolean;
   expiry: bigint;
+  createdAt: bigint;
+  updatedAt: bigint;
+  revision: number;
+  mutationCursor: number;
+  recentMutations: Array<RecordMutation>;
+  bump: number;
+  mintBump: number;
   seed: Uint8Array;
   data: Uint8Array;
 };
@@ -47,7 +62,15 @@ export type RecordAccountDataArgs = {
   class: PublicKey;
   owner: PublicKey;
   isFrozen: boolean;
+  nonTransferable: boolean;
   expiry: number | bigint;
+  createdAt: number | bigint;
+  updatedAt: number | bigint;
+  revision: number;
+  mutationCursor: number;
+  recentMutations: Array<RecordMutationArgs>;
+  bump: number;
+  mintBump: number;
   seed: Uint8Array;
   data: Uint8Array;
 };
@@ -64,7 +87,15 @@ export function getRecordAccountDataSerializer(): Serializer<
         ['ownerType', u8()],
         ['owner', publicKeySerializer()],
         ['isFrozen', bool()],
+        ['nonTransferable', bool()],
         ['expiry', i64()],
+        ['createdAt', i64()],
+        ['updatedAt', i64()],
+        ['revision', u32()],
+        ['mutationCursor', u8()],
+        ['recentMutations', array(getRecordMutationSerializer(), { size: 5 })],
+        ['bump', u8()],
+        ['mintBump', u8()],
         ['seed', bytes({ size: u8() })],
         ['data', bytes()],
       ],
@@ -146,7 +177,15 @@ export function getRecordGpaBuilder(
       ownerType: number;
       owner: PublicKey;
       isFrozen: boolean;
-      expiry: number | bigint;
+      nonTransferable: boolean;
+          expiry: number | bigint;
+      createdAt: number | bigint;
+      updatedAt: number | bigint;
+      revision: number;
+      mutationCursor: number;
+      recentMutations: Array<RecordMutationArgs>;
+      bump: number;
+      mintBump: number;
       seed: Uint8Array;
       data: Uint8Array;
     }>({
@@ -155,8 +194,16 @@ export function getRecordGpaBuilder(
       ownerType: [33, u8()],
       owner: [34, publicKeySerializer()],
       isFrozen: [66, bool()],
-      expiry: [67, i64()],
-      seed: [75, bytes({ size: u8() })],
+      nonTransferable: [67, bool()],
+      expiry: [68, i64()],
+      createdAt: [76, i64()],
+      updatedAt: [84, i64()],
+      revision: [92, u32()],
+      mutationCursor: [96, u8()],
+      recentMutations: [97, array(getRecordMutationSerializer(), { size: 5 })],
+      bump: [302, u8()],
+      mintBump: [303, u8()],
+      seed: [304, bytes({ size: u8() })],
       data: [null, bytes()],
     })
     .deserializeUsing<Record>((account) => deserializeRecord(account));
@@ -15,6 +15,191 @@ type ProgramErrorConstructor = new (
 const codeToErrorMap: Map<number, ProgramErrorConstructor> = new Map();
 const nameToErrorMap: Map<string, ProgramErrorConstructor> = new Map();
 
+/** ClassCapacityExceeded: The class group has reached its maximum size */
+export class ClassCapacityExceededError extends ProgramError {
+  override readonly name: string = 'ClassCapacityExceeded';
+
+  readonly code: number = 0x1; // 1
+
+  constructor(program: Program, cause?: Error) {
+    super('The class group has reached its maximum size', program, cause);
+  }
+}
+codeToErrorMap.set(0x1, ClassCapacityExceededError);
+nameToErrorMap.set('ClassCapacityExceeded', ClassCapacityExceededError);
+
+/** StaleRevision: The record revision does not match the expected revision */
+export class StaleRevisionError extends ProgramError {
+  override readonly name: string = 'StaleRevision';
+
+  readonly code: number = 0x2; // 2
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The record revision does not match the expected revision',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2, StaleRevisionError);
+nameToErrorMap.set('StaleRevision', StaleRevisionError);
+
+/** BatchTooLarge: The batch holds more records than fit in a single transaction */
+export class BatchTooLargeError extends ProgramError {
+  override readonly name: string = 'BatchTooLarge';
+
+  readonly code: number = 0x3; // 3
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The batch holds more records than fit in a single transaction',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x3, BatchTooLargeError);
+nameToErrorMap.set('BatchTooLarge', BatchTooLargeError);
+
+/** ClassFrozen: The class is frozen */
+export class ClassFrozenError extends ProgramError {
+  override readonly name: string = 'ClassFrozen';
+
+  readonly code: number = 0x4; // 4
+
+  constructor(program: Program, cause?: Error) {
+    super('The class is frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0x4, ClassFrozenError);
+nameToErrorMap.set('ClassFrozen', ClassFrozenError);
+
+/** RecordFrozen: The record is frozen */
+export class RecordFrozenError extends ProgramError {
+  override readonly name: string = 'RecordFrozen';
+
+  readonly code: number = 0x5; // 5
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0x5, RecordFrozenError);
+nameToErrorMap.set('RecordFrozen', RecordFrozenError);
+
+/** RecordExpired: The record has expired */
+export class RecordExpiredError extends ProgramError {
+  override readonly name: string = 'RecordExpired';
+
+  readonly code: number = 0x6; // 6
+
+  constructor(program: Program, cause?: Error) {
+    super('The record has expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x6, RecordExpiredError);
+nameToErrorMap.set('RecordExpired', RecordExpiredError);
+
+/** RecordTooLarge: The record data exceeds the class maximum record size */
+export class RecordTooLargeError extends ProgramError {
+  override readonly name: string = 'RecordTooLarge';
+
+  readonly code: number = 0x7; // 7
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The record data exceeds the class maximum record size',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x7, RecordTooLargeError);
+nameToErrorMap.set('RecordTooLarge', RecordTooLargeError);
+
+/** NotRecordOwner: The authority is not the owner of the record */
+export class NotRecordOwnerError extends ProgramError {
+  override readonly name: string = 'NotRecordOwner';
+
+  readonly code: number = 0x8; // 8
+
+  constructor(program: Program, cause?: Error) {
+    super('The authority is not the owner of the record', program, cause);
+  }
+}
+codeToErrorMap.set(0x8, NotRecordOwnerError);
+nameToErrorMap.set('NotRecordOwner', NotRecordOwnerError);
+
+/** NotClassAuthority: The authority is not the authority of the class */
+export class NotClassAuthorityError extends ProgramError {
+  override readonly name: string = 'NotClassAuthority';
+
+  readonly code: number = 0x9; // 9
+
+  constructor(program: Program, cause?: Error) {
+    super('The authority is not the authority of the class', program, cause);
+  }
+}
+codeToErrorMap.set(0x9, NotClassAuthorityError);
+nameToErrorMap.set('NotClassAuthority', NotClassAuthorityError);
+
+/** InvalidDelegate: The class does not allow its authority to act as a record delegate */
+export class InvalidDelegateError extends ProgramError {
+  override readonly name: string = 'InvalidDelegate';
+
+  readonly code: number = 0xa; // 10
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The class does not allow its authority to act as a record delegate',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0xa, InvalidDelegateError);
+nameToErrorMap.set('InvalidDelegate', InvalidDelegateError);
+
+/** AlreadyTokenized: The record is tokenized */
+export class AlreadyTokenizedError extends ProgramError {
+  override readonly name: string = 'AlreadyTokenized';
+
+  readonly code: number = 0xb; // 11
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is tokenized', program, cause);
+  }
+}
+codeToErrorMap.set(0xb, AlreadyTokenizedError);
+nameToErrorMap.set('AlreadyTokenized', AlreadyTokenizedError);
+
+/** NotTokenized: The record is not tokenized by the given mint */
+export class NotTokenizedError extends ProgramError {
+  override readonly name: string = 'NotTokenized';
+
+  readonly code: number = 0xc; // 12
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is not tokenized by the given mint', program, cause);
+  }
+}
+codeToErrorMap.set(0xc, NotTokenizedError);
+nameToErrorMap.set('NotTokenized', NotTokenizedError);
+
+/** ClassNotFrozen: The class is not frozen */
+export class ClassNotFrozenError extends ProgramError {
+  override readonly name: string = 'ClassNotFrozen';
+
+  readonly code: number = 0xd; // 13
+
+  constructor(program: Program, cause?: Error) {
+    super('The class is not frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0xd, ClassNotFrozenError);
+nameToErrorMap.set('ClassNotFrozen', ClassNotFrozenError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
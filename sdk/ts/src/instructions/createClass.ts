@@ -20,6 +20,7 @@ import {
   mapSerializer,
   string,
   struct,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -36,8 +37,14 @@ export type CreateClassInstructionAccounts = {
   payer: Signer;
   /** New class account to be initialized */
   class: PublicKey | Pda;
+  /** Class registry of the authority, created on the first class */
+  classRegistry: PublicKey | Pda;
+  /** Class registry page the new class is appended to */
+  classRegistryPage: PublicKey | Pda;
   /** System Program used to open our new class account */
   systemProgram?: PublicKey | Pda;
+  /** Class stats account of the class */
+  classStats: PublicKey | Pda;
 };
 
 // Data.
@@ -45,14 +52,20 @@ export type CreateClassInstructionData = {
   discriminator: number;
   isPermissioned: boolean;
   isFrozen: boolean;
+  maxRecordSize: number;
+  maxRecords: number;
   name: string;
+  metadataSchemaUri: string;
   metadata: string;
 };
 
 export type CreateClassInstructionDataArgs = {
   isPermissioned: boolean;
   isFrozen: boolean;
+  maxRecordSize: number;
+  maxRecords: number;
   name: string;
+  metadataSchemaUri: string;
   metadata: string;
 };
 
@@ -70,7 +83,10 @@ export function getCreateClassInstructionDataSerializer(): Serializer<
         ['discriminator', u8()],
         ['isPermissioned', bool()],
         ['isFrozen', bool()],
+        ['maxRecordSize', u32()],
+        ['maxRecords', u32()],
         ['name', string({ size: u8() })],
+        ['metadataSchemaUri', string({ size: u8() })],
         ['metadata', string({ size: 'variable' })],
       ],
       { description: 'CreateClassInstructionData' }
@@ -110,11 +126,26 @@ export function createClass(
       isWritable: true as boolean,
       value: input.class ?? null,
     },
-    systemProgram: {
+    classRegistry: {
       index: 3,
+      isWritable: true as boolean,
+      value: input.classRegistry ?? null,
+    },
+    classRegistryPage: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.classRegistryPage ?? null,
+    },
+    systemProgram: {
+      index: 5,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    classStats: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.classStats ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -16,6 +16,7 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
+  bool,
   bytes,
   i64,
   mapSerializer,
@@ -40,20 +41,26 @@ export type CreateRecordInstructionAccounts = {
   record: PublicKey | Pda;
   /** System Program used to create our record account */
   systemProgram?: PublicKey | Pda;
+  /** Class stats account of the class, created on the first record */
+  classStats: PublicKey | Pda;
   /** Optional authority for permissioned classes */
   authority?: Signer;
+  /** Optional record access list for permissioned classes */
+  accessList?: PublicKey | Pda;
 };
 
 // Data.
 export type CreateRecordInstructionData = {
   discriminator: number;
   expiration: bigint;
+  nonTransferable: boolean;
   seed: Uint8Array;
   data: Uint8Array;
 };
 
 export type CreateRecordInstructionDataArgs = {
   expiration: number | bigint;
+  nonTransferable: boolean;
   seed: Uint8Array;
   data: Uint8Array;
 };
@@ -71,6 +78,7 @@ export function getCreateRecordInstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['expiration', i64()],
+        ['nonTransferable', bool()],
         ['seed', bytes({ size: u8() })],
         ['data', bytes()],
       ],
@@ -121,11 +129,21 @@ export function createRecord(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
-    authority: {
+    classStats: {
       index: 5,
+      isWritable: true as boolean,
+      value: input.classStats ?? null,
+    },
+    authority: {
+      index: 6,
       isWritable: false as boolean,
       value: input.authority ?? null,
     },
+    accessList: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.accessList ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -34,6 +34,8 @@ export type DeleteRecordInstructionAccounts = {
   payer: Signer;
   /** Record account to be updated */
   record: PublicKey | Pda;
+  /** Class stats account of the record class */
+  classStats: PublicKey | Pda;
   /** Class account of the record */
   class?: PublicKey | Pda;
   /** Token2022 Program used to close the mint account */
@@ -91,17 +93,22 @@ export function deleteRecord(
       isWritable: true as boolean,
       value: input.record ?? null,
     },
-    class: {
+    classStats: {
       index: 3,
+      isWritable: true as boolean,
+      value: input.classStats ?? null,
+    },
+    class: {
+      index: 4,
       isWritable: false as boolean,
       value: input.class ?? null,
     },
     token2022Program: {
-      index: 4,
+      index: 5,
       isWritable: false as boolean,
       value: input.token2022Program ?? null,
     },
-    mint: { index: 5, isWritable: true as boolean, value: input.mint ?? null },
+    mint: { index: 6, isWritable: true as boolean, value: input.mint ?? null },
   } satisfies ResolvedAccountsWithIndices;
 
   // Accounts in order.
@@ -16,7 +16,6 @@ import {
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
-  bool,
   mapSerializer,
   struct,
   u8,
@@ -29,19 +28,16 @@ import {
 
 // Accounts.
 export type FreezeClassInstructionAccounts = {
-  /** Authority used to freeze/thaw a class */
+  /** Authority used to freeze a class */
   authority: Signer;
-  /** Class account to be frozen/thawed */
+  /** Class account to be frozen */
   class: PublicKey | Pda;
 };
 
 // Data.
-export type FreezeClassInstructionData = {
-  discriminator: number;
-  isFrozen: boolean;
-};
+export type FreezeClassInstructionData = { discriminator: number };
 
-export type FreezeClassInstructionDataArgs = { isFrozen: boolean };
+export type FreezeClassInstructionDataArgs = {};
 
 export function getFreezeClassInstructionDataSerializer(): Serializer<
   FreezeClassInstructionDataArgs,
@@ -52,24 +48,17 @@ export function getFreezeClassInstructionDataSerializer(): Serializer<
     any,
     FreezeClassInstructionData
   >(
-    struct<FreezeClassInstructionData>(
-      [
-        ['discriminator', u8()],
-        ['isFrozen', bool()],
-      ],
-      { description: 'FreezeClassInstructionData' }
-    ),
+    struct<FreezeClassInstructionData>([['discriminator', u8()]], {
+      description: 'FreezeClassInstructionData',
+    }),
     (value) => ({ ...value, discriminator: 3 })
   ) as Serializer<FreezeClassInstructionDataArgs, FreezeClassInstructionData>;
 }
 
-// Args.
-export type FreezeClassInstructionArgs = FreezeClassInstructionDataArgs;
-
 // Instruction.
 export function freezeClass(
   context: Pick<Context, 'programs'>,
-  input: FreezeClassInstructionAccounts & FreezeClassInstructionArgs
+  input: FreezeClassInstructionAccounts
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -91,9 +80,6 @@ export function freezeClass(
     },
   } satisfies ResolvedAccountsWithIndices;
 
-  // Arguments.
-  const resolvedArgs: FreezeClassInstructionArgs = { ...input };
-
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
     resolvedAccounts
@@ -107,9 +93,7 @@ export function freezeClass(
   );
 
   // Data.
-  const data = getFreezeClassInstructionDataSerializer().serialize(
-    resolvedArgs as FreezeClassInstructionDataArgs
-  );
+  const data = getFreezeClassInstructionDataSerializer().serialize({});
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
@@ -35,6 +35,8 @@ export type FreezeRecordInstructionAccounts = {
   record: PublicKey | Pda;
   /** Class account of the record */
   class: PublicKey | Pda;
+  /** Active record lease held by the authority */
+  lease?: PublicKey | Pda;
 };
 
 // Data.
@@ -96,6 +98,11 @@ export function freezeRecord(
       isWritable: false as boolean,
       value: input.class ?? null,
     },
+    lease: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.lease ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
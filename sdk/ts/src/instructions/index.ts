@@ -6,19 +6,46 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addAdditionalMetadata';
+export * from './batchCreateRecord';
+export * from './batchDeleteRecord';
 export * from './burnTokenizedRecord';
+export * from './cloneRecord';
 export * from './createClass';
 export * from './createRecord';
+export * from './createRecordAccessList';
+export * from './createRecordLease';
+export * from './createRecordSnapshot';
 export * from './createRecordTokenizable';
+export * from './createRoyaltyConfig';
+export * from './deleteClass';
 export * from './deleteRecord';
+export * from './deleteRecordAccessList';
+export * from './expireRecordLease';
+export * from './extendRecordExpiry';
 export * from './freezeClass';
 export * from './freezeRecord';
 export * from './freezeTokenizedRecord';
+export * from './grantRecordLease';
 export * from './mintTokenizedRecord';
+export * from './reclaimExpiredRecord';
+export * from './removeAdditionalMetadata';
+export * from './renameRecord';
+export * from './renewRecordLease';
+export * from './resizeRecordDataOnly';
+export * from './revokeRecordLease';
 export * from './transferRecord';
+export * from './transferRecordToClass';
 export * from './transferTokenizedRecord';
+export * from './unfreezeClass';
 export * from './updateClassAuthority';
 export * from './updateClassMetadata';
+export * from './updateClassPermission';
+export * from './updateGroupMetadata';
 export * from './updateRecord';
+export * from './updateRecordAccessList';
 export * from './updateRecordExpiry';
 export * from './updateRecordTokenizable';
+export * from './updateRecordWithRevision';
+export * from './updateRoyaltyConfig';
+export * from './updateTokenizedRecordMetadata';
@@ -50,6 +50,8 @@ export type MintTokenizedRecordInstructionAccounts = {
   token2022?: PublicKey | Pda;
   /** System Program used to create our token */
   systemProgram?: PublicKey | Pda;
+  /** Wallet receiving the record token, defaults to the record owner */
+  recipient?: PublicKey | Pda;
 };
 
 // Data.
@@ -140,6 +142,11 @@ export function mintTokenizedRecord(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    recipient: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.recipient ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -33,8 +33,14 @@ export type TransferRecordInstructionAccounts = {
   authority: Signer;
   /** Record account to be updated */
   record: PublicKey | Pda;
-  /** Class account of the record */
+  /** Class account of the record, required to pay the transfer fee */
   class?: PublicKey | Pda;
+  /** Account paying the transfer fee, required when the class charges a transfer fee */
+  payer?: Signer;
+  /** Fee destination of the class, required when the class charges a transfer fee */
+  feeDestination?: PublicKey | Pda;
+  /** System Program used to pay the transfer fee */
+  systemProgram?: PublicKey | Pda;
 };
 
 // Data.
@@ -99,11 +105,35 @@ export function transferRecord(
       isWritable: false as boolean,
       value: input.class ?? null,
     },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    feeDestination: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.feeDestination ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
   const resolvedArgs: TransferRecordInstructionArgs = { ...input };
 
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
     resolvedAccounts
@@ -8,16 +8,21 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
   mapSerializer,
+  option,
   struct,
+  u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -40,14 +45,29 @@ export type TransferTokenizedRecordInstructionAccounts = {
   record: PublicKey | Pda;
   /** Token2022 Program used to freeze/unfreeze the tokenized record */
   token2022?: PublicKey | Pda;
-  /** Class account of the record */
+  /** Class account of the record, required with the royalty config and to pay the transfer fee */
   class?: PublicKey | Pda;
+  /** Royalty config account of the record class */
+  royaltyConfig?: PublicKey | Pda;
+  /** Account paying the royalty and the transfer fee, required when the class charges either */
+  payer?: Signer;
+  /** Royalty destination of the royalty config, required when the class charges a royalty */
+  destination?: PublicKey | Pda;
+  /** System Program used to pay the royalty and the transfer fee */
+  systemProgram?: PublicKey | Pda;
+  /** Fee destination of the class, required when the class charges a transfer fee */
+  feeDestination?: PublicKey | Pda;
 };
 
 // Data.
-export type TransferTokenizedRecordInstructionData = { discriminator: number };
+export type TransferTokenizedRecordInstructionData = {
+  discriminator: number;
+  tokenValueLamports: Option<bigint>;
+};
 
-export type TransferTokenizedRecordInstructionDataArgs = {};
+export type TransferTokenizedRecordInstructionDataArgs = {
+  tokenValueLamports?: OptionOrNullable<number | bigint>;
+};
 
 export function getTransferTokenizedRecordInstructionDataSerializer(): Serializer<
   TransferTokenizedRecordInstructionDataArgs,
@@ -58,20 +78,33 @@ export function getTransferTokenizedRecordInstructionDataSerializer(): Serialize
     any,
     TransferTokenizedRecordInstructionData
   >(
-    struct<TransferTokenizedRecordInstructionData>([['discriminator', u8()]], {
-      description: 'TransferTokenizedRecordInstructionData',
-    }),
-    (value) => ({ ...value, discriminator: 12 })
+    struct<TransferTokenizedRecordInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['tokenValueLamports', option(u64(), { prefix: null })],
+      ],
+      { description: 'TransferTokenizedRecordInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 12,
+      tokenValueLamports: value.tokenValueLamports ?? none(),
+    })
   ) as Serializer<
     TransferTokenizedRecordInstructionDataArgs,
     TransferTokenizedRecordInstructionData
   >;
 }
 
+// Args.
+export type TransferTokenizedRecordInstructionArgs =
+  TransferTokenizedRecordInstructionDataArgs;
+
 // Instruction.
 export function transferTokenizedRecord(
   context: Pick<Context, 'programs'>,
-  input: TransferTokenizedRecordInstructionAccounts
+  input: TransferTokenizedRecordInstructionAccounts &
+    TransferTokenizedRecordInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -112,8 +145,36 @@ export function transferTokenizedRecord(
       isWritable: false as boolean,
       value: input.class ?? null,
     },
+    royaltyConfig: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.royaltyConfig ?? null,
+    },
+    payer: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    destination: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    feeDestination: {
+      index: 11,
+      isWritable: true as boolean,
+      value: input.feeDestination ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
+  // Arguments.
+  const resolvedArgs: TransferTokenizedRecordInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.token2022.value) {
     resolvedAccounts.token2022.value = context.programs.getPublicKey(
@@ -122,6 +183,13 @@ export function transferTokenizedRecord(
     );
     resolvedAccounts.token2022.isWritable = false;
   }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -137,7 +205,7 @@ export function transferTokenizedRecord(
 
   // Data.
   const data = getTransferTokenizedRecordInstructionDataSerializer().serialize(
-    {}
+    resolvedArgs as TransferTokenizedRecordInstructionDataArgs
   );
 
   // Bytes Created On Chain.
@@ -17,8 +17,11 @@ import {
 import {
   Serializer,
   mapSerializer,
+  publicKey as publicKeySerializer,
   string,
   struct,
+  u16,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
@@ -42,10 +45,22 @@ export type UpdateClassMetadataInstructionAccounts = {
 // Data.
 export type UpdateClassMetadataInstructionData = {
   discriminator: number;
+  maxRecordSize: number;
+  maxRecords: number;
+  transferFeeBps: number;
+  feeDestination: PublicKey;
+  metadataSchemaUri: string;
   metadata: string;
 };
 
-export type UpdateClassMetadataInstructionDataArgs = { metadata: string };
+export type UpdateClassMetadataInstructionDataArgs = {
+  maxRecordSize: number;
+  maxRecords: number;
+  transferFeeBps: number;
+  feeDestination: PublicKey;
+  metadataSchemaUri: string;
+  metadata: string;
+};
 
 export function getUpdateClassMetadataInstructionDataSerializer(): Serializer<
   UpdateClassMetadataInstructionDataArgs,
@@ -59,6 +74,11 @@ export function getUpdateClassMetadataInstructionDataSerializer(): Serializer<
     struct<UpdateClassMetadataInstructionData>(
       [
         ['discriminator', u8()],
+        ['maxRecordSize', u32()],
+        ['maxRecords', u32()],
+        ['transferFeeBps', u16()],
+        ['feeDestination', publicKeySerializer()],
+        ['metadataSchemaUri', string({ size: u8() })],
         ['metadata', string({ size: 'variable' })],
       ],
       { description: 'UpdateClassMetadataInstructionData' }
@@ -39,6 +39,8 @@ export type UpdateRecordExpiryInstructionAccounts = {
   class: PublicKey | Pda;
   /** System Program used to extend our record account */
   systemProgram?: PublicKey | Pda;
+  /** Active record lease held by the authority */
+  lease?: PublicKey | Pda;
 };
 
 // Data.
@@ -115,6 +117,11 @@ export function updateRecordExpiry(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    lease: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.lease ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
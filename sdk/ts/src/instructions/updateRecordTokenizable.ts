@@ -39,6 +39,10 @@ export type UpdateRecordTokenizableInstructionAccounts = {
   class: PublicKey | Pda;
   /** System Program used to extend our record account */
   systemProgram?: PublicKey | Pda;
+  /** Class stats account of the record class */
+  classStats: PublicKey | Pda;
+  /** Active record lease held by the authority */
+  lease?: PublicKey | Pda;
 };
 
 // Data.
@@ -117,6 +121,16 @@ export function updateRecordTokenizable(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    classStats: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.classStats ?? null,
+    },
+    lease: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.lease ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -7,4 +7,6 @@
  */
 
 export * from './additionalMetadata';
+export * from './batchRecord';
 export * from './metadata';
+export * from './recordMutation';
@@ -35,7 +35,10 @@ describe('test', () => {
         const ix = program.getCreateClassInstruction({
             isPermissioned: false,
             isFrozen: false,
+            maxRecordSize: 0,
+            maxRecords: 0,
             name: "twitter",
+            metadataSchemaUri: "",
             metadata: "test",
             authority,
             class: classAddress